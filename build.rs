@@ -0,0 +1,29 @@
+//! Records build information shown by the `server_info` tool.
+use std::process::Command;
+
+fn main() {
+    // short hash of the commit being built, or "unknown" outside a git checkout
+    let git_hash = command_output("git", &["rev-parse", "--short", "HEAD"]);
+    println!("cargo:rustc-env=BUILD_GIT_HASH={git_hash}");
+
+    // e.g. "rustc 1.85.0 (4d91de4e4 2025-02-17)"
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = command_output(&rustc, &["--version"]);
+    println!("cargo:rustc-env=BUILD_RUSTC_VERSION={rustc_version}");
+
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+}
+
+/// Runs a command and returns its trimmed output, or "unknown" if it fails.
+fn command_output(program: &str, args: &[&str]) -> String {
+    Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|output| output.trim().to_string())
+        .filter(|output| !output.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
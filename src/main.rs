@@ -2,7 +2,11 @@
 mod mcp_handler;
 mod tmdb_client;
 mod tools;
-use crate::{mcp_handler::McpHandler, tmdb_client::TmdbClient};
+use crate::{
+    mcp_handler::{Exports, LogLevel, McpHandler, SingleFlight},
+    tmdb_client::{TmdbClient, TmdbConfig},
+    tools::ToolRegistry,
+};
 use rust_mcp_sdk::{
     McpServer, StdioTransport, ToMcpServerHandler, TransportOptions,
     error::SdkResult,
@@ -10,14 +14,16 @@ use rust_mcp_sdk::{
     mcp_server::{McpServerOptions, server_runtime},
     schema::*,
 };
+use std::sync::Arc;
+use std::time::Instant;
 
-#[tokio::main]
-async fn main() -> SdkResult<()> {
-    //STEP 1: Define server name & capabilities
-    let server_details = InitializeResult {
+/// The server's name, version and capabilities, sent to clients in the initialize result and
+/// reported by the `server_info` tool.
+fn server_details() -> InitializeResult {
+    InitializeResult {
         server_info: Implementation {
             name: "Techshare MCP Server".into(),
-            version: "0.1.0".into(),
+            version: env!("CARGO_PKG_VERSION").into(),
             title: Some("Example MCP Server Demonstrating MCP Tools".into()),
             description: Some("An MCP server that retrieves detailed information about actors and movies from the TMDB database.".into()),
             icons: vec![mcp_icon!(
@@ -29,20 +35,73 @@ async fn main() -> SdkResult<()> {
         },
         capabilities: ServerCapabilities {
             tools: Some(ServerCapabilitiesTools { list_changed: None }),
+            // actor name suggestions, see `McpHandler::handle_complete_request`
+            completions: Some(Default::default()),
+            // TMDB latency summaries, see `McpHandler::handle_set_level_request`
+            logging: Some(Default::default()),
+            // documents published by tools, see `McpHandler::handle_read_resource_request`
+            resources: Some(ServerCapabilitiesResources {
+                list_changed: None,
+                subscribe: None,
+            }),
 
             ..Default::default() // Using default values for other fields
         },
         meta: None,
         instructions: Some("server instructions...".into()),
         protocol_version: ProtocolVersion::V2025_11_25.into(),
-    };
+    }
+}
+
+#[tokio::main]
+async fn main() -> SdkResult<()> {
+    //STEP 1: Define server name & capabilities
+    let server_details = server_details();
 
     // use stdio transport
     let transport = StdioTransport::new(TransportOptions::default())?;
 
+    // read the TMDB settings and fail fast, listing every problem, if any are invalid
+    let config = TmdbConfig::from_env();
+    if let Err(err) = config.validate() {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+
+    // one line on stderr (stdout carries the protocol) confirming the settings in effect
+    eprintln!("starting with transport=stdio {}", config.summary());
+
+    let tmdb_client = TmdbClient::with_config(config);
+
+    // optionally wait a random moment so servers started together don't all call TMDB at once
+    let startup_delay = tmdb_client.config().startup_delay();
+    if !startup_delay.is_zero() {
+        eprintln!("delaying startup by {startup_delay:?}");
+        tokio::time::sleep(startup_delay).await;
+    }
+
+    // optionally prefetch rarely-changing data so the first tool call doesn't pay for it
+    if tmdb_client.config().warmup {
+        let started = Instant::now();
+        match tmdb_client.warm_up().await {
+            Ok(()) => eprintln!("tmdb warm-up finished in {:?}", started.elapsed()),
+            Err(err) => eprintln!("tmdb warm-up failed after {:?}: {err}", started.elapsed()),
+        }
+    }
+    // kept to log a final latency summary on shutdown, if the client enabled info-level logs
+    let latency_tracker = tmdb_client.latency_tracker();
+    let log_level = Arc::new(LogLevel::default());
+
+    // the built-in tools, plus any enabled through configuration
+    let tools = ToolRegistry::new(tmdb_client.config());
+
     // custom handler for managing various incoming client requests.
     let handler = McpHandler {
-        tmdb_client: TmdbClient::new(),
+        tmdb_client,
+        tools,
+        in_flight: SingleFlight::default(),
+        exports: Exports::default(),
+        log_level: log_level.clone(),
     };
 
     // create server instance
@@ -57,5 +116,12 @@ async fn main() -> SdkResult<()> {
     // start the server
     server.start().await?;
 
+    // the client has gone by now, so the final summary goes to stderr
+    if log_level.enables(LoggingLevel::Info)
+        && let Some(summary) = latency_tracker.summary()
+    {
+        eprintln!("{summary}");
+    }
+
     Ok(())
 }
@@ -1,11 +1,35 @@
-use crate::{tmdb_client::TmdbClient, tools::TmdbTools};
+use crate::{
+    tmdb_client::{BudgetChange, TmdbClient},
+    tools::{ResponseFormat, TmdbTools, ToolRegistry, structured_mismatches, truncate_output},
+};
+mod exports;
+mod logging;
+mod single_flight;
+
+pub use exports::Exports;
+pub use logging::LogLevel;
+pub use single_flight::SingleFlight;
+
 use async_trait::async_trait;
 use rust_mcp_sdk::{McpServer, mcp_server::ServerHandler, schema::*};
 use std::sync::Arc;
 
+/// Maximum number of suggestions returned for a completion request.
+const MAX_COMPLETIONS: usize = 5;
+
+/// Shortest partial name worth searching TMDB for.
+const MIN_COMPLETION_CHARS: usize = 2;
+
 // Define a custom handler for mcp messages
 pub struct McpHandler {
     pub tmdb_client: TmdbClient,
+    pub tools: ToolRegistry,
+    /// Identical tool calls currently running, shared with duplicates that arrive meanwhile
+    pub in_flight: SingleFlight,
+    /// Documents published by tools such as export_filmography, served as resources
+    pub exports: Exports,
+    /// Severity of the log messages the client asked for, shared with `main` for shutdown
+    pub log_level: Arc<LogLevel>,
 }
 
 /// MCP server handler implementation.
@@ -25,29 +49,685 @@ impl ServerHandler for McpHandler {
         _runtime: Arc<dyn McpServer>,
     ) -> std::result::Result<ListToolsResult, RpcError> {
         Ok(ListToolsResult {
-            tools: TmdbTools::tools(),
+            tools: self.tools.tools(),
             meta: None,
             next_cursor: None,
         })
     }
 
+    /// Lists the documents tools have published as resources, e.g. exported filmographies.
+    async fn handle_list_resources_request(
+        &self,
+        _params: Option<PaginatedRequestParams>,
+        _runtime: Arc<dyn McpServer>,
+    ) -> std::result::Result<ListResourcesResult, RpcError> {
+        Ok(ListResourcesResult {
+            resources: self.exports.list(),
+            meta: None,
+            next_cursor: None,
+        })
+    }
+
+    /// Returns the content of a published document.
+    async fn handle_read_resource_request(
+        &self,
+        params: ReadResourceRequestParams,
+        _runtime: Arc<dyn McpServer>,
+    ) -> std::result::Result<ReadResourceResult, RpcError> {
+        match self.exports.read(&params.uri) {
+            Some(content) => Ok(ReadResourceResult {
+                contents: vec![content],
+                meta: None,
+            }),
+            None => Err(RpcError::invalid_params().with_message(format!(
+                "No resource with the URI {} exists; call export_filmography to create it",
+                params.uri
+            ))),
+        }
+    }
+
+    /// Suggests actor names while the user types a value for an `actor_name` argument.
+    ///
+    /// Completion references can only name prompts or resource templates, so suggestions are
+    /// keyed on the argument name rather than on the tool being called.
+    async fn handle_complete_request(
+        &self,
+        params: CompleteRequestParams,
+        _runtime: Arc<dyn McpServer>,
+    ) -> std::result::Result<CompleteResult, RpcError> {
+        Ok(CompleteResult {
+            completion: complete_argument(&self.tmdb_client, &params.argument).await?,
+            meta: None,
+        })
+    }
+
+    /// Records the minimum severity of the log messages the client wants to receive.
+    async fn handle_set_level_request(
+        &self,
+        params: SetLevelRequestParams,
+        _runtime: Arc<dyn McpServer>,
+    ) -> std::result::Result<Result, RpcError> {
+        self.log_level.set(params.level);
+        Ok(Result::default())
+    }
+
     /// Handles client requests to invoke a specific tool (Step 3 from the slide).
     async fn handle_call_tool_request(
         &self,
         params: CallToolRequestParams,
-        _runtime: Arc<dyn McpServer>,
+        runtime: Arc<dyn McpServer>,
     ) -> std::result::Result<CallToolResult, CallToolError> {
+        // Point clients that guessed a tool name at the closest real one.
+        let tools = self.tools.tools();
+        if !tools.iter().any(|tool| tool.name == params.name) {
+            return Err(unknown_tool_error(&params.name, &tools));
+        }
+
+        // Reject arguments that violate the bounds declared in the tool's input schema.
+        validate_arguments(&params, &tools)?;
+
+        // Raw TMDB JSON cut short would no longer parse, so it is never truncated.
+        let output_budget =
+            output_budget(&params, &tools, self.tmdb_client.config().max_output_tokens);
+
+        // A retried call that is still running shares the first call's result.
+        let key = format!(
+            "{}:{}:{:?}",
+            params.name,
+            serde_json::to_string(&params.arguments).unwrap_or_default(),
+            ResponseFormat::from_meta(params.meta.as_ref())
+        );
+        let tool_name = params.name.clone();
+        let result = self
+            .in_flight
+            .run(key, self.call_tool(params, runtime.server_info()))
+            .await?;
+
+        // Debug builds point out tools whose structured content drifted from their text,
+        // without failing the call.
+        if cfg!(debug_assertions)
+            && let Some(warning) = structured_mismatch_warning(&tool_name, &result)
+        {
+            eprintln!("{warning}");
+        }
+
+        // Every LOG_EVERY TMDB requests, share the latency percentiles with clients that
+        // asked for info-level logs.
+        if self.log_level.enables(LoggingLevel::Info)
+            && let Some(summary) = self.tmdb_client.latency_tracker().due_summary()
+        {
+            let message = LoggingMessageNotificationParams {
+                data: summary.to_string().into(),
+                level: LoggingLevel::Info,
+                logger: Some("tmdb_latency".into()),
+                meta: None,
+            };
+            if let Err(err) = runtime.notify_log_message(message).await {
+                eprintln!("failed to send the latency summary: {err}");
+            }
+        }
+
+        // Tell clients when failed TMDB requests stop being retried and when retries resume.
+        // Changes are taken even when nobody listens, so they don't pile up.
+        for change in self.tmdb_client.retry_budget().take_changes() {
+            let level = match change {
+                BudgetChange::Exhausted => LoggingLevel::Warning,
+                BudgetChange::Refilled => LoggingLevel::Info,
+            };
+            if !self.log_level.enables(level) {
+                continue;
+            }
+            let message = LoggingMessageNotificationParams {
+                data: change.to_string().into(),
+                level,
+                logger: Some("tmdb_retry_budget".into()),
+                meta: None,
+            };
+            if let Err(err) = runtime.notify_log_message(message).await {
+                eprintln!("failed to send the retry budget change: {err}");
+            }
+        }
+
+        // Keep long lists within the configured budget, whichever tool produced them.
+        Ok(match output_budget {
+            Some(max_tokens) => truncate_output(result, max_tokens),
+            None => result,
+        })
+    }
+}
+
+impl McpHandler {
+    /// Invokes the requested tool with its arguments. `server_details` is what the server
+    /// advertised on initialization.
+    async fn call_tool(
+        &self,
+        params: CallToolRequestParams,
+        server_details: &InitializeResult,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // Tools registered at runtime parse their own arguments.
+        if let Some(tool) = self.tools.find(&params.name) {
+            return tool
+                .call(&self.tmdb_client, params.arguments.unwrap_or_default())
+                .await;
+        }
+
+        // Optional per-call hint (`_meta.format`) for tools that can give a shorter answer.
+        let format = ResponseFormat::from_meta(params.meta.as_ref());
+
         // Create a tool instance from the request, or return an error if the request is invalid.
         let requested_tool: TmdbTools = TmdbTools::try_from(params).map_err(CallToolError::new)?;
 
         // invoke the tool
         match requested_tool {
             TmdbTools::GetActorInfo(get_actor_info) => {
-                get_actor_info.invoke(&self.tmdb_client).await
+                get_actor_info.invoke(&self.tmdb_client, format).await
             }
             TmdbTools::GetMoviesByActor(get_movie_info) => {
-                get_movie_info.invoke(&self.tmdb_client).await
+                get_movie_info.invoke(&self.tmdb_client, format).await
+            }
+            TmdbTools::FindByImdbId(find_by_imdb_id) => {
+                find_by_imdb_id.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::FeaturedActors(featured_actors) => {
+                featured_actors.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::ComparePopularity(compare_popularity) => {
+                compare_popularity.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::CompareMovies(compare_movies) => {
+                compare_movies.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetTrendingMovies(get_trending_movies) => {
+                get_trending_movies.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetUpcomingForActor(get_upcoming_for_actor) => {
+                get_upcoming_for_actor.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetBestFilm(get_best_film) => get_best_film.invoke(&self.tmdb_client).await,
+            TmdbTools::GetAlternativeTitles(get_alternative_titles) => {
+                get_alternative_titles.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetFrequentCostars(get_frequent_costars) => {
+                get_frequent_costars.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetMovieCredits(get_movie_credits) => {
+                get_movie_credits.invoke(&self.tmdb_client, format).await
+            }
+            TmdbTools::GetMovieImages(get_movie_images) => {
+                get_movie_images.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetFilmographyCsv(get_filmography_csv) => {
+                get_filmography_csv.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::OnThisDay(on_this_day) => on_this_day.invoke(&self.tmdb_client).await,
+            TmdbTools::GetActorLinks(get_actor_links) => {
+                get_actor_links.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetTvSeason(get_tv_season) => get_tv_season.invoke(&self.tmdb_client).await,
+            TmdbTools::GetCareerSummary(get_career_summary) => {
+                get_career_summary.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::MoviesWithBothActors(movies_with_both_actors) => {
+                movies_with_both_actors.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetMultilingualBio(get_multilingual_bio) => {
+                get_multilingual_bio.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::SummarizeMovie(summarize_movie) => {
+                summarize_movie.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::TrendingInGenre(trending_in_genre) => {
+                trending_in_genre.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetCrewCredits(get_crew_credits) => {
+                get_crew_credits.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetActorMoviesInYear(get_actor_movies_in_year) => {
+                get_actor_movies_in_year.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::DegreesOfSeparation(degrees_of_separation) => {
+                degrees_of_separation.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetTopCast(get_top_cast) => get_top_cast.invoke(&self.tmdb_client).await,
+            TmdbTools::GetActorHighlights(get_actor_highlights) => {
+                get_actor_highlights.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::ServerInfo(server_info) => server_info.invoke(server_details),
+            TmdbTools::SearchByDepartment(search_by_department) => {
+                search_by_department.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::RecommendForActor(recommend_for_actor) => {
+                recommend_for_actor.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::RatingTrend(rating_trend) => rating_trend.invoke(&self.tmdb_client).await,
+            TmdbTools::WhereToWatchActor(where_to_watch_actor) => {
+                where_to_watch_actor.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::FilmographyDiff(filmography_diff) => {
+                filmography_diff.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::WatchOrder(watch_order) => watch_order.invoke(&self.tmdb_client).await,
+            TmdbTools::GetAcclaimedRoles(get_acclaimed_roles) => {
+                get_acclaimed_roles.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::ListGenres(list_genres) => list_genres.invoke(&self.tmdb_client).await,
+            TmdbTools::GetActorFilmsByGenre(get_actor_films_by_genre) => {
+                get_actor_films_by_genre.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::GetProductionCompanies(get_production_companies) => {
+                get_production_companies.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::TrendingActorsLatest(trending_actors_latest) => {
+                trending_actors_latest.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::SearchMovies(search_movies) => search_movies.invoke(&self.tmdb_client).await,
+            TmdbTools::TopGrossing(top_grossing) => top_grossing.invoke(&self.tmdb_client).await,
+            TmdbTools::FrequentDirector(frequent_director) => {
+                frequent_director.invoke(&self.tmdb_client).await
+            }
+            TmdbTools::ExportFilmography(export_filmography) => {
+                export_filmography
+                    .invoke(&self.tmdb_client, &self.exports)
+                    .await
             }
+            TmdbTools::GetCostarsInFilm(get_costars_in_film) => {
+                get_costars_in_film.invoke(&self.tmdb_client).await
+            }
+        }
+    }
+}
+
+/// Suggests values for a completion request: actor names matching the partial value of an
+/// `actor_name` argument, and nothing for other arguments.
+async fn complete_argument(
+    tmdb_client: &TmdbClient,
+    argument: &CompleteRequestArgument,
+) -> std::result::Result<CompleteResultCompletion, RpcError> {
+    let partial_name = argument.value.trim();
+    let mut values = Vec::new();
+
+    // avoid searching on every keystroke of very short input
+    if argument.name == "actor_name" && partial_name.chars().count() >= MIN_COMPLETION_CHARS {
+        let people = tmdb_client
+            .search_people(partial_name)
+            .await
+            .map_err(|err| RpcError::internal_error().with_message(err.to_string()))?;
+
+        for person in people.results {
+            if !values.contains(&person.name) {
+                values.push(person.name);
+            }
+        }
+    }
+
+    let total = values.len();
+    values.truncate(MAX_COMPLETIONS);
+
+    Ok(CompleteResultCompletion {
+        has_more: Some(total > values.len()),
+        total: Some(total as i64),
+        values,
+    })
+}
+
+/// Checks the request arguments against the `minLength`/`maxLength`, `minItems`/`maxItems`
+/// and `minimum`/`maximum` bounds declared in the requested tool's input schema, so
+/// out-of-range values never reach `invoke`.
+fn validate_arguments(
+    params: &CallToolRequestParams,
+    tools: &[Tool],
+) -> std::result::Result<(), CallToolError> {
+    let (Some(arguments), Some(tool)) = (
+        params.arguments.as_ref(),
+        tools.iter().find(|tool| tool.name == params.name),
+    ) else {
+        return Ok(());
+    };
+
+    let properties = tool.input_schema.properties.clone().unwrap_or_default();
+    let invalid = |message: String| {
+        Err(CallToolError::invalid_arguments(
+            &params.name,
+            Some(message),
+        ))
+    };
+    for (field, value) in arguments {
+        let Some(schema) = properties.get(field) else {
+            continue;
+        };
+        let bound = |name: &str| schema.get(name).and_then(|v| v.as_u64());
+
+        if let Some(text) = value.as_str() {
+            let length = text.trim().chars().count() as u64;
+            if let Some(min_length) = bound("minLength")
+                && length < min_length
+            {
+                return invalid(format!(
+                    "`{field}` must be at least {min_length} characters long"
+                ));
+            }
+            if let Some(max_length) = bound("maxLength")
+                && length > max_length
+            {
+                return invalid(format!(
+                    "`{field}` must be at most {max_length} characters long"
+                ));
+            }
+        }
+
+        if let Some(items) = value.as_array() {
+            let count = items.len() as u64;
+            if let Some(min_items) = bound("minItems")
+                && count < min_items
+            {
+                return invalid(format!("`{field}` must list at least {min_items} item(s)"));
+            }
+            if let Some(max_items) = bound("maxItems")
+                && count > max_items
+            {
+                return invalid(format!("`{field}` must list at most {max_items} item(s)"));
+            }
+        }
+
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = schema.get("minimum").and_then(|v| v.as_f64())
+                && number < minimum
+            {
+                return invalid(format!("`{field}` must be at least {minimum}"));
+            }
+            if let Some(maximum) = schema.get("maximum").and_then(|v| v.as_f64())
+                && number > maximum
+            {
+                return invalid(format!("`{field}` must be at most {maximum}"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the token budget a call's output is truncated to: `max_output_tokens`, or None for
+/// a `raw: true` call to a tool that takes `raw`, whose JSON must reach the client whole.
+fn output_budget(
+    params: &CallToolRequestParams,
+    tools: &[Tool],
+    max_output_tokens: Option<usize>,
+) -> Option<usize> {
+    let takes_raw = tools
+        .iter()
+        .find(|tool| tool.name == params.name)
+        .and_then(|tool| tool.input_schema.properties.as_ref())
+        .is_some_and(|properties| properties.contains_key("raw"));
+    let raw = params
+        .arguments
+        .as_ref()
+        .and_then(|arguments| arguments.get("raw"))
+        .and_then(|raw| raw.as_bool())
+        .unwrap_or(false);
+    if takes_raw && raw {
+        None
+    } else {
+        max_output_tokens
+    }
+}
+
+/// Describes where a result's structured content disagrees with its text, if it does.
+fn structured_mismatch_warning(tool_name: &str, result: &CallToolResult) -> Option<String> {
+    let mismatches = structured_mismatches(result);
+    (!mismatches.is_empty()).then(|| {
+        format!(
+            "{tool_name}: structured content disagrees with the text: {}",
+            mismatches.join("; ")
+        )
+    })
+}
+
+/// Builds the error returned for an unknown tool name, listing the available tools
+/// and suggesting the closest match by edit distance.
+fn unknown_tool_error(requested: &str, tools: &[Tool]) -> CallToolError {
+    let tool_names: Vec<&str> = tools.iter().map(|tool| tool.name.as_str()).collect();
+
+    let suggestion = tool_names
+        .iter()
+        .map(|name| (edit_distance(requested, name), name))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| format!(" Did you mean \"{name}\"?"))
+        .unwrap_or_default();
+
+    CallToolError::from_message(format!(
+        "Unknown tool: \"{requested}\".{suggestion} Available tools: {}",
+        tool_names.join(", ")
+    ))
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::list_tools;
+    use serde_json::json;
+
+    /// Validates a call to `name` with `arguments` against the built-in tools' schemas.
+    fn validate(name: &str, arguments: serde_json::Value) -> std::result::Result<(), String> {
+        let params: CallToolRequestParams =
+            serde_json::from_value(json!({ "name": name, "arguments": arguments })).unwrap();
+        validate_arguments(&params, &list_tools()).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn arrays_must_have_the_declared_number_of_items() {
+        let err = validate(
+            "compare_popularity",
+            json!({ "actor_names": ["Tom Hanks"] }),
+        );
+        assert!(err.unwrap_err().contains("at least 2 item(s)"));
+
+        let ok = validate(
+            "compare_popularity",
+            json!({ "actor_names": ["Tom Hanks", "Meryl Streep"] }),
+        );
+        assert!(ok.is_ok());
+    }
+
+    #[test]
+    fn strings_and_numbers_must_be_within_their_bounds() {
+        let short = validate("find_by_imdb_id", json!({ "imdb_id": "tt1" }));
+        assert!(short.unwrap_err().contains("at least 9 characters"));
+
+        let long = validate(
+            "get_alternative_titles",
+            json!({ "movie_id": 550, "country": "USA" }),
+        );
+        assert!(long.unwrap_err().contains("at most 2 characters"));
+
+        let low = validate("get_movies_by_actor", json!({ "actor_id": 0 }));
+        assert!(low.unwrap_err().contains("at least 1"));
+
+        let high = validate("trending_actors_latest", json!({ "count": 11 }));
+        assert!(high.unwrap_err().contains("at most 10"));
+    }
+
+    #[test]
+    fn drifted_structured_content_is_reported_without_panicking() {
+        let structured = json!({ "id": 31, "name": "Tom Hanks" });
+        let matching = CallToolResult::text_content(vec!["Tom Hanks - TMDB ID: 31".into()])
+            .with_structured_content(structured.as_object().unwrap().clone());
+        let drifted = CallToolResult::text_content(vec!["Meg Ryan - TMDB ID: 5344".into()])
+            .with_structured_content(structured.as_object().unwrap().clone());
+
+        assert_eq!(
+            structured_mismatch_warning("get_actor_info", &matching),
+            None
+        );
+        assert_eq!(
+            structured_mismatch_warning("get_actor_info", &drifted).as_deref(),
+            Some(
+                "get_actor_info: structured content disagrees with the text: \
+                 id 31 is not in the text; name \"Tom Hanks\" is not in the text"
+            )
+        );
+    }
+
+    fn call(name: &str, arguments: serde_json::Value) -> CallToolRequestParams {
+        serde_json::from_value(json!({ "name": name, "arguments": arguments })).unwrap()
+    }
+
+    #[test]
+    fn only_raw_calls_to_tools_taking_raw_skip_truncation() {
+        let tools = list_tools();
+        let budget =
+            |name: &str, arguments| output_budget(&call(name, arguments), &tools, Some(50));
+
+        assert_eq!(
+            budget(
+                "get_actor_info",
+                json!({ "actor_name": "Tom Hanks", "raw": true })
+            ),
+            None
+        );
+        assert_eq!(
+            budget(
+                "get_actor_info",
+                json!({ "actor_name": "Tom Hanks", "raw": false })
+            ),
+            Some(50)
+        );
+        assert_eq!(
+            budget("get_actor_info", json!({ "actor_name": "Tom Hanks" })),
+            Some(50)
+        );
+        // compare_movies takes no `raw`, so its output stays within the budget
+        assert_eq!(
+            budget(
+                "compare_movies",
+                json!({ "movie_id_a": 1, "movie_id_b": 2, "raw": true })
+            ),
+            Some(50)
+        );
+    }
+
+    #[tokio::test]
+    async fn raw_output_past_the_budget_matches_the_upstream_body() {
+        let mock = MockTmdb::start().await;
+        let mut movie = fixtures::movie_full(550, "Fight Club", "1999-10-15");
+        movie["overview"] = "A ticking-time-bomb insomniac meets a soap salesman. "
+            .repeat(20)
+            .into();
+        mock.json("/movie/550", movie.clone());
+        let client = mock.client_with(|config| {
+            config.allow_raw = true;
+            config.experimental_tools = true;
+            config.max_output_tokens = Some(50);
+        });
+        let registry = ToolRegistry::new(client.config());
+        let params = call("get_movie_details", json!({ "movie_id": 550, "raw": true }));
+
+        let budget = output_budget(
+            &params,
+            &registry.tools(),
+            client.config().max_output_tokens,
+        );
+        assert_eq!(budget, None);
+        let result = registry
+            .find("get_movie_details")
+            .unwrap()
+            .call(&client, params.arguments.unwrap())
+            .await
+            .unwrap();
+
+        let text = crate::tools::result_text(&result);
+        assert!(crate::tools::estimate_tokens(&text) > 50);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&text).unwrap(),
+            movie
+        );
+        // the same text would have been cut had it not been raw
+        assert_ne!(
+            crate::tools::result_text(&truncate_output(result, 50)),
+            text
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_characters() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn unknown_tools_suggest_the_closest_name() {
+        let message = unknown_tool_error("get_actor_inf", &list_tools()).to_string();
+
+        assert!(
+            message
+                .starts_with("Unknown tool: \"get_actor_inf\". Did you mean \"get_actor_info\"?")
+        );
+        assert!(message.contains("Available tools: get_actor_info, get_movies_by_actor"));
+    }
+
+    fn argument(name: &str, value: &str) -> CompleteRequestArgument {
+        CompleteRequestArgument {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn completes_actor_names_without_duplicates() {
+        let mock = MockTmdb::start().await;
+        let people: Vec<_> = ["Tom Hanks", "Tom Holland", "Tom Hanks", "Tom Hardy"]
+            .iter()
+            .chain(&["Tom Cruise", "Tom Hiddleston", "Tom Wilkinson"])
+            .zip(1..)
+            .map(|(name, id)| fixtures::person_summary(id, name))
+            .collect();
+        mock.json("/search/person?query=Tom", fixtures::single_page(people));
+
+        let completion = complete_argument(&mock.client(), &argument("actor_name", " Tom "))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            completion.values,
+            [
+                "Tom Hanks",
+                "Tom Holland",
+                "Tom Hardy",
+                "Tom Cruise",
+                "Tom Hiddleston"
+            ]
+        );
+        assert_eq!(completion.total, Some(6));
+        assert_eq!(completion.has_more, Some(true));
+    }
+
+    #[tokio::test]
+    async fn short_input_and_other_arguments_are_not_searched() {
+        let mock = MockTmdb::start().await;
+
+        for argument in [argument("actor_name", "T"), argument("movie_title", "Big")] {
+            let completion = complete_argument(&mock.client(), &argument).await.unwrap();
+            assert!(completion.values.is_empty());
+            assert_eq!(completion.has_more, Some(false));
         }
+        assert!(mock.requests().is_empty());
     }
 }
@@ -0,0 +1,168 @@
+use rust_mcp_sdk::schema::{ReadResourceContent, Resource, TextResourceContents};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Number of documents kept before the least recently used one is dropped.
+const MAX_DOCUMENTS: usize = 64;
+
+/// Documents generated by tools and offered to clients as MCP resources.
+///
+/// A tool such as `export_filmography` builds a document, publishes it here under a stable URI
+/// and returns the URI; clients read the document later through `resources/read`. Publishing
+/// under a URI that is already taken replaces the earlier document, so repeating an export
+/// refreshes it rather than piling up copies. At most `MAX_DOCUMENTS` are kept: publishing
+/// another drops the one least recently published or read, so a long-running server doesn't
+/// grow without bound.
+#[derive(Default)]
+pub struct Exports {
+    documents: Mutex<Documents>,
+}
+
+#[derive(Default)]
+struct Documents {
+    /// Published documents, keyed by URI so they are listed in a stable order
+    by_uri: BTreeMap<String, Document>,
+    /// Incremented on every publish and read, to tell which document was used least recently
+    clock: u64,
+}
+
+struct Document {
+    resource: Resource,
+    text: String,
+    /// Value of `Documents::clock` when the document was last published or read
+    last_used: u64,
+}
+
+impl Documents {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
+
+impl Exports {
+    /// Publishes `text` as the content of `resource`, replacing any document under its URI and
+    /// dropping the least recently used document if the limit is reached.
+    pub fn publish(&self, resource: Resource, text: String) {
+        let mut documents = self.documents.lock().unwrap();
+        let last_used = documents.tick();
+        documents.by_uri.insert(
+            resource.uri.clone(),
+            Document {
+                resource,
+                text,
+                last_used,
+            },
+        );
+
+        if documents.by_uri.len() > MAX_DOCUMENTS
+            && let Some(oldest) = documents
+                .by_uri
+                .iter()
+                .min_by_key(|(_, document)| document.last_used)
+                .map(|(uri, _)| uri.clone())
+        {
+            documents.by_uri.remove(&oldest);
+        }
+    }
+
+    /// Lists the published documents, ordered by URI.
+    pub fn list(&self) -> Vec<Resource> {
+        let documents = self.documents.lock().unwrap();
+        documents
+            .by_uri
+            .values()
+            .map(|document| document.resource.clone())
+            .collect()
+    }
+
+    /// Returns the content of the document published under `uri`, if there is one.
+    pub fn read(&self, uri: &str) -> Option<ReadResourceContent> {
+        let mut documents = self.documents.lock().unwrap();
+        let last_used = documents.tick();
+        let document = documents.by_uri.get_mut(uri)?;
+        document.last_used = last_used;
+        Some(
+            TextResourceContents {
+                meta: None,
+                mime_type: document.resource.mime_type.clone(),
+                text: document.text.clone(),
+                uri: document.resource.uri.clone(),
+            }
+            .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(uri: &str) -> Resource {
+        Resource {
+            annotations: None,
+            description: None,
+            icons: vec![],
+            meta: None,
+            mime_type: Some("application/json".into()),
+            name: uri.to_string(),
+            size: None,
+            title: None,
+            uri: uri.to_string(),
+        }
+    }
+
+    fn read_text(exports: &Exports, uri: &str) -> Option<String> {
+        match exports.read(uri)? {
+            ReadResourceContent::TextResourceContents(contents) => Some(contents.text),
+            other => panic!("expected text contents, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn published_documents_are_listed_and_read_back() {
+        let exports = Exports::default();
+        exports.publish(
+            resource("tmdb://export/actor/31"),
+            "{\"id\":31}".to_string(),
+        );
+        exports.publish(resource("tmdb://export/actor/287"), "{}".to_string());
+        // publishing under a taken URI replaces the document
+        exports.publish(
+            resource("tmdb://export/actor/31"),
+            "{\"id\":31,\"v\":2}".to_string(),
+        );
+
+        let uris: Vec<_> = exports
+            .list()
+            .into_iter()
+            .map(|resource| resource.uri)
+            .collect();
+        assert_eq!(uris, ["tmdb://export/actor/287", "tmdb://export/actor/31"]);
+        assert_eq!(
+            read_text(&exports, "tmdb://export/actor/31").as_deref(),
+            Some("{\"id\":31,\"v\":2}")
+        );
+        assert_eq!(read_text(&exports, "tmdb://export/actor/1"), None);
+    }
+
+    #[test]
+    fn the_least_recently_used_document_is_dropped_past_the_limit() {
+        let exports = Exports::default();
+        for id in 0..MAX_DOCUMENTS {
+            exports.publish(
+                resource(&format!("tmdb://export/actor/{id}")),
+                id.to_string(),
+            );
+        }
+        // reading the oldest document makes the second oldest the least recently used
+        assert!(read_text(&exports, "tmdb://export/actor/0").is_some());
+
+        exports.publish(resource("tmdb://export/actor/new"), String::new());
+
+        assert_eq!(exports.list().len(), MAX_DOCUMENTS);
+        assert_eq!(read_text(&exports, "tmdb://export/actor/1"), None);
+        assert!(read_text(&exports, "tmdb://export/actor/0").is_some());
+        assert!(read_text(&exports, "tmdb://export/actor/new").is_some());
+    }
+}
@@ -0,0 +1,60 @@
+use rust_mcp_sdk::schema::LoggingLevel;
+use std::sync::Mutex;
+
+/// The minimum severity of log messages the client asked for with `logging/setLevel`.
+///
+/// Nothing is sent until the client sets a level.
+#[derive(Default)]
+pub struct LogLevel {
+    level: Mutex<Option<LoggingLevel>>,
+}
+
+impl LogLevel {
+    /// Records the level requested by the client.
+    pub fn set(&self, level: LoggingLevel) {
+        *self.level.lock().unwrap() = Some(level);
+    }
+
+    /// Whether a message at `level` should be sent to the client.
+    pub fn enables(&self, level: LoggingLevel) -> bool {
+        self.level
+            .lock()
+            .unwrap()
+            .is_some_and(|minimum| severity(level) >= severity(minimum))
+    }
+}
+
+/// Rank of a level from least (debug) to most (emergency) severe, as ordered by RFC 5424.
+/// `LoggingLevel`'s derived `Ord` is alphabetical, so it can't be compared directly.
+fn severity(level: LoggingLevel) -> u8 {
+    match level {
+        LoggingLevel::Debug => 0,
+        LoggingLevel::Info => 1,
+        LoggingLevel::Notice => 2,
+        LoggingLevel::Warning => 3,
+        LoggingLevel::Error => 4,
+        LoggingLevel::Critical => 5,
+        LoggingLevel::Alert => 6,
+        LoggingLevel::Emergency => 7,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_is_enabled_until_the_client_sets_a_level() {
+        let log_level = LogLevel::default();
+        assert!(!log_level.enables(LoggingLevel::Emergency));
+
+        log_level.set(LoggingLevel::Info);
+        assert!(log_level.enables(LoggingLevel::Info));
+        assert!(log_level.enables(LoggingLevel::Warning));
+        assert!(!log_level.enables(LoggingLevel::Debug));
+
+        log_level.set(LoggingLevel::Error);
+        assert!(!log_level.enables(LoggingLevel::Info));
+        assert!(log_level.enables(LoggingLevel::Critical));
+    }
+}
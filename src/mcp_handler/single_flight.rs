@@ -0,0 +1,283 @@
+use futures::channel::oneshot;
+use rust_mcp_sdk::schema::{CallToolError, CallToolResult};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+/// Outcome of a tool call as shared with duplicate callers.
+type SharedResult = Result<CallToolResult, SharedError>;
+
+/// What a waiting caller is told when the call it waits for ends.
+enum Handoff {
+    /// The call completed with this outcome.
+    Done(SharedResult),
+    /// The call was cancelled and this caller should run it in its place.
+    Lead,
+}
+
+/// Coalesces identical tool calls that are in flight at the same time.
+///
+/// Clients sometimes retry a call before the first attempt has answered. The first call with a
+/// given key runs the tool; calls with the same key that arrive while it is running wait for
+/// its result instead of sending the same requests to TMDB again. If the running call is
+/// cancelled, the longest-waiting caller runs the tool instead and the others keep waiting.
+/// Nothing is kept once the call completes, so a later identical call runs the tool again.
+#[derive(Default)]
+pub struct SingleFlight {
+    /// Callers waiting for the result of the call running under each key
+    in_flight: Mutex<HashMap<String, Vec<oneshot::Sender<Handoff>>>>,
+}
+
+impl SingleFlight {
+    /// Runs `call`, unless a call with the same `key` is already running, in which case its
+    /// result is returned instead.
+    pub async fn run<F>(&self, key: String, call: F) -> Result<CallToolResult, CallToolError>
+    where
+        F: Future<Output = Result<CallToolResult, CallToolError>>,
+    {
+        let waiting = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            match in_flight.get_mut(&key) {
+                Some(waiters) => {
+                    let (sender, receiver) = oneshot::channel();
+                    waiters.push(sender);
+                    Some(receiver)
+                }
+                None => {
+                    in_flight.insert(key.clone(), Vec::new());
+                    None
+                }
+            }
+        };
+
+        if let Some(receiver) = waiting {
+            match receiver.await {
+                Ok(Handoff::Done(result)) => return result.map_err(CallToolError::new),
+                // the running call was cancelled and this caller now holds the key
+                Ok(Handoff::Lead) => {}
+                // only if the running call's task panicked while handing off
+                Err(_) => return call.await,
+            }
+        }
+
+        // hands the key to a waiter if this call is cancelled, so waiters don't hang
+        let mut guard = InFlightGuard {
+            flight: self,
+            key: Some(key),
+        };
+        let result = call.await;
+        let shared = match &result {
+            Ok(result) => Ok(result.clone()),
+            Err(err) => Err(SharedError::from(err as &dyn Error)),
+        };
+        for waiter in guard.finish() {
+            let _ = waiter.send(Handoff::Done(shared.clone()));
+        }
+        result
+    }
+}
+
+/// Owns a key in `SingleFlight::in_flight` while the call running under it is in progress.
+struct InFlightGuard<'a> {
+    flight: &'a SingleFlight,
+    /// The key, until the call finishes or is handed off
+    key: Option<String>,
+}
+
+impl InFlightGuard<'_> {
+    /// Removes the key and returns the callers waiting for its result.
+    fn finish(&mut self) -> Vec<oneshot::Sender<Handoff>> {
+        let Some(key) = self.key.take() else {
+            return Vec::new();
+        };
+        self.flight
+            .in_flight
+            .lock()
+            .unwrap()
+            .remove(&key)
+            .unwrap_or_default()
+    }
+}
+
+/// Implements Drop so a cancelled call hands its key to the longest-waiting caller, which then
+/// runs the tool for everyone still waiting. The key is removed only if nobody is waiting.
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        let mut in_flight = self.flight.in_flight.lock().unwrap();
+        let Some(waiters) = in_flight.get_mut(&key) else {
+            return;
+        };
+        while !waiters.is_empty() {
+            // a waiter that was cancelled too has dropped its receiver; try the next one
+            if waiters.remove(0).send(Handoff::Lead).is_ok() {
+                return;
+            }
+        }
+        in_flight.remove(&key);
+    }
+}
+
+/// A tool call error shared with duplicate callers. `CallToolError` can be neither cloned nor
+/// sent between threads, so the error is copied with its message and chain of sources.
+#[derive(Clone, Debug)]
+struct SharedError {
+    message: String,
+    source: Option<Arc<SharedError>>,
+}
+
+impl From<&dyn Error> for SharedError {
+    fn from(err: &dyn Error) -> Self {
+        Self {
+            message: err.to_string(),
+            source: err.source().map(|source| Arc::new(Self::from(source))),
+        }
+    }
+}
+
+impl Display for SharedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for SharedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|source| source as _)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// A tool call that counts its runs and answers with `text` after `delay`.
+    async fn counted_call(
+        runs: Arc<AtomicUsize>,
+        text: String,
+        delay: Duration,
+    ) -> Result<CallToolResult, CallToolError> {
+        runs.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(delay).await;
+        Ok(CallToolResult::text_content(vec![text.into()]))
+    }
+
+    fn text(result: &CallToolResult) -> String {
+        crate::tools::result_text(result)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_identical_calls_run_once() {
+        let flight = Arc::new(SingleFlight::default());
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let calls = (0..8).map(|index| {
+            let flight = flight.clone();
+            let runs = runs.clone();
+            tokio::spawn(async move {
+                let call = counted_call(runs, format!("call {index}"), Duration::from_millis(200));
+                flight.run("key".to_string(), call).await.unwrap()
+            })
+        });
+        let results = futures::future::join_all(calls).await;
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        let first = text(results[0].as_ref().unwrap());
+        for result in &results {
+            assert_eq!(text(result.as_ref().unwrap()), first);
+        }
+        assert!(flight.in_flight.lock().unwrap().is_empty());
+
+        // nothing is kept once the call completes
+        let call = counted_call(runs.clone(), "again".to_string(), Duration::ZERO);
+        flight.run("key".to_string(), call).await.unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn a_waiter_takes_over_when_the_running_call_is_cancelled() {
+        let flight = Arc::new(SingleFlight::default());
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let leader = {
+            let (flight, runs) = (flight.clone(), runs.clone());
+            tokio::spawn(async move {
+                let call = counted_call(runs, "leader".to_string(), Duration::from_secs(60));
+                let _ = flight.run("key".to_string(), call).await;
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let waiters: Vec<_> = (0..3)
+            .map(|index| {
+                let (flight, runs) = (flight.clone(), runs.clone());
+                tokio::spawn(async move {
+                    let call =
+                        counted_call(runs, format!("waiter {index}"), Duration::from_millis(100));
+                    flight.run("key".to_string(), call).await.unwrap()
+                })
+            })
+            .collect();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        leader.abort();
+        let results = futures::future::join_all(waiters).await;
+
+        // one waiter ran its call, and the other two shared its result
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        let first = text(results[0].as_ref().unwrap());
+        assert!(first.starts_with("waiter "));
+        for result in &results {
+            assert_eq!(text(result.as_ref().unwrap()), first);
+        }
+        assert!(flight.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn errors_are_shared_with_their_sources() {
+        #[derive(Debug)]
+        struct Timeout;
+        impl Display for Timeout {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("timed out")
+            }
+        }
+        impl Error for Timeout {}
+
+        #[derive(Debug)]
+        struct RequestFailed(Timeout);
+        impl Display for RequestFailed {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("request failed")
+            }
+        }
+        impl Error for RequestFailed {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let flight = SingleFlight::default();
+        let leader = flight.run("key".to_string(), async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Err(CallToolError::new(RequestFailed(Timeout)))
+        });
+        let waiter = flight.run("key".to_string(), async {
+            unreachable!("the waiter shares the leader's error")
+        });
+
+        let (leader, waiter) = tokio::join!(leader, waiter);
+
+        assert_eq!(leader.unwrap_err().to_string(), "request failed");
+        let waiter = waiter.unwrap_err();
+        assert_eq!(waiter.to_string(), "request failed");
+        let source = waiter.0.source().unwrap();
+        assert_eq!(source.to_string(), "timed out");
+        assert!(source.source().is_none());
+    }
+}
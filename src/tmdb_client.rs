@@ -1,282 +1,3756 @@
+mod circuit_breaker;
+mod config;
+mod error;
+mod latency;
+mod rate_limit;
+mod retry_budget;
+#[cfg(test)]
+pub mod test_server;
+
+pub use config::{MissingFields, TmdbConfig, parse_region};
+pub use error::TmdbError;
+pub use latency::LatencyTracker;
+use rate_limit::RateLimiter;
+pub use retry_budget::{BudgetChange, RetryBudget};
+
+use circuit_breaker::CircuitBreaker;
+
 use base64::{Engine, engine::general_purpose};
 use reqwest::{
-    Client,
-    header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue},
+    Client, RequestBuilder, Response, StatusCode,
+    header::{
+        ACCEPT, AUTHORIZATION, CACHE_CONTROL, CONTENT_TYPE, ETAG, HeaderMap, HeaderValue,
+        IF_NONE_MATCH, USER_AGENT,
+    },
 };
-use serde::{Deserialize, Serialize};
+use rust_mcp_sdk::macros::JsonSchema;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt::Display;
-use std::{env, fmt::Formatter};
+use std::fmt::Formatter;
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicBool, Ordering},
+};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Number of characters of a non-JSON response body written to the log.
+const MAX_LOGGED_BODY_CHARS: usize = 200;
+
+/// Number of extra attempts made when an image download fails with a transient error.
+const IMAGE_MAX_RETRIES: u32 = 2;
+
+/// Per-attempt timeout for image downloads. Images are larger than JSON payloads,
+/// so they get a more generous budget.
+const IMAGE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Base delay between image download attempts, doubled after each failure.
+const IMAGE_RETRY_DELAY: Duration = Duration::from_millis(250);
 
-const BASE_URL: &str = "https://api.themoviedb.org/3";
+/// Number of extra attempts made when an API request fails with a transient error.
+const API_MAX_RETRIES: u32 = 2;
+
+/// Base delay between API request attempts, doubled after each failure.
+const API_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// How long this week's trending movies are reused. TMDB's week is a rolling seven days,
+/// recomputed daily, so there is no calendar boundary to wait for.
+const WEEK_TRENDING_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// How long a response is cached when its TTL is too long to represent as an `Instant`,
+/// e.g. a bogus `max-age` or `TMDB_CACHE_TTL_SECS`.
+const MAX_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
 
 /// A simple client for interacting with The Movie Database (TMDB) API.
 pub struct TmdbClient {
     client: Client,
+    config: TmdbConfig,
+    /// Request latency histogram, summarized for clients that enable logging.
+    latency: Arc<LatencyTracker>,
+    /// Limits the rate of outbound requests, absent when rate limiting is disabled.
+    rate_limiter: Option<RateLimiter>,
+    /// Retry permits shared by all requests, so retries stop when TMDB is struggling.
+    retry_budget: RetryBudget,
+    /// Pauses API requests while TMDB keeps answering 503 (e.g. during maintenance)
+    circuit_breaker: CircuitBreaker,
+    /// Last `/configuration` response, revalidated with `If-None-Match` once stale.
+    configuration_cache: Mutex<Option<CachedResponse<TmdbConfiguration>>>,
+    /// Last `/genre/movie/list` response, revalidated with `If-None-Match` once stale.
+    genre_cache: Mutex<Option<CachedResponse<GenreResponse>>>,
+    /// Trending movies per time window, valid for `TimeWindow::cache_ttl`.
+    trending_cache: Mutex<HashMap<TimeWindow, CachedResponse<Paginated<MovieDetail>>>>,
+    /// Whether `genre_names` has already logged that it fell back to genre IDs.
+    genre_fallback_logged: AtomicBool,
+    /// Account ID of the configured session, once looked up by `account_id`.
+    account_id: Mutex<Option<i64>>,
+}
+
+/// A cached response body, when it goes stale, and the `ETag` it was served with (if any).
+struct CachedResponse<T> {
+    etag: Option<String>,
+    expires_at: Instant,
+    value: T,
+}
+
+/// Time window used by the trending endpoints. Derives `JsonSchema` so tools can take it
+/// as an input, advertised to clients as an enum of "day" and "week".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum TimeWindow {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "week")]
+    Week,
+}
+
+impl TimeWindow {
+    /// Path segment used by the TMDB API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeWindow::Day => "day",
+            TimeWindow::Week => "week",
+        }
+    }
+
+    /// How long trending results fetched at `now` stay valid: until the next UTC midnight
+    /// for `Day`, and a fixed `WEEK_TRENDING_TTL` for `Week`, whose window is rolling.
+    fn cache_ttl(&self, now: SystemTime) -> Duration {
+        match self {
+            TimeWindow::Day => {
+                let secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                Duration::from_secs(86_400 - secs % 86_400)
+            }
+            TimeWindow::Week => WEEK_TRENDING_TTL,
+        }
+    }
 }
 
 impl TmdbClient {
-    /// Creates a new TMDB client using the API token from the environment variable `TMDB_TOKEN`.
+    /// Creates a new TMDB client from an explicit configuration.
+    ///
+    /// Use `TmdbConfig::from_env` to read the configuration from environment variables, and
+    /// `TmdbConfig::validate` to check it first.
     ///
     /// # Panics
-    /// Panics if the `TMDB_TOKEN` environment variable is not set.
-    pub fn new() -> Self {
-        let auth_token = env::var("TMDB_TOKEN").expect("TMDB_TOKEN must be set in environment");
+    /// Panics if the token or user agent are not valid header values.
+    pub fn with_config(config: TmdbConfig) -> Self {
         // Build the client with default headers
-        let client = reqwest::Client::builder()
+        let mut builder = reqwest::Client::builder()
             .default_headers({
                 let mut headers = HeaderMap::new();
                 headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
                 headers.insert(
                     AUTHORIZATION,
-                    HeaderValue::from_str(&format!("Bearer {}", auth_token)).unwrap(),
+                    HeaderValue::from_str(&format!("Bearer {}", config.api_token)).unwrap(),
+                );
+                headers.insert(
+                    USER_AGENT,
+                    HeaderValue::from_str(&config.user_agent)
+                        .expect("user agent must be a valid header value"),
                 );
                 headers
             })
-            .build()
-            .unwrap();
-        Self { client }
+            .timeout(config.timeout);
+        // connection pool tuning; reqwest's defaults apply unless configured
+        if let Some(max_idle) = config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+        Self::from_parts(builder.build().unwrap(), config)
+    }
+
+    /// Creates a client that sends its requests with `client` to the API at `base_url`, with
+    /// every other setting at its default and no environment variables read. Headers, timeouts
+    /// and authentication come from `client` alone, so a test can point a preconfigured client
+    /// at a mock server (see `test_server`); the server itself uses `with_config`.
+    #[cfg(test)]
+    pub fn with_client(client: Client, base_url: String) -> Self {
+        Self::from_parts(client, TmdbConfig::with_defaults(String::new(), base_url))
+    }
+
+    /// Wraps a built HTTP client with the state derived from `config`.
+    fn from_parts(client: Client, config: TmdbConfig) -> Self {
+        let rate_limiter = (config.rate_limit > 0.0).then(|| RateLimiter::new(config.rate_limit));
+        let retry_budget = RetryBudget::new(config.retry_budget);
+        let circuit_breaker =
+            CircuitBreaker::new(config.breaker_threshold, config.breaker_cooldown);
+        Self {
+            client,
+            config,
+            latency: Arc::new(LatencyTracker::new()),
+            rate_limiter,
+            retry_budget,
+            circuit_breaker,
+            configuration_cache: Mutex::new(None),
+            genre_cache: Mutex::new(None),
+            trending_cache: Mutex::new(HashMap::new()),
+            genre_fallback_logged: AtomicBool::new(false),
+            account_id: Mutex::new(None),
+        }
+    }
+
+    /// Returns the settings this client was built with.
+    pub fn config(&self) -> &TmdbConfig {
+        &self.config
+    }
+
+    /// Returns the request latency tracker.
+    pub fn latency_tracker(&self) -> Arc<LatencyTracker> {
+        self.latency.clone()
+    }
+
+    /// Returns the retry budget shared by every request, which records when it runs out.
+    pub fn retry_budget(&self) -> &RetryBudget {
+        &self.retry_budget
     }
 
-    /// Retrieves a list of movies featuring the specified actor by TMDB actor ID.
+    /// Sends a request to TMDB. Every outbound request goes through here.
+    ///
+    /// Waits for the rate limiter first, so bursts of tool calls stay under the
+    /// configured requests-per-second.
     ///
     /// # Arguments
-    /// * `actor_id` - The TMDB ID of the actor.
+    /// * `request` - The request to send.
     ///
     /// # Returns
-    /// * `Ok(Vec<MovieDetail>)` - List of movies the actor appeared in.
-    /// * `Err(reqwest::Error)` - If the request or parsing fails.
-    pub async fn movies_by_actor(&self, actor_id: i64) -> Result<Vec<MovieDetail>, reqwest::Error> {
-        // https://api.themoviedb.org/3/discover/movie?with_cast=
-        let url = format!("{BASE_URL}/discover/movie");
+    /// * `Ok(Response)` - The response, whatever its status code.
+    /// * `Err(reqwest::Error)` - If the request could not be completed.
+    async fn execute(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
 
-        let response = self
-            .client
-            .get(url)
-            .query(&[("with_cast", actor_id.to_string())])
-            .send()
-            .await?;
+        let started = Instant::now();
+        let response = request.send().await;
+        self.latency.record(started.elapsed());
+        response
+    }
+
+    /// Sends an API request to TMDB, unless the circuit breaker is open after repeated
+    /// `503 Service Unavailable` responses, in which case it fails immediately.
+    ///
+    /// Connection errors, timeouts, server errors (5xx) and rate limiting (429) are retried up
+    /// to `API_MAX_RETRIES` times, each retry taking a permit from the client-wide
+    /// `RetryBudget`. The last response is returned as is once retries run out, the budget is
+    /// used up or the breaker opens.
+    ///
+    /// # Arguments
+    /// * `request` - The request to send.
+    ///
+    /// # Returns
+    /// * `Ok(Response)` - The response, whatever its status code.
+    /// * `Err(TmdbError)` - If the breaker is open or the request could not be completed.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, TmdbError> {
+        if let Err(retry_after) = self.circuit_breaker.try_acquire() {
+            return Err(TmdbError::CircuitOpen { retry_after });
+        }
+
+        let mut request = request;
+        let mut attempt = 0;
+        loop {
+            // GET requests have no body, so they can always be cloned for another attempt
+            let next = request.try_clone();
+            let result = self.execute(request).await;
+            if let Ok(response) = &result {
+                self.circuit_breaker
+                    .record(response.status() == StatusCode::SERVICE_UNAVAILABLE);
+            }
 
-        let result: MovieResponse = response.json().await?;
+            let Some(next) = next.filter(|_| attempt < API_MAX_RETRIES && is_retryable(&result))
+            else {
+                return Ok(result?);
+            };
+            if self.circuit_breaker.is_open() {
+                return Ok(result?);
+            }
+            // fail fast once retries across the whole client have used up the shared budget
+            if !self.retry_budget.try_acquire() {
+                return Ok(result?);
+            }
 
-        Ok(result.results)
+            tokio::time::sleep(API_RETRY_DELAY * 2u32.pow(attempt)).await;
+            attempt += 1;
+            request = next;
+        }
     }
 
-    /// Searches for an actor by name and returns their TMDB ID if found.
-    /// this is used internally to find actor id by name, other details will be retrieved by other endpoints
+    /// Builds a full API URL from a path such as `/person/31`.
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.config.base_url)
+    }
+
+    /// Value of the `include_adult` parameter sent with search and discover requests.
+    fn include_adult(&self) -> &'static str {
+        if self.config.safe_search {
+            "false"
+        } else {
+            "true"
+        }
+    }
+
+    /// Removes adult entries, and the adult entries nested in the others, when safe search is
+    /// on. TMDB's `include_adult` only applies to search and discover, so every list of
+    /// movies, credits or people a method returns goes through this as well.
+    fn hide_adult<T: AdultContent>(&self, items: &mut Vec<T>) {
+        if self.config.safe_search {
+            items.retain(|item| !item.is_adult());
+            items.iter_mut().for_each(AdultContent::hide_nested_adult);
+        }
+    }
+
+    /// Like `hide_adult`, for a page of results whose totals then leave the hidden entries out.
+    fn hide_adult_results<T: AdultContent>(&self, page: &mut Paginated<T>) {
+        if self.config.safe_search {
+            page.retain(|item| !item.is_adult());
+            page.results
+                .iter_mut()
+                .for_each(AdultContent::hide_nested_adult);
+        }
+    }
+
+    /// Fetches a JSON resource that rarely changes, caching it.
+    ///
+    /// A cached value is reused without a request while it is fresh: for the `max-age` TMDB
+    /// sent in `Cache-Control`, or the configured cache TTL if it sent none. Once stale, a
+    /// cached `ETag` is sent as `If-None-Match` and a `304 Not Modified` reply renews it.
     ///
     /// # Arguments
-    /// * `actor_name` - The name of the actor to search for.
+    /// * `url` - The full URL of the resource.
+    /// * `cache` - The cache slot holding the last response for this resource.
     ///
     /// # Returns
-    /// * `Ok(Some(id))` - The TMDB ID of the actor if found.
-    /// * `Ok(None)` - If no actor is found.
-    /// * `Err(reqwest::Error)` - If the request or parsing fails.
-    async fn actor_id(&self, actor_name: &str) -> Result<Option<i64>, reqwest::Error> {
-        // https://api.themoviedb.org/3/search/person?query=
-        let url = format!("{BASE_URL}/search/person");
+    /// * `Ok(T)` - The fresh or cached value.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    async fn get_cached<T: Clone + DeserializeOwned + Serialize + IgnoredFields>(
+        &self,
+        url: String,
+        cache: &Mutex<Option<CachedResponse<T>>>,
+    ) -> Result<T, TmdbError> {
+        let cached_etag = {
+            let cached = cache.lock().unwrap();
+            match cached.as_ref() {
+                Some(cached) if Instant::now() < cached.expires_at => {
+                    return Ok(cached.value.clone());
+                }
+                cached => cached.and_then(|cached| cached.etag.clone()),
+            }
+        };
+
+        let mut request = self.client.get(url);
+        if let Some(etag) = &cached_etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = self.send(request).await?;
+        let expires_at = expires_after(self.freshness(response.headers()));
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(cached) = cache.lock().unwrap().as_mut()
+        {
+            cached.expires_at = expires_at;
+            return Ok(cached.value.clone());
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let value: T = parse_json(response).await?;
+
+        *cache.lock().unwrap() = Some(CachedResponse {
+            etag,
+            expires_at,
+            value: value.clone(),
+        });
+
+        Ok(value)
+    }
+
+    /// How long a response may be reused: its `Cache-Control: max-age`, no time at all for
+    /// `no-cache`/`no-store`, or the configured cache TTL when the header says neither.
+    fn freshness(&self, headers: &HeaderMap) -> Duration {
+        let Some(cache_control) = headers
+            .get(CACHE_CONTROL)
+            .and_then(|value| value.to_str().ok())
+        else {
+            return self.config.cache_ttl;
+        };
+
+        for directive in cache_control.split(',').map(str::trim) {
+            if directive.eq_ignore_ascii_case("no-cache")
+                || directive.eq_ignore_ascii_case("no-store")
+            {
+                return Duration::ZERO;
+            }
+            if let Some((name, seconds)) = directive.split_once('=')
+                && name.trim().eq_ignore_ascii_case("max-age")
+                && let Ok(seconds) = seconds.trim().trim_matches('"').parse()
+            {
+                return Duration::from_secs(seconds);
+            }
+        }
+        self.config.cache_ttl
+    }
+
+    /// Retrieves the TMDB API configuration (image base URLs and available sizes).
+    ///
+    /// The response is cached for as long as TMDB allows (see `get_cached`).
+    ///
+    /// # Returns
+    /// * `Ok(TmdbConfiguration)` - The API configuration.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn configuration(&self) -> Result<TmdbConfiguration, TmdbError> {
+        // https://api.themoviedb.org/3/configuration
+        self.get_cached(self.url("/configuration"), &self.configuration_cache)
+            .await
+    }
+
+    /// Retrieves the list of official movie genres.
+    ///
+    /// The response is cached for as long as TMDB allows (see `get_cached`).
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Genre>)` - All movie genres with their TMDB IDs.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movie_genres(&self) -> Result<Vec<Genre>, TmdbError> {
+        // https://api.themoviedb.org/3/genre/movie/list
         let response = self
-            .client
-            .get(url)
-            .query(&[("query", actor_name), ("language", "en-US")])
-            .send()
+            .get_cached(
+                self.url(&format!(
+                    "/genre/movie/list?language={}",
+                    self.config.language
+                )),
+                &self.genre_cache,
+            )
             .await?;
+        Ok(response.genres)
+    }
 
-        let json_value: Value = response.json().await?;
+    /// Retrieves genre names by ID, for labelling movies with their genres.
+    ///
+    /// Genre names only enrich output, so a failure to fetch them does not fail the caller:
+    /// the returned `GenreNames` then labels genres by ID, and the first such failure is logged.
+    pub async fn genre_names(&self) -> GenreNames {
+        match self.movie_genres().await {
+            Ok(genres) => GenreNames(Some(
+                genres
+                    .into_iter()
+                    .map(|genre| (genre.id, genre.name))
+                    .collect(),
+            )),
+            Err(err) => {
+                if !self.genre_fallback_logged.swap(true, Ordering::Relaxed) {
+                    eprintln!("genre list unavailable, showing genre IDs instead of names: {err}");
+                }
+                GenreNames(None)
+            }
+        }
+    }
 
-        // extract the .results.id from the response json and return it
-        Ok(json_value
-            .get("results")
-            .and_then(|r| r.as_array())
-            .and_then(|arr| {
-                arr.iter()
-                    .find_map(|item| item.get("id").and_then(|id| id.as_i64()))
-            }))
+    /// Fetches the genre list and API configuration so they are cached before the first tool call.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If both were fetched.
+    /// * `Err(TmdbError)` - If either request fails.
+    pub async fn warm_up(&self) -> Result<(), TmdbError> {
+        let (genres, configuration) = tokio::join!(self.movie_genres(), self.configuration());
+        genres?;
+        configuration?;
+        Ok(())
     }
 
-    /// Retrieves detailed information about an actor by name.
+    /// Retrieves the movies a person appeared in, including the character they played.
     ///
     /// # Arguments
-    /// * `actor_name` - The name of the actor.
+    /// * `person_id` - The TMDB ID of the person.
     ///
     /// # Returns
-    /// * `Ok(Some(PersonDetails))` - Detailed info if the actor is found.
-    /// * `Ok(None)` - If no actor is found.
-    /// * `Err(reqwest::Error)` - If the request or parsing fails.
-    pub async fn actor_info(
+    /// * `Ok(Vec<MovieCredit>)` - The person's movie cast credits.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn person_movie_credits(
         &self,
-        actor_name: &str,
-    ) -> Result<Option<PersonDetails>, reqwest::Error> {
-        let Some(person_id) = self.actor_id(actor_name).await? else {
-            return Ok(None);
-        };
-
-        // https://api.themoviedb.org/3/search/person/{id}
+        person_id: i64,
+    ) -> Result<Vec<MovieCredit>, TmdbError> {
+        // https://api.themoviedb.org/3/person/{id}/movie_credits
         let response = self
-            .client
-            .get(format!("{BASE_URL}/person/{person_id}"))
-            .send()
+            .send(
+                self.client
+                    .get(self.url(&format!("/person/{person_id}/movie_credits")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
             .await?;
 
-        Ok(Some(response.json::<PersonDetails>().await?))
+        let mut result: MovieCreditsResponse = parse_json(response).await?;
+        self.hide_adult(&mut result.cast);
+
+        Ok(result.cast)
     }
 
-    /// Resolves a TMDB image path to a full image URL.
+    /// Retrieves the movies and TV shows a person worked on behind the camera.
     ///
     /// # Arguments
-    /// * `image_path` - The relative path to the image from TMDB.
+    /// * `person_id` - The TMDB ID of the person.
     ///
     /// # Returns
-    /// * `String` - The full URL to the image.
-    pub fn resolve_image_url(image_path: &str) -> String {
-        let image_size = "w92";
-        format!("https://image.tmdb.org/t/p/{image_size}{image_path}")
+    /// * `Ok(Vec<CrewCredit>)` - The person's crew credits, one per job and title.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn person_crew_credits(&self, person_id: i64) -> Result<Vec<CrewCredit>, TmdbError> {
+        // https://api.themoviedb.org/3/person/{id}/combined_credits
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/person/{person_id}/combined_credits")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
+
+        let mut result: CombinedCreditsResponse = parse_json(response).await?;
+        self.hide_adult(&mut result.crew);
+
+        Ok(result.crew)
     }
 
-    /// Downloads an image from a URL and encodes it as a base64 string.
+    /// Retrieves full details for a movie, including runtime, budget and revenue.
     ///
     /// # Arguments
-    /// * `image_url` - The full URL to the image.
+    /// * `movie_id` - The TMDB ID of the movie.
     ///
     /// # Returns
-    /// * `Ok(String)` - The base64-encoded image data.
-    /// * `Err(reqwest::Error)` - If the request or encoding fails.
-    async fn image_url_to_base64(&self, image_url: &str) -> Result<String, reqwest::Error> {
+    /// * `Ok(Some(MovieFull))` - The movie details if the movie exists.
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movie_details(&self, movie_id: i64) -> Result<Option<MovieFull>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}
         let response = self
-            .client
-            .get(image_url)
-            .send()
-            .await?
-            .error_for_status()?;
+            .send(
+                self.client
+                    .get(self.url(&format!("/movie/{movie_id}")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
 
-        let bytes = response.bytes().await?;
+        Ok(Some(parse_json(response).await?))
+    }
 
-        let base64_string = general_purpose::STANDARD.encode(&bytes);
+    /// Retrieves the movies the user of the configured session marked as favorites.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<MovieDetail>)` - The first page (up to 20) of favorite movies, oldest first.
+    /// * `Err(TmdbError)` - `TmdbError::NoSession` without `TMDB_SESSION_ID`, or if the
+    ///   request or parsing fails, or TMDB reports an error.
+    pub async fn account_favorites(&self) -> Result<Paginated<MovieDetail>, TmdbError> {
+        // https://api.themoviedb.org/3/account/{account_id}/favorite/movies?session_id=
+        let mut result: Paginated<MovieDetail> = self.account_json("favorite/movies").await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
 
-        Ok(base64_string)
+    /// Retrieves the movies the user of the configured session has rated, with their ratings.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<RatedMovie>)` - The first page (up to 20) of rated movies, oldest rating first.
+    /// * `Err(TmdbError)` - `TmdbError::NoSession` without `TMDB_SESSION_ID`, or if the
+    ///   request or parsing fails, or TMDB reports an error.
+    pub async fn account_rated_movies(&self) -> Result<Paginated<RatedMovie>, TmdbError> {
+        // https://api.themoviedb.org/3/account/{account_id}/rated/movies?session_id=
+        let mut result: Paginated<RatedMovie> = self.account_json("rated/movies").await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
     }
 
-    /// Retrieves an image from TMDB by its path and returns it as a base64 string.
+    /// Fetches a resource of the configured session's account, e.g. `favorite/movies`.
+    ///
+    /// The session ID is sent in the query string, and `reqwest` errors include the URL, so
+    /// URLs are stripped from the errors returned here to keep the session out of tool
+    /// results and logs.
+    async fn account_json<T: DeserializeOwned + Serialize + IgnoredFields>(
+        &self,
+        path: &str,
+    ) -> Result<T, TmdbError> {
+        let session_id = self
+            .config
+            .session_id
+            .as_deref()
+            .ok_or(TmdbError::NoSession)?;
+
+        let result = async {
+            let account_id = self.account_id(session_id).await?;
+            let response = self
+                .send(
+                    self.client
+                        .get(self.url(&format!("/account/{account_id}/{path}")))
+                        .query(&[
+                            ("session_id", session_id),
+                            ("language", self.config.language.as_str()),
+                        ]),
+                )
+                .await?;
+            parse_json(response).await
+        }
+        .await;
+
+        result.map_err(|err| match err {
+            TmdbError::Http(err) => TmdbError::Http(err.without_url()),
+            err => err,
+        })
+    }
+
+    /// Returns the account ID of the session, looking it up on first use. A session belongs
+    /// to one account for its whole life, so the ID is kept for the life of the client.
+    async fn account_id(&self, session_id: &str) -> Result<i64, TmdbError> {
+        if let Some(account_id) = *self.account_id.lock().unwrap() {
+            return Ok(account_id);
+        }
+
+        // https://api.themoviedb.org/3/account?session_id=
+        let response = self
+            .send(
+                self.client
+                    .get(self.url("/account"))
+                    .query(&[("session_id", session_id)]),
+            )
+            .await?;
+        let account: AccountDetails = parse_json(response).await?;
+
+        *self.account_id.lock().unwrap() = Some(account.id);
+        Ok(account.id)
+    }
+
+    /// Retrieves the movies TMDB recommends to people who liked a movie.
     ///
     /// # Arguments
-    /// * `image_path` - The relative path to the image from TMDB.
+    /// * `movie_id` - The TMDB ID of the movie.
     ///
     /// # Returns
-    /// * `Ok(String)` - The base64-encoded image data.
-    /// * `Err(reqwest::Error)` - If the request or encoding fails.
-    pub async fn image_as_base64(&self, image_path: &str) -> Result<String, reqwest::Error> {
-        self.image_url_to_base64(Self::resolve_image_url(image_path).as_str())
-            .await
-    }
-}
+    /// * `Ok(Some(Paginated<MovieDetail>))` - The first page (up to 20) of recommendations if the movie exists.
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movie_recommendations(
+        &self,
+        movie_id: i64,
+    ) -> Result<Option<Paginated<MovieDetail>>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}/recommendations
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/movie/{movie_id}/recommendations")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
 
-// TMDB Types
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MovieDetail {
-    pub adult: bool,
-    pub backdrop_path: Option<String>,
-    pub genre_ids: Vec<u32>,
-    pub id: i64,
-    pub original_language: String,
-    pub original_title: String,
-    pub overview: String,
-    pub popularity: f64,
-    pub poster_path: Option<String>,
-    pub release_date: String,
-    pub title: String,
-    pub video: bool,
-    pub vote_average: f64,
-    pub vote_count: u32,
-}
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
 
-/// Implements Display for MovieDetail to show the movie title and release year (if available).
-impl Display for MovieDetail {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let release_year = if self.release_date.len() > 4 {
-            Some(self.release_date[0..4].to_string())
-        } else {
-            None
-        };
-        write!(
-            f,
-            "{} {}",
-            self.title,
-            release_year
-                .map(|year| format!("({year})"))
-                .unwrap_or_default()
-        )
+        let mut result: Paginated<MovieDetail> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(Some(result))
     }
-}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MovieResponse {
-    results: Vec<MovieDetail>,
-}
+    /// Retrieves a collection (franchise) with all of its movies.
+    ///
+    /// # Arguments
+    /// * `collection_id` - The TMDB ID of the collection (see `MovieFull::belongs_to_collection`).
+    ///
+    /// # Returns
+    /// * `Ok(Some(Collection))` - The collection if it exists.
+    /// * `Ok(None)` - If no collection has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn collection(&self, collection_id: i64) -> Result<Option<Collection>, TmdbError> {
+        // https://api.themoviedb.org/3/collection/{id}
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/collection/{collection_id}")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PersonDetails {
-    /// Whether the person is marked as adult content
-    pub adult: bool,
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
 
-    /// Alternative names in different languages/scripts
-    #[serde(rename = "also_known_as")]
-    pub also_known_as: Vec<String>,
+        let mut collection: Collection = parse_json(response).await?;
+        self.hide_adult(&mut collection.parts);
+        Ok(Some(collection))
+    }
 
-    /// Biography text (often sourced from Wikipedia or TMDB editors)
-    pub biography: String,
+    /// Retrieves where a movie can be watched in one region (data from JustWatch, which TMDB
+    /// asks to be credited when it is shown).
+    ///
+    /// # Arguments
+    /// * `movie_id` - The TMDB ID of the movie.
+    /// * `region` - ISO 3166-1 region code, e.g. `US`.
+    ///
+    /// # Returns
+    /// * `Ok(Some(WatchProviders))` - The providers in that region (all empty if there are none).
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movie_watch_providers(
+        &self,
+        movie_id: i64,
+        region: &str,
+    ) -> Result<Option<WatchProviders>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}/watch/providers
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/movie/{movie_id}/watch/providers"))),
+            )
+            .await?;
 
-    /// Birth date in YYYY-MM-DD format
-    pub birthday: Option<String>,
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
 
-    /// Death date in YYYY-MM-DD format (null if still alive)
-    pub deathday: Option<String>,
+        // the endpoint has no region filter; it returns every region at once
+        let mut result: WatchProvidersResponse = parse_json(response).await?;
+        Ok(Some(result.results.remove(region).unwrap_or_default()))
+    }
 
-    /// Gender code (0 = unknown, 1 = female, 2 = male, 3 = non-binary)
-    pub gender: u8,
+    /// Retrieves the cast (in billing order) and crew of a movie.
+    ///
+    /// # Arguments
+    /// * `movie_id` - The TMDB ID of the movie.
+    ///
+    /// # Returns
+    /// * `Ok(Some(MovieCredits))` - The movie's credits if the movie exists.
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movie_credits(&self, movie_id: i64) -> Result<Option<MovieCredits>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}/credits
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/movie/{movie_id}/credits")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
 
-    /// Official personal or agency homepage URL (often null)
-    pub homepage: Option<String>,
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
 
-    /// TMDB person ID
-    pub id: u32,
+        let mut credits: MovieCredits = parse_json(response).await?;
+        self.hide_adult(&mut credits.cast);
+        self.hide_adult(&mut credits.crew);
+        Ok(Some(credits))
+    }
 
-    /// Corresponding IMDb ID (with "nm" prefix)
-    #[serde(rename = "imdb_id")]
-    pub imdb_id: Option<String>,
+    /// Retrieves the posters and backdrops available for a movie, best rated first.
+    ///
+    /// # Arguments
+    /// * `movie_id` - The TMDB ID of the movie.
+    ///
+    /// # Returns
+    /// * `Ok(Some(MovieImages))` - The movie's images if the movie exists.
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movie_images(&self, movie_id: i64) -> Result<Option<MovieImages>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}/images
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/movie/{movie_id}/images"))),
+            )
+            .await?;
 
-    /// Primary department this person is known for
-    #[serde(rename = "known_for_department")]
-    pub known_for_department: String,
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
 
-    /// Primary name used for display
-    pub name: String,
+        Ok(Some(parse_json(response).await?))
+    }
 
-    /// Place of birth (city, country, etc.)
-    #[serde(rename = "place_of_birth")]
-    pub place_of_birth: Option<String>,
+    /// Retrieves summary details of a TV series.
+    ///
+    /// # Arguments
+    /// * `series_id` - The TMDB ID of the series.
+    ///
+    /// # Returns
+    /// * `Ok(Some(TvSeries))` - The series details if the series exists.
+    /// * `Ok(None)` - If no series has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn tv_series(&self, series_id: i64) -> Result<Option<TvSeries>, TmdbError> {
+        // https://api.themoviedb.org/3/tv/{id}
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/tv/{series_id}")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
 
-    /// Popularity score (higher = more popular)
-    pub popularity: f64,
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
 
-    /// Relative path to profile image
-    #[serde(rename = "profile_path")]
-    pub profile_path: Option<String>,
-}
+        Ok(Some(parse_json(response).await?))
+    }
 
-impl Display for PersonDetails {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            r#"ID: {}
-Name: {}
-Date of Birth: {}
-Place of Birth: {}
-Biography: {}"#,
-            self.id,
-            self.name,
-            self.birthday.as_deref().unwrap_or_default(),
-            self.place_of_birth.as_deref().unwrap_or_default(),
-            self.biography
-        )
+    /// Retrieves a season of a TV series, including its episodes.
+    ///
+    /// # Arguments
+    /// * `series_id` - The TMDB ID of the series.
+    /// * `season` - The season number (0 holds specials on TMDB).
+    ///
+    /// # Returns
+    /// * `Ok(Some(TvSeason))` - The season if both the series and season exist.
+    /// * `Ok(None)` - If there is no such series or season.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn tv_season(
+        &self,
+        series_id: i64,
+        season: u32,
+    ) -> Result<Option<TvSeason>, TmdbError> {
+        // https://api.themoviedb.org/3/tv/{id}/season/{season}
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/tv/{series_id}/season/{season}")))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        Ok(Some(parse_json(response).await?))
+    }
+
+    /// Retrieves the titles a movie is known by in different countries.
+    ///
+    /// # Arguments
+    /// * `movie_id` - The TMDB ID of the movie.
+    ///
+    /// # Returns
+    /// * `Ok(Some(Vec<AlternativeTitle>))` - The alternative titles if the movie exists.
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn alternative_titles(
+        &self,
+        movie_id: i64,
+    ) -> Result<Option<Vec<AlternativeTitle>>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}/alternative_titles
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/movie/{movie_id}/alternative_titles"))),
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let result: AlternativeTitlesResponse = parse_json(response).await?;
+
+        Ok(Some(result.titles))
+    }
+
+    /// Retrieves the keywords tagged on a movie (e.g. "boxer", "underdog").
+    ///
+    /// # Arguments
+    /// * `movie_id` - The TMDB ID of the movie.
+    ///
+    /// # Returns
+    /// * `Ok(Some(Vec<Keyword>))` - The movie's keywords if the movie exists.
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movie_keywords(&self, movie_id: i64) -> Result<Option<Vec<Keyword>>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}/keywords
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/movie/{movie_id}/keywords"))),
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let result: KeywordsResponse = parse_json(response).await?;
+
+        Ok(Some(result.keywords))
+    }
+
+    /// Retrieves the movies trending on TMDB for the given time window.
+    ///
+    /// Results are cached per time window: until the next UTC day for `Day`, and for an hour
+    /// for `Week`, since TMDB's week is a rolling window that shifts every day.
+    ///
+    /// # Arguments
+    /// * `time_window` - Whether to get today's or this week's trending movies.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<MovieDetail>)` - The first page (20) of trending movies, most trending first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn trending_movies(
+        &self,
+        time_window: TimeWindow,
+    ) -> Result<Paginated<MovieDetail>, TmdbError> {
+        if let Some(cached) = self.trending_cache.lock().unwrap().get(&time_window)
+            && cached.expires_at > Instant::now()
+        {
+            return Ok(cached.value.clone());
+        }
+
+        // https://api.themoviedb.org/3/trending/movie/{time_window}
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/trending/movie/{}", time_window.as_str())))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
+
+        let mut result: Paginated<MovieDetail> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+
+        self.trending_cache.lock().unwrap().insert(
+            time_window,
+            CachedResponse {
+                etag: None,
+                expires_at: expires_after(time_window.cache_ttl(SystemTime::now())),
+                value: result.clone(),
+            },
+        );
+
+        Ok(result)
+    }
+
+    /// Retrieves the people trending on TMDB for the given time window.
+    ///
+    /// # Arguments
+    /// * `time_window` - Whether to get today's or this week's trending people.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<PersonSummary>)` - The first page (20) of trending people, most trending first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn trending_people(
+        &self,
+        time_window: TimeWindow,
+    ) -> Result<Paginated<PersonSummary>, TmdbError> {
+        // https://api.themoviedb.org/3/trending/person/{time_window}
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/trending/person/{}", time_window.as_str())))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
+
+        let mut result: Paginated<PersonSummary> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Searches for an actor by name and returns their TMDB ID if found.
+    /// this is used internally to find actor id by name, other details will be retrieved by other endpoints
+    ///
+    /// # Arguments
+    /// * `actor_name` - The name of the actor to search for.
+    ///
+    /// # Returns
+    /// * `Ok(Some(id))` - The TMDB ID of the actor if found.
+    /// * `Ok(None)` - If the search has no results.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error. A response
+    ///   without a `results` array, or whose first result has no numeric `id`, is a
+    ///   `TmdbError::Decode` rather than "not found", so changes to TMDB's schema get noticed.
+    pub async fn actor_id(&self, actor_name: &str) -> Result<Option<i64>, TmdbError> {
+        // https://api.themoviedb.org/3/search/person?query=
+        let url = self.url("/search/person");
+        let response = self
+            .send(self.client.get(url).query(&[
+                ("query", actor_name),
+                ("language", self.config.language.as_str()),
+                ("include_adult", self.include_adult()),
+            ]))
+            .await?;
+
+        let json_value: Value = parse_json(response).await?;
+
+        // extract the .results[0].id from the response json and return it
+        let results = json_value
+            .get("results")
+            .and_then(Value::as_array)
+            .ok_or_else(|| {
+                TmdbError::Decode(serde::de::Error::custom(
+                    "person search response has no `results` array",
+                ))
+            })?;
+        let hidden = |result: &&Value| {
+            self.config.safe_search && result.get("adult").and_then(Value::as_bool) == Some(true)
+        };
+        let Some(first) = results.iter().find(|result| !hidden(result)) else {
+            return Ok(None);
+        };
+        first
+            .get("id")
+            .and_then(Value::as_i64)
+            .map(Some)
+            .ok_or_else(|| {
+                TmdbError::Decode(serde::de::Error::custom(
+                    "person search result has no numeric `id`",
+                ))
+            })
+    }
+
+    /// Searches for people whose name matches `query`, most relevant first.
+    ///
+    /// # Arguments
+    /// * `query` - The full or partial name to search for.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<PersonSummary>)` - The first page (up to 20) of matching people (empty if none match).
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn search_people(&self, query: &str) -> Result<Paginated<PersonSummary>, TmdbError> {
+        // https://api.themoviedb.org/3/search/person?query=
+        let response = self
+            .send(self.client.get(self.url("/search/person")).query(&[
+                ("query", query),
+                ("language", self.config.language.as_str()),
+                ("include_adult", self.include_adult()),
+            ]))
+            .await?;
+
+        let mut result: Paginated<PersonSummary> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Retrieves one page (20 people) of the people currently popular on TMDB.
+    ///
+    /// # Arguments
+    /// * `page` - The page to fetch, starting at 1. Pages past [`MAX_PAGE`], which TMDB
+    ///   refuses, are clamped to it.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<PersonSummary>)` - The people on that page, most popular first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn popular_people(&self, page: u32) -> Result<Paginated<PersonSummary>, TmdbError> {
+        // https://api.themoviedb.org/3/person/popular
+        let response = self
+            .send(self.client.get(self.url("/person/popular")).query(&[
+                ("page", page.min(MAX_PAGE).to_string().as_str()),
+                ("language", self.config.language.as_str()),
+            ]))
+            .await?;
+
+        let mut result: Paginated<PersonSummary> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Finds movies that all of the given actors are in the cast of, using TMDB's discover
+    /// endpoint. Crew credits don't count, so a director who also acted elsewhere doesn't match.
+    ///
+    /// # Arguments
+    /// * `actor_ids` - TMDB IDs of the actors who must all be in the cast.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<MovieDetail>)` - The first page (up to 20) of matching movies, most popular first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movies_with_cast(
+        &self,
+        actor_ids: &[i64],
+    ) -> Result<Paginated<MovieDetail>, TmdbError> {
+        // a comma-separated list means AND (a pipe-separated one would mean OR)
+        let with_cast = actor_ids
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        // https://api.themoviedb.org/3/discover/movie?with_cast=
+        let response = self
+            .send(self.client.get(self.url("/discover/movie")).query(&[
+                ("with_cast", with_cast.as_str()),
+                ("sort_by", "popularity.desc"),
+                ("language", self.config.language.as_str()),
+                ("include_adult", self.include_adult()),
+            ]))
+            .await?;
+
+        let mut result: Paginated<MovieDetail> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Finds the movies an actor appeared in that were first released in a given year,
+    /// using TMDB's discover endpoint.
+    ///
+    /// # Arguments
+    /// * `actor_id` - The TMDB ID of the actor.
+    /// * `year` - The primary release year.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<MovieDetail>)` - The first page (up to 20) of matching movies, most popular first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn actor_movies_in_year(
+        &self,
+        actor_id: i64,
+        year: u32,
+    ) -> Result<Paginated<MovieDetail>, TmdbError> {
+        // https://api.themoviedb.org/3/discover/movie?with_cast=&primary_release_year=
+        let response = self
+            .send(self.client.get(self.url("/discover/movie")).query(&[
+                ("with_cast", actor_id.to_string().as_str()),
+                ("primary_release_year", year.to_string().as_str()),
+                ("sort_by", "popularity.desc"),
+                ("language", self.config.language.as_str()),
+                ("include_adult", self.include_adult()),
+            ]))
+            .await?;
+
+        let mut result: Paginated<MovieDetail> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Searches for movies by title.
+    ///
+    /// # Arguments
+    /// * `query` - The title (or part of it) to search for.
+    /// * `year` - Optional release year; only movies first released that year are returned.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<MovieDetail>)` - The first page (up to 20) of matches, most relevant first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn search_movies(
+        &self,
+        query: &str,
+        year: Option<u32>,
+    ) -> Result<Paginated<MovieDetail>, TmdbError> {
+        // https://api.themoviedb.org/3/search/movie?query=&primary_release_year=
+        let mut request = self.client.get(self.url("/search/movie")).query(&[
+            ("query", query),
+            ("language", self.config.language.as_str()),
+            ("include_adult", self.include_adult()),
+        ]);
+        if let Some(year) = year {
+            request = request.query(&[("primary_release_year", year.to_string())]);
+        }
+        let response = self.send(request).await?;
+
+        let mut result: Paginated<MovieDetail> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Retrieves the most popular movies produced by a company, using TMDB's discover endpoint.
+    ///
+    /// # Arguments
+    /// * `company_id` - The TMDB company ID (see `MovieFull::production_companies`).
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<MovieDetail>)` - The first page (up to 20) of movies, most popular first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn movies_by_company(
+        &self,
+        company_id: i64,
+    ) -> Result<Paginated<MovieDetail>, TmdbError> {
+        // https://api.themoviedb.org/3/discover/movie?with_companies=
+        let response = self
+            .send(self.client.get(self.url("/discover/movie")).query(&[
+                ("with_companies", company_id.to_string().as_str()),
+                ("sort_by", "popularity.desc"),
+                ("language", self.config.language.as_str()),
+                ("include_adult", self.include_adult()),
+            ]))
+            .await?;
+
+        let mut result: Paginated<MovieDetail> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Retrieves the movies in a genre that are currently most popular, using TMDB's discover endpoint.
+    ///
+    /// # Arguments
+    /// * `genre_id` - The TMDB genre ID (see `movie_genres`).
+    /// * `region` - Optional ISO 3166-1 code; release dates in that region are used when
+    ///   TMDB sorts and filters by date.
+    ///
+    /// # Returns
+    /// * `Ok(Paginated<MovieDetail>)` - The first page (up to 20) of movies, most popular first.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn popular_movies_in_genre(
+        &self,
+        genre_id: u32,
+        region: Option<&str>,
+    ) -> Result<Paginated<MovieDetail>, TmdbError> {
+        // https://api.themoviedb.org/3/discover/movie?with_genres=&region=
+        let mut request = self.client.get(self.url("/discover/movie")).query(&[
+            ("with_genres", genre_id.to_string().as_str()),
+            ("sort_by", "popularity.desc"),
+            ("language", self.config.language.as_str()),
+            ("include_adult", self.include_adult()),
+        ]);
+        if let Some(region) = region {
+            request = request.query(&[("region", region)]);
+        }
+        let response = self.send(request).await?;
+
+        let mut result: Paginated<MovieDetail> = parse_json(response).await?;
+        self.hide_adult_results(&mut result);
+        Ok(result)
+    }
+
+    /// Retrieves detailed information about an actor by name.
+    ///
+    /// The best search match is combined with the person's full details, so fields only the
+    /// search returns (the titles they are known for) are kept alongside the biography etc.
+    ///
+    /// When several people share a name, `hint` picks between them: the first search result
+    /// known for a title with exactly that name, or for a title released in it if it is a year
+    /// (see `KnownFor::matches`). If no result matches, the most popular one is used.
+    ///
+    /// # Arguments
+    /// * `actor_name` - The name of the actor.
+    /// * `hint` - Optional title or year the actor is known for.
+    ///
+    /// # Returns
+    /// * `Ok(Some(ActorProfile))` - Detailed info if the actor is found.
+    /// * `Ok(None)` - If no actor is found.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn actor_info(
+        &self,
+        actor_name: &str,
+        hint: Option<&str>,
+    ) -> Result<Option<ActorProfile>, TmdbError> {
+        let mut results = self.search_people(actor_name).await?.results;
+        let index = match hint {
+            None => 0,
+            Some(hint) => results
+                .iter()
+                .position(|person| person.is_known_for(hint))
+                .or_else(|| {
+                    results
+                        .iter()
+                        .enumerate()
+                        .max_by(|(_, a), (_, b)| a.popularity.total_cmp(&b.popularity))
+                        .map(|(index, _)| index)
+                })
+                .unwrap_or(0),
+        };
+        if index >= results.len() {
+            return Ok(None);
+        }
+        let search_result = results.swap_remove(index);
+
+        let details = self
+            .person_details(search_result.id, &self.config.language)
+            .await?;
+
+        Ok(details.map(|details| ActorProfile::new(details, search_result)))
+    }
+
+    /// Retrieves detailed information about a person by TMDB ID, localized to `language`.
+    ///
+    /// # Arguments
+    /// * `person_id` - The TMDB ID of the person.
+    /// * `language` - Language for localized fields such as the biography (e.g. `en-US`).
+    ///
+    /// # Returns
+    /// * `Ok(Some(PersonDetails))` - Detailed info if the person exists.
+    /// * `Ok(None)` - If no person has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn person_details(
+        &self,
+        person_id: i64,
+        language: &str,
+    ) -> Result<Option<PersonDetails>, TmdbError> {
+        // https://api.themoviedb.org/3/person/{id}
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/person/{person_id}")))
+                    .query(&[("language", language)]),
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        Ok(Some(parse_json(response).await?))
+    }
+
+    /// Retrieves a person's details as TMDB's unmodified JSON body, for tools' `raw` output.
+    ///
+    /// # Arguments
+    /// * `person_id` - The TMDB ID of the person.
+    ///
+    /// # Returns
+    /// * `Ok(Some(String))` - The response body exactly as TMDB sent it.
+    /// * `Ok(None)` - If no person has that ID.
+    /// * `Err(TmdbError)` - If the request fails, the body is not JSON, or TMDB reports an error.
+    pub async fn person_details_raw(&self, person_id: i64) -> Result<Option<String>, TmdbError> {
+        // https://api.themoviedb.org/3/person/{id}
+        self.get_raw(&format!("/person/{person_id}")).await
+    }
+
+    /// Retrieves a movie's details as TMDB's unmodified JSON body, for tools' `raw` output.
+    ///
+    /// # Arguments
+    /// * `movie_id` - The TMDB ID of the movie.
+    ///
+    /// # Returns
+    /// * `Ok(Some(String))` - The response body exactly as TMDB sent it.
+    /// * `Ok(None)` - If no movie has that ID.
+    /// * `Err(TmdbError)` - If the request fails, the body is not JSON, or TMDB reports an error.
+    pub async fn movie_details_raw(&self, movie_id: i64) -> Result<Option<String>, TmdbError> {
+        // https://api.themoviedb.org/3/movie/{id}
+        self.get_raw(&format!("/movie/{movie_id}")).await
+    }
+
+    /// Fetches an API path in the configured language and returns the body unparsed, after
+    /// checking that it is JSON. A `404 Not Found` is `Ok(None)`.
+    async fn get_raw(&self, path: &str) -> Result<Option<String>, TmdbError> {
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(path))
+                    .query(&[("language", self.config.language.as_str())]),
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let (body, _) = read_json(response).await?;
+        Ok(Some(String::from_utf8_lossy(&body).into_owned()))
+    }
+
+    /// Retrieves a person's IDs on other sites (IMDb, Instagram, X, ...).
+    ///
+    /// # Arguments
+    /// * `person_id` - The TMDB ID of the person.
+    ///
+    /// # Returns
+    /// * `Ok(Some(ExternalIds))` - The person's external IDs if the person exists.
+    /// * `Ok(None)` - If no person has that ID.
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn person_external_ids(
+        &self,
+        person_id: i64,
+    ) -> Result<Option<ExternalIds>, TmdbError> {
+        // https://api.themoviedb.org/3/person/{id}/external_ids
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/person/{person_id}/external_ids"))),
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        Ok(Some(parse_json(response).await?))
+    }
+
+    /// Looks up TMDB movies and people by their IMDb ID.
+    ///
+    /// # Arguments
+    /// * `imdb_id` - An IMDb ID, e.g. `tt0075148` for a title or `nm0000230` for a person.
+    ///
+    /// # Returns
+    /// * `Ok(FindResults)` - Matching movies and people (both lists may be empty).
+    /// * `Err(TmdbError)` - If the request or parsing fails, or TMDB reports an error.
+    pub async fn find_by_imdb(&self, imdb_id: &str) -> Result<FindResults, TmdbError> {
+        // https://api.themoviedb.org/3/find/{external_id}?external_source=imdb_id
+        let response = self
+            .send(
+                self.client
+                    .get(self.url(&format!("/find/{imdb_id}")))
+                    .query(&[
+                        ("external_source", "imdb_id"),
+                        ("language", self.config.language.as_str()),
+                    ]),
+            )
+            .await?;
+
+        let mut result: FindResults = parse_json(response).await?;
+        self.hide_adult(&mut result.movie_results);
+        self.hide_adult(&mut result.person_results);
+        Ok(result)
+    }
+
+    /// Resolves a TMDB image path to a full image URL, using the configured image size.
+    ///
+    /// # Arguments
+    /// * `image_path` - The relative path to the image from TMDB.
+    ///
+    /// # Returns
+    /// * `String` - The full URL to the image.
+    pub fn resolve_image_url(&self, image_path: &str) -> String {
+        format!(
+            "{}/{}{image_path}",
+            self.config.image_base_url, self.config.image_size
+        )
+    }
+
+    /// Downloads an image from a URL and encodes it as a base64 string.
+    ///
+    /// Image downloads are retried up to `IMAGE_MAX_RETRIES` times and use their own timeout
+    /// (`IMAGE_TIMEOUT`), since transient CDN errors are more common than API errors. Each
+    /// retry also needs a permit from the client-wide `RetryBudget`.
+    /// A `404 Not Found` means the image is genuinely missing and is not retried.
+    ///
+    /// Images larger than `config.max_image_bytes` are rejected rather than encoded, to keep
+    /// oversized payloads off the transport. A body shorter than its `Content-Length` (an
+    /// interrupted download) would encode to a corrupt image, so it is retried like a
+    /// transient error and reported as `TmdbError::IncompleteImage` if it keeps happening.
+    ///
+    /// # Arguments
+    /// * `image_url` - The full URL to the image.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The base64-encoded image data.
+    /// * `Err(TmdbError)` - If the request fails after all retries or the image is too large.
+    async fn image_url_to_base64(&self, image_url: &str) -> Result<String, TmdbError> {
+        let mut attempt = 0;
+        let bytes = loop {
+            // images come from the CDN, so they bypass the API's circuit breaker
+            let result = self
+                .execute(self.client.get(image_url).timeout(IMAGE_TIMEOUT))
+                .await
+                .and_then(|response| response.error_for_status());
+
+            let error = match result {
+                Ok(response) => {
+                    // bail out early when the server announces an oversized body
+                    let expected = response.content_length();
+                    if let Some(size) = expected {
+                        self.check_image_size(image_url, size)?;
+                    }
+                    match response.bytes().await {
+                        Ok(bytes) => match expected {
+                            Some(expected) if expected != bytes.len() as u64 => {
+                                eprintln!(
+                                    "incomplete image: {image_url} sent {} of {expected} bytes",
+                                    bytes.len()
+                                );
+                                TmdbError::IncompleteImage {
+                                    expected,
+                                    received: bytes.len() as u64,
+                                }
+                            }
+                            _ => break bytes,
+                        },
+                        Err(err) => err.into(),
+                    }
+                }
+                Err(err) => err.into(),
+            };
+
+            if attempt >= IMAGE_MAX_RETRIES || !is_retryable_image_error(&error) {
+                return Err(error);
+            }
+            // fail fast once retries across the whole client have used up the shared budget
+            if !self.retry_budget.try_acquire() {
+                return Err(error);
+            }
+
+            tokio::time::sleep(IMAGE_RETRY_DELAY * 2u32.pow(attempt)).await;
+            attempt += 1;
+        };
+
+        self.check_image_size(image_url, bytes.len() as u64)?;
+
+        let base64_string = general_purpose::STANDARD.encode(&bytes);
+
+        Ok(base64_string)
+    }
+
+    /// Rejects images over the configured size limit, logging when the guard trips.
+    fn check_image_size(&self, image_url: &str, size: u64) -> Result<(), TmdbError> {
+        if size <= self.config.max_image_bytes {
+            return Ok(());
+        }
+        eprintln!(
+            "image size guard: {image_url} is {size} bytes, over the {} byte limit",
+            self.config.max_image_bytes
+        );
+        Err(TmdbError::ImageTooLarge {
+            size,
+            limit: self.config.max_image_bytes,
+        })
+    }
+
+    /// Retrieves an image from TMDB by its path and returns it as a base64 string.
+    ///
+    /// # Arguments
+    /// * `image_path` - The relative path to the image from TMDB.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The base64-encoded image data.
+    /// * `Err(TmdbError)` - If the request fails or the image is too large.
+    pub async fn image_as_base64(&self, image_path: &str) -> Result<String, TmdbError> {
+        self.image_url_to_base64(self.resolve_image_url(image_path).as_str())
+            .await
+    }
+}
+
+/// Reads a response body that should be JSON, returning both the raw body and its parsed
+/// value. Checks the body before the status code: TMDB explains most errors, 4xx and 5xx
+/// included, with a `{"success": false, "status_code": 34, "status_message": "..."}`
+/// envelope, which is reported as `TmdbError::Api` with TMDB's message. Any other error
+/// status is `TmdbError::Http`.
+///
+/// Bodies that are not JSON (by `Content-Type` or by content), such as a proxy's HTML error
+/// page, are reported as `TmdbError::NonJson` with a truncated copy logged to stderr.
+async fn read_json(response: Response) -> Result<(Vec<u8>, Value), TmdbError> {
+    let status = response.status();
+    let status_error = response.error_for_status_ref().err();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let body = response.bytes().await?;
+
+    // Proxies and CDNs answer with HTML error pages, sometimes even labelled as JSON, so
+    // check both the declared type and the body before handing it to serde.
+    let declared_json = content_type.is_empty() || content_type.contains("json");
+    let first_byte = body.iter().find(|byte| !byte.is_ascii_whitespace());
+    if !declared_json || !matches!(first_byte, Some(b'{' | b'[')) {
+        if let Some(err) = status_error {
+            return Err(err.into());
+        }
+        let snippet: String = String::from_utf8_lossy(&body)
+            .chars()
+            .take(MAX_LOGGED_BODY_CHARS)
+            .collect();
+        eprintln!(
+            "non-JSON response from TMDB (status {status}, content type \"{content_type}\"): {}",
+            snippet.replace(['\n', '\r'], " ")
+        );
+        return Err(TmdbError::NonJson {
+            status: status.as_u16(),
+        });
+    }
+
+    let value = serde_json::from_slice::<Value>(&body);
+    if let Ok(value) = &value
+        && value.get("success").and_then(Value::as_bool) == Some(false)
+    {
+        return Err(TmdbError::Api {
+            status: status.as_u16(),
+            status_code: value.get("status_code").and_then(Value::as_i64),
+            message: value
+                .get("status_message")
+                .and_then(Value::as_str)
+                .unwrap_or("TMDB reported an error without a message")
+                .to_string(),
+        });
+    }
+    if let Some(err) = status_error {
+        return Err(err.into());
+    }
+    Ok((body.into(), value.map_err(TmdbError::Decode)?))
+}
+
+/// Parses a TMDB JSON response body, whatever its status code: error envelopes, error
+/// statuses and non-JSON bodies are reported as described for `read_json`, so callers don't
+/// call `error_for_status` first.
+///
+/// With the `strict-schema` feature, fields in the response that `T` does not capture are
+/// reported as `TmdbError::Decode` too (see `unknown_fields`), unless `T` ignores them on
+/// purpose (see `IgnoredFields`).
+async fn parse_json<T: DeserializeOwned + Serialize + IgnoredFields>(
+    response: Response,
+) -> Result<T, TmdbError> {
+    let (_, value) = read_json(response).await?;
+
+    if cfg!(feature = "strict-schema") {
+        parse_strict(value)
+    } else {
+        serde_json::from_value(value).map_err(TmdbError::Decode)
+    }
+}
+
+/// Parses a TMDB response, rejecting fields `T` neither captures nor ignores on purpose.
+fn parse_strict<T: DeserializeOwned + Serialize + IgnoredFields>(
+    value: Value,
+) -> Result<T, TmdbError> {
+    let parsed: T = serde_json::from_value(value.clone()).map_err(TmdbError::Decode)?;
+    let reserialized = serde_json::to_value(&parsed).map_err(TmdbError::Decode)?;
+    let mut unknown = Vec::new();
+    unknown_fields(&value, &reserialized, "", &mut unknown);
+    let ignored = T::ignored_fields();
+    unknown.retain(|path| !ignored.contains(&any_index(path)));
+    if !unknown.is_empty() {
+        return Err(TmdbError::Decode(serde::de::Error::custom(format!(
+            "unknown fields in TMDB response: {}",
+            unknown.join(", ")
+        ))));
+    }
+    Ok(parsed)
+}
+
+/// Collects the paths (e.g. `results[0].known_for`) of fields present in the `original`
+/// response but missing after a round trip through the parsed type, i.e. fields the type
+/// doesn't capture.
+///
+/// Used by the `strict-schema` feature to notice TMDB adding or renaming fields. Every
+/// response type must then model every field TMDB sends, so it is meant for CI runs rather
+/// than production, where unknown fields are ignored.
+fn unknown_fields(original: &Value, parsed: &Value, path: &str, unknown: &mut Vec<String>) {
+    match (original, parsed) {
+        (Value::Object(original), Value::Object(parsed)) => {
+            for (key, value) in original {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match parsed.get(key) {
+                    Some(parsed_value) => unknown_fields(value, parsed_value, &field_path, unknown),
+                    None => unknown.push(field_path),
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(parsed)) => {
+            for (index, (value, parsed_value)) in original.iter().zip(parsed).enumerate() {
+                unknown_fields(value, parsed_value, &format!("{path}[{index}]"), unknown);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replaces the array indexes in a field path with `[]`, e.g. `results[0].gender` becomes
+/// `results[].gender`, the form `IgnoredFields` paths take.
+fn any_index(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    let mut in_index = false;
+    for c in path.chars() {
+        match c {
+            '[' => {
+                in_index = true;
+                result.push_str("[]");
+            }
+            ']' => in_index = false,
+            _ if in_index => {}
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Fields TMDB sends that a response type leaves out on purpose, so `strict-schema` builds
+/// don't report them as unknown. Only worth overriding for types that drop fields TMDB is
+/// known to send; everything else reports all the fields it doesn't capture.
+pub trait IgnoredFields {
+    /// Paths of the ignored fields relative to the type, with `[]` for any array index,
+    /// e.g. `results[].gender`.
+    fn ignored_fields() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Prefixes the ignored fields of a nested type with the path it is found at.
+fn nested_ignored_fields<T: IgnoredFields>(prefix: &str) -> Vec<String> {
+    T::ignored_fields()
+        .into_iter()
+        .map(|path| format!("{prefix}.{path}"))
+        .collect()
+}
+
+impl<T: IgnoredFields> IgnoredFields for Paginated<T> {
+    fn ignored_fields() -> Vec<String> {
+        nested_ignored_fields::<T>("results[]")
+    }
+}
+
+impl IgnoredFields for FindResults {
+    fn ignored_fields() -> Vec<String> {
+        nested_ignored_fields::<PersonSummary>("person_results[]")
+    }
+}
+
+impl IgnoredFields for PersonSummary {
+    fn ignored_fields() -> Vec<String> {
+        // gender isn't shown for search results, and the original name is the same as
+        // `name` for nearly everyone
+        let mut fields = vec!["gender".to_string(), "original_name".to_string()];
+        fields.extend(nested_ignored_fields::<KnownFor>("known_for[]"));
+        fields
+    }
+}
+
+impl IgnoredFields for KnownFor {
+    fn ignored_fields() -> Vec<String> {
+        // only enough of each title is kept to name it and match disambiguation hints
+        [
+            "backdrop_path",
+            "genre_ids",
+            "original_language",
+            "original_title",
+            "original_name",
+            "overview",
+            "popularity",
+            "video",
+            "vote_average",
+            "vote_count",
+            "origin_country",
+        ]
+        .map(str::to_string)
+        .to_vec()
+    }
+}
+
+impl IgnoredFields for ExternalIds {
+    fn ignored_fields() -> Vec<String> {
+        // the person's own TMDB ID, which the caller already has
+        vec!["id".to_string()]
+    }
+}
+
+impl IgnoredFields for MovieCreditsResponse {
+    fn ignored_fields() -> Vec<String> {
+        // crew credits come from `/person/{id}/combined_credits`, which includes TV, and the
+        // billing order across a person's movies means nothing
+        ["id", "crew", "cast[].order"].map(str::to_string).to_vec()
+    }
+}
+
+impl IgnoredFields for CombinedCreditsResponse {
+    fn ignored_fields() -> Vec<String> {
+        // acting credits come from `/person/{id}/movie_credits`
+        vec!["id".to_string(), "cast".to_string()]
+    }
+}
+
+impl IgnoredFields for AlternativeTitlesResponse {
+    fn ignored_fields() -> Vec<String> {
+        vec!["id".to_string()]
+    }
+}
+
+impl IgnoredFields for WatchProvidersResponse {
+    fn ignored_fields() -> Vec<String> {
+        // the movie's ID, which the caller already has
+        vec!["id".to_string()]
+    }
+}
+
+impl IgnoredFields for KeywordsResponse {
+    fn ignored_fields() -> Vec<String> {
+        vec!["id".to_string()]
+    }
+}
+
+impl IgnoredFields for TvSeason {
+    fn ignored_fields() -> Vec<String> {
+        // the episode list is a summary: no stills, per-episode credits or overviews
+        let episode_fields = [
+            "id",
+            "overview",
+            "production_code",
+            "season_number",
+            "show_id",
+            "still_path",
+            "episode_type",
+            "crew",
+            "guest_stars",
+        ]
+        .map(|field| format!("episodes[].{field}"));
+        ["_id", "id", "poster_path", "vote_average"]
+            .map(str::to_string)
+            .into_iter()
+            .chain(episode_fields)
+            .collect()
+    }
+}
+
+impl IgnoredFields for MovieDetail {
+    fn ignored_fields() -> Vec<String> {
+        // trending and collection listings tag each entry as a movie
+        vec!["media_type".to_string()]
+    }
+}
+
+impl IgnoredFields for MovieFull {
+    fn ignored_fields() -> Vec<String> {
+        // tools show the spoken languages and production countries instead
+        ["adult", "original_language", "origin_country", "video"]
+            .map(str::to_string)
+            .to_vec()
+    }
+}
+
+impl IgnoredFields for Collection {
+    fn ignored_fields() -> Vec<String> {
+        let mut fields = vec!["original_language".to_string(), "original_name".to_string()];
+        fields.extend(nested_ignored_fields::<MovieDetail>("parts[]"));
+        fields
+    }
+}
+
+impl IgnoredFields for MovieCredits {
+    fn ignored_fields() -> Vec<String> {
+        let mut fields = nested_ignored_fields::<CastMember>("cast[]");
+        fields.extend(nested_ignored_fields::<CrewMember>("crew[]"));
+        fields
+    }
+}
+
+impl IgnoredFields for CastMember {
+    fn ignored_fields() -> Vec<String> {
+        // a cast list names people and their characters; their details are a lookup away
+        [
+            "cast_id",
+            "credit_id",
+            "gender",
+            "known_for_department",
+            "original_name",
+            "popularity",
+        ]
+        .map(str::to_string)
+        .to_vec()
+    }
+}
+
+impl IgnoredFields for CrewMember {
+    fn ignored_fields() -> Vec<String> {
+        [
+            "credit_id",
+            "gender",
+            "known_for_department",
+            "original_name",
+            "popularity",
+            "profile_path",
+        ]
+        .map(str::to_string)
+        .to_vec()
+    }
+}
+
+impl IgnoredFields for MovieImages {
+    fn ignored_fields() -> Vec<String> {
+        // logos aren't offered, and the size is enough to describe an image
+        let mut fields = vec!["logos".to_string()];
+        fields.extend(nested_ignored_fields::<ImageInfo>("posters[]"));
+        fields.extend(nested_ignored_fields::<ImageInfo>("backdrops[]"));
+        fields
+    }
+}
+
+impl IgnoredFields for ImageInfo {
+    fn ignored_fields() -> Vec<String> {
+        vec!["aspect_ratio".to_string(), "vote_count".to_string()]
+    }
+}
+
+impl IgnoredFields for TvSeries {
+    fn ignored_fields() -> Vec<String> {
+        // only enough of the series is kept to name it and page through its seasons
+        [
+            "adult",
+            "backdrop_path",
+            "created_by",
+            "episode_run_time",
+            "genres",
+            "homepage",
+            "in_production",
+            "languages",
+            "last_air_date",
+            "last_episode_to_air",
+            "next_episode_to_air",
+            "networks",
+            "origin_country",
+            "original_language",
+            "original_name",
+            "overview",
+            "popularity",
+            "poster_path",
+            "production_companies",
+            "production_countries",
+            "seasons",
+            "spoken_languages",
+            "status",
+            "tagline",
+            "type",
+            "vote_average",
+            "vote_count",
+        ]
+        .map(str::to_string)
+        .to_vec()
+    }
+}
+
+impl IgnoredFields for AccountDetails {
+    fn ignored_fields() -> Vec<String> {
+        // the session only needs the account ID
+        ["avatar", "include_adult", "iso_639_1", "iso_3166_1", "name"]
+            .map(str::to_string)
+            .to_vec()
+    }
+}
+
+/// Response types that report every field they don't capture.
+macro_rules! ignores_no_fields {
+    ($($type:ty),* $(,)?) => {
+        $(impl IgnoredFields for $type {})*
+    };
+}
+
+impl IgnoredFields for RatedMovie {
+    fn ignored_fields() -> Vec<String> {
+        MovieDetail::ignored_fields()
+    }
+}
+
+ignores_no_fields!(Value, GenreResponse, TmdbConfiguration, PersonDetails,);
+
+/// Returns true if an API request is worth retrying: it could not be completed because of a
+/// timeout or connection error, or TMDB answered with a server error or `429 Too Many Requests`.
+fn is_retryable(result: &Result<Response, reqwest::Error>) -> bool {
+    match result {
+        Ok(response) => {
+            response.status().is_server_error()
+                || response.status() == StatusCode::TOO_MANY_REQUESTS
+        }
+        Err(error) => error.is_timeout() || error.is_connect(),
+    }
+}
+
+/// Returns true if a failed image download is worth retrying.
+///
+/// Connection errors, timeouts, incomplete bodies and server-side (5xx) or rate-limit (429)
+/// responses are considered transient. Any other status, in particular `404 Not Found`, is final.
+fn is_retryable_image_error(error: &TmdbError) -> bool {
+    match error {
+        TmdbError::Http(error) => match error.status() {
+            Some(status) => status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+            // bodies are read through reqwest's decompression layer, which reports a
+            // connection dropped mid-body as a decode error
+            None => {
+                error.is_timeout() || error.is_connect() || error.is_body() || error.is_decode()
+            }
+        },
+        TmdbError::IncompleteImage { .. } => true,
+        _ => false,
+    }
+}
+
+/// When something cached now for `ttl` goes stale. A TTL that overflows `Instant` (such as
+/// `max-age=18446744073709551615`) is capped at `MAX_CACHE_TTL` instead of panicking.
+fn expires_after(ttl: Duration) -> Instant {
+    let now = Instant::now();
+    now.checked_add(ttl).unwrap_or_else(|| now + MAX_CACHE_TTL)
+}
+
+// TMDB Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovieDetail {
+    pub adult: bool,
+    pub backdrop_path: Option<String>,
+    pub genre_ids: Vec<u32>,
+    pub id: i64,
+    pub original_language: String,
+    pub original_title: String,
+    #[serde(default)]
+    pub overview: String,
+    pub popularity: f64,
+    pub poster_path: Option<String>,
+    #[serde(default)]
+    pub release_date: String,
+    pub title: String,
+    pub video: bool,
+    pub vote_average: f64,
+    pub vote_count: u32,
+}
+
+impl MovieDetail {
+    /// Renders the title and release year, e.g. `Cast Away (2000)`. Without a release date the
+    /// year is shown as `Unknown year` or left out according to `missing`.
+    pub fn render(&self, missing: MissingFields) -> String {
+        match self
+            .release_date
+            .get(0..4)
+            .filter(|_| self.release_date.len() > 4)
+        {
+            Some(year) => format!("{} ({year})", self.title),
+            None if missing == MissingFields::Label => format!("{} (Unknown year)", self.title),
+            None => self.title.clone(),
+        }
+    }
+}
+
+/// Implements Display for MovieDetail to show the movie title and release year (if available).
+impl Display for MovieDetail {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(MissingFields::Omit))
+    }
+}
+
+/// Highest page TMDB serves from a search, discover or popular list; asking for a later page
+/// is an error, however many pages `total_pages` reports.
+pub const MAX_PAGE: u32 = 500;
+
+/// One page of a list endpoint (search, discover, trending, account lists, ...), with TMDB's
+/// totals so callers can tell whether there are more pages to fetch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Paginated<T> {
+    /// This page's number, starting at 1
+    pub page: u32,
+    /// Number of pages available
+    pub total_pages: u32,
+    /// Number of results across all pages
+    pub total_results: u32,
+    /// The results on this page
+    pub results: Vec<T>,
+}
+
+impl<T> Paginated<T> {
+    /// Whether TMDB has pages after this one that it will serve (none past [`MAX_PAGE`]).
+    pub fn has_more(&self) -> bool {
+        self.page < self.total_pages.min(MAX_PAGE)
+    }
+
+    /// Whether this is the last page TMDB serves although it reports more results, which
+    /// can then not be fetched at all.
+    pub fn at_page_limit(&self) -> bool {
+        self.page >= MAX_PAGE && self.page < self.total_pages
+    }
+
+    /// Keeps only the results `keep` accepts, taking the others off `total_results` as well
+    /// so the totals describe what is returned. Other pages are not fetched, so when there
+    /// are more, the total is an upper bound.
+    pub fn retain(&mut self, keep: impl FnMut(&T) -> bool) {
+        let before = self.results.len();
+        self.results.retain(keep);
+        let removed = (before - self.results.len()) as u32;
+        self.total_results = self.total_results.saturating_sub(removed);
+    }
+}
+
+/// A movie rated by the session's user, from `/account/{id}/rated/movies`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatedMovie {
+    #[serde(flatten)]
+    pub movie: MovieDetail,
+    /// The user's rating, out of 10
+    pub rating: f64,
+}
+
+/// Entries TMDB can flag as adult content, hidden by `TmdbClient::hide_adult` when safe
+/// search is on.
+pub trait AdultContent {
+    fn is_adult(&self) -> bool;
+
+    /// Removes the adult entries listed inside this one, such as a person's `known_for`.
+    fn hide_nested_adult(&mut self) {}
+}
+
+impl AdultContent for MovieDetail {
+    fn is_adult(&self) -> bool {
+        self.adult
+    }
+}
+
+impl AdultContent for MovieCredit {
+    fn is_adult(&self) -> bool {
+        self.movie.adult
+    }
+}
+
+impl AdultContent for RatedMovie {
+    fn is_adult(&self) -> bool {
+        self.movie.adult
+    }
+}
+
+impl AdultContent for PersonSummary {
+    fn is_adult(&self) -> bool {
+        self.adult
+    }
+
+    fn hide_nested_adult(&mut self) {
+        self.known_for.retain(|title| !title.adult);
+    }
+}
+
+impl AdultContent for CrewCredit {
+    fn is_adult(&self) -> bool {
+        self.adult
+    }
+}
+
+impl AdultContent for CastMember {
+    fn is_adult(&self) -> bool {
+        self.adult
+    }
+}
+
+impl AdultContent for CrewMember {
+    fn is_adult(&self) -> bool {
+        self.adult
+    }
+}
+
+/// Where a movie can be watched in one region, by type of offer.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchProviders {
+    /// TMDB page listing the offers for the region
+    pub link: Option<String>,
+    /// Included in a subscription
+    #[serde(default)]
+    pub flatrate: Vec<WatchProvider>,
+    /// Free to watch
+    #[serde(default)]
+    pub free: Vec<WatchProvider>,
+    /// Free with ads
+    #[serde(default)]
+    pub ads: Vec<WatchProvider>,
+    #[serde(default)]
+    pub rent: Vec<WatchProvider>,
+    #[serde(default)]
+    pub buy: Vec<WatchProvider>,
+}
+
+impl WatchProviders {
+    /// Providers the movie can be streamed on right now without renting or buying it
+    /// (subscription, free or with ads), each listed once.
+    pub fn streaming(&self) -> Vec<&WatchProvider> {
+        let mut providers: Vec<&WatchProvider> = Vec::new();
+        for provider in self.flatrate.iter().chain(&self.free).chain(&self.ads) {
+            if !providers
+                .iter()
+                .any(|seen| seen.provider_id == provider.provider_id)
+            {
+                providers.push(provider);
+            }
+        }
+        providers
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchProvider {
+    pub provider_id: i64,
+    /// Display name, e.g. "Netflix"
+    pub provider_name: String,
+    /// Relative path to the provider's logo (see `TmdbClient::resolve_image_url`)
+    pub logo_path: Option<String>,
+    /// Position TMDB gives the provider in its own listings, lowest first
+    #[serde(default)]
+    pub display_priority: u32,
+}
+
+/// Watch providers of a movie for every region, keyed by ISO 3166-1 code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchProvidersResponse {
+    results: HashMap<String, WatchProviders>,
+}
+
+/// The account a session belongs to, from `/account`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountDetails {
+    /// TMDB account ID
+    pub id: i64,
+    /// Account user name
+    pub username: String,
+}
+
+/// A movie a person appeared in, as returned by `/person/{id}/movie_credits`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovieCredit {
+    #[serde(flatten)]
+    pub movie: MovieDetail,
+    /// Name of the character played (may be empty for uncredited or cameo roles)
+    #[serde(default)]
+    pub character: String,
+    /// Unique TMDB credit ID
+    pub credit_id: String,
+}
+
+impl MovieCredit {
+    /// Renders the movie (see `MovieDetail::render`) followed by the character played, if known.
+    pub fn render(&self, missing: MissingFields) -> String {
+        let movie = self.movie.render(missing);
+        if self.character.trim().is_empty() {
+            movie
+        } else {
+            format!("{movie} (as {})", self.character)
+        }
+    }
+}
+
+/// Implements Display for MovieCredit to show the movie followed by the character played.
+impl Display for MovieCredit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(MissingFields::Omit))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovieCreditsResponse {
+    cast: Vec<MovieCredit>,
+}
+
+/// A movie or TV show a person worked on behind the camera, from `/person/{id}/combined_credits`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrewCredit {
+    /// TMDB ID of the movie or show
+    pub id: i64,
+    /// Whether the movie or show is marked as adult content
+    #[serde(default)]
+    pub adult: bool,
+    /// "movie" or "tv"
+    pub media_type: String,
+    /// Movie title (movies only)
+    pub title: Option<String>,
+    /// Show name (TV only)
+    pub name: Option<String>,
+    /// Release date in YYYY-MM-DD format (movies only)
+    pub release_date: Option<String>,
+    /// First air date in YYYY-MM-DD format (TV only)
+    pub first_air_date: Option<String>,
+    /// Department worked in, e.g. "Directing" or "Writing"
+    pub department: String,
+    /// Job within the department, e.g. "Director" or "Screenplay"
+    pub job: String,
+}
+
+impl CrewCredit {
+    /// Returns the movie title or show name.
+    pub fn display_title(&self) -> &str {
+        self.title
+            .as_deref()
+            .or(self.name.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Returns the release or first air date, empty if unknown.
+    pub fn date(&self) -> &str {
+        self.release_date
+            .as_deref()
+            .or(self.first_air_date.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombinedCreditsResponse {
+    crew: Vec<CrewCredit>,
+}
+
+/// Full movie record returned by `/movie/{id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovieFull {
+    /// TMDB movie ID
+    pub id: i64,
+    /// Corresponding IMDb ID (with "tt" prefix)
+    pub imdb_id: Option<String>,
+    pub title: String,
+    pub original_title: String,
+    /// Short marketing tagline
+    pub tagline: Option<String>,
+    #[serde(default)]
+    pub overview: String,
+    /// Release date in YYYY-MM-DD format (empty if unknown)
+    #[serde(default)]
+    pub release_date: String,
+    /// Runtime in minutes
+    pub runtime: Option<u32>,
+    pub genres: Vec<Genre>,
+    /// Production budget in US dollars (0 if unknown)
+    pub budget: i64,
+    /// Box office revenue in US dollars (0 if unknown)
+    pub revenue: i64,
+    pub popularity: f64,
+    pub vote_average: f64,
+    pub vote_count: u32,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+    /// Official homepage URL
+    pub homepage: Option<String>,
+    /// Release status, e.g. "Released" or "In Production"
+    pub status: String,
+    /// Languages spoken in the movie
+    #[serde(default)]
+    pub spoken_languages: Vec<SpokenLanguage>,
+    /// Countries the movie was produced in
+    #[serde(default)]
+    pub production_countries: Vec<ProductionCountry>,
+    /// Franchise the movie is part of, if any (see `TmdbClient::collection`)
+    #[serde(default)]
+    pub belongs_to_collection: Option<CollectionSummary>,
+    /// Studios that produced the movie, the primary one first
+    #[serde(default)]
+    pub production_companies: Vec<ProductionCompany>,
+}
+
+/// The collection (franchise) a movie belongs to, as listed in its details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionSummary {
+    /// TMDB collection ID
+    pub id: i64,
+    /// e.g. "The Lord of the Rings Collection"
+    pub name: String,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+}
+
+/// A collection (franchise) with all of its movies, from `/collection/{id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Collection {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub overview: String,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+    /// The movies in the collection, in TMDB's order (not necessarily by release)
+    pub parts: Vec<MovieDetail>,
+}
+
+/// A language spoken in a movie.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpokenLanguage {
+    /// ISO 639-1 language code (e.g. "fr")
+    pub iso_639_1: String,
+    /// Language name in English (e.g. "French")
+    #[serde(default)]
+    pub english_name: String,
+    /// Language name in the language itself (e.g. "Français")
+    #[serde(default)]
+    pub name: String,
+}
+
+/// A country a movie was produced in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductionCountry {
+    /// ISO 3166-1 country code (e.g. "US")
+    pub iso_3166_1: String,
+    /// Country name in English (e.g. "United States of America")
+    pub name: String,
+}
+
+/// A company that produced a movie.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductionCompany {
+    /// TMDB company ID (see `TmdbClient::movies_by_company`)
+    pub id: i64,
+    pub name: String,
+    /// Relative path to the company's logo (see `TmdbClient::resolve_image_url`)
+    pub logo_path: Option<String>,
+    /// ISO 3166-1 code of the country the company is based in (may be empty)
+    #[serde(default)]
+    pub origin_country: String,
+}
+
+impl MovieFull {
+    /// Returns the four-digit release year, if the release date is known.
+    pub fn release_year(&self) -> Option<&str> {
+        self.release_date.get(0..4)
+    }
+}
+
+/// Credits of a movie returned by `/movie/{id}/credits`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovieCredits {
+    /// TMDB movie ID
+    pub id: i64,
+    /// Cast members in billing order
+    pub cast: Vec<CastMember>,
+    /// Crew members (directors, writers, composers, ...)
+    #[serde(default)]
+    pub crew: Vec<CrewMember>,
+}
+
+impl MovieCredits {
+    /// Returns the crew members credited with the job "Director".
+    pub fn directors(&self) -> impl Iterator<Item = &CrewMember> {
+        self.crew.iter().filter(|member| member.job == "Director")
+    }
+}
+
+/// A person appearing in a movie's cast.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CastMember {
+    /// TMDB person ID
+    pub id: i64,
+    pub name: String,
+    /// Whether the person is marked as adult content
+    #[serde(default)]
+    pub adult: bool,
+    /// Character played (empty if unknown)
+    #[serde(default)]
+    pub character: String,
+    /// Billing position (0 = top billed)
+    pub order: u32,
+    /// Relative path to profile image
+    pub profile_path: Option<String>,
+}
+
+/// A person working on a movie behind the camera.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrewMember {
+    /// TMDB person ID
+    pub id: i64,
+    pub name: String,
+    /// Whether the person is marked as adult content
+    #[serde(default)]
+    pub adult: bool,
+    /// Department worked in, e.g. "Directing" or "Writing"
+    pub department: String,
+    /// Job within the department, e.g. "Director" or "Screenplay"
+    pub job: String,
+}
+
+/// Images of a movie returned by `/movie/{id}/images`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovieImages {
+    /// TMDB movie ID
+    pub id: i64,
+    #[serde(default)]
+    pub posters: Vec<ImageInfo>,
+    #[serde(default)]
+    pub backdrops: Vec<ImageInfo>,
+}
+
+/// A single poster, backdrop or profile image.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageInfo {
+    /// Relative path to the image, for use with `image_as_base64`
+    pub file_path: String,
+    /// Original width in pixels
+    pub width: u32,
+    /// Original height in pixels
+    pub height: u32,
+    /// Language of any text in the image (null for images without text)
+    pub iso_639_1: Option<String>,
+    pub vote_average: f64,
+}
+
+/// Summary of a TV series returned by `/tv/{id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvSeries {
+    /// TMDB series ID
+    pub id: i64,
+    pub name: String,
+    /// First air date in YYYY-MM-DD format (null if unknown)
+    pub first_air_date: Option<String>,
+    pub number_of_seasons: u32,
+    pub number_of_episodes: u32,
+}
+
+/// A season of a TV series returned by `/tv/{id}/season/{season}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvSeason {
+    pub name: String,
+    pub season_number: u32,
+    /// Air date of the first episode in YYYY-MM-DD format (null if unknown)
+    pub air_date: Option<String>,
+    #[serde(default)]
+    pub overview: String,
+    #[serde(default)]
+    pub episodes: Vec<Episode>,
+}
+
+/// A single episode of a TV season.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Episode {
+    pub episode_number: u32,
+    pub name: String,
+    /// Air date in YYYY-MM-DD format (null if unknown)
+    pub air_date: Option<String>,
+    /// Runtime in minutes
+    pub runtime: Option<u32>,
+    pub vote_average: f64,
+    pub vote_count: u32,
+}
+
+/// A title a movie is known by in a particular country.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlternativeTitle {
+    /// ISO 3166-1 country code (e.g. "US", "JP")
+    pub iso_3166_1: String,
+    pub title: String,
+    /// Kind of title, e.g. "working title" (often empty)
+    #[serde(default, rename = "type")]
+    pub title_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlternativeTitlesResponse {
+    titles: Vec<AlternativeTitle>,
+}
+
+/// A keyword tagged on a movie.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keyword {
+    /// TMDB keyword ID
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordsResponse {
+    keywords: Vec<Keyword>,
+}
+
+/// Results of an external ID lookup via `/find/{external_id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindResults {
+    pub movie_results: Vec<MovieDetail>,
+    pub person_results: Vec<PersonSummary>,
+}
+
+/// Condensed person record returned by search and lookup endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonSummary {
+    /// TMDB person ID
+    pub id: i64,
+    /// Whether the person is marked as adult content
+    #[serde(default)]
+    pub adult: bool,
+    /// Primary name used for display
+    pub name: String,
+    /// Primary department this person is known for
+    pub known_for_department: Option<String>,
+    /// Popularity score (higher = more popular)
+    pub popularity: f64,
+    /// Relative path to profile image
+    pub profile_path: Option<String>,
+    /// Movies and shows the person is best known for (search results only)
+    #[serde(default)]
+    pub known_for: Vec<KnownFor>,
+}
+
+impl PersonSummary {
+    /// Returns true if any title the person is known for matches `hint` (see `KnownFor::matches`).
+    pub fn is_known_for(&self, hint: &str) -> bool {
+        self.known_for.iter().any(|title| title.matches(hint))
+    }
+}
+
+/// A movie or TV show listed in a person search result's `known_for`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownFor {
+    /// TMDB ID of the movie or show
+    pub id: i64,
+    /// Whether the movie or show is marked as adult content
+    #[serde(default)]
+    pub adult: bool,
+    /// "movie" or "tv"
+    pub media_type: String,
+    /// Movie title (movies only)
+    pub title: Option<String>,
+    /// Show name (TV only)
+    pub name: Option<String>,
+    /// Release date in YYYY-MM-DD format (movies only)
+    pub release_date: Option<String>,
+    /// First air date in YYYY-MM-DD format (TV only)
+    pub first_air_date: Option<String>,
+    /// Relative path to the poster image
+    #[serde(default)]
+    pub poster_path: Option<String>,
+}
+
+impl KnownFor {
+    /// Returns the movie title or show name.
+    pub fn display_title(&self) -> &str {
+        self.title
+            .as_deref()
+            .or(self.name.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if `hint` is the whole title (ignoring case and surrounding spaces), or
+    /// the year the title was first released: the movie's release date, or the show's first
+    /// air date. Part of a title doesn't match, so "Rocky" picks out neither "Rocky Horror"
+    /// nor "Rocky IV".
+    pub fn matches(&self, hint: &str) -> bool {
+        let hint = hint.trim();
+        if hint.is_empty() {
+            return false;
+        }
+        let date = self
+            .release_date
+            .as_deref()
+            .or(self.first_air_date.as_deref())
+            .unwrap_or_default();
+        let is_year = hint.len() == 4 && hint.bytes().all(|byte| byte.is_ascii_digit());
+        (is_year && date.get(0..4) == Some(hint))
+            || self.display_title().trim().to_lowercase() == hint.to_lowercase()
+    }
+}
+
+/// A person's full details enriched with what only the search endpoint returns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActorProfile {
+    #[serde(flatten)]
+    pub details: PersonDetails,
+    /// Movies and shows the person is best known for
+    pub known_for: Vec<KnownFor>,
+}
+
+impl ActorProfile {
+    /// Merges a person's details with their search result, filling details left empty
+    /// by the detail endpoint from the search result.
+    pub fn new(mut details: PersonDetails, search_result: PersonSummary) -> Self {
+        if details.known_for_department.is_empty()
+            && let Some(department) = search_result.known_for_department
+        {
+            details.known_for_department = department;
+        }
+        if details.profile_path.is_none() {
+            details.profile_path = search_result.profile_path;
+        }
+        Self {
+            details,
+            known_for: search_result.known_for,
+        }
+    }
+}
+
+/// A person's IDs and handles on other sites, from `/person/{id}/external_ids`.
+/// Every field is null (or empty) when unknown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalIds {
+    /// IMDb ID (with "nm" prefix)
+    pub imdb_id: Option<String>,
+    pub facebook_id: Option<String>,
+    pub instagram_id: Option<String>,
+    /// X (formerly Twitter) handle
+    pub twitter_id: Option<String>,
+    pub tiktok_id: Option<String>,
+    pub wikidata_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Genre {
+    /// TMDB genre ID
+    pub id: u32,
+    /// Genre display name (e.g. "Action")
+    pub name: String,
+}
+
+/// Genre names by ID (see `TmdbClient::genre_names`), or None when the genre list could not
+/// be fetched.
+#[derive(Debug, Clone)]
+pub struct GenreNames(Option<HashMap<u32, String>>);
+
+impl GenreNames {
+    /// Returns the genre's name, or `genre {id}` if the genre list is unavailable or doesn't
+    /// include it.
+    pub fn name(&self, id: u32) -> String {
+        self.0
+            .as_ref()
+            .and_then(|names| names.get(&id))
+            .cloned()
+            .unwrap_or_else(|| format!("genre {id}"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenreResponse {
+    genres: Vec<Genre>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TmdbConfiguration {
+    /// Image base URLs and the sizes available for each image type
+    pub images: ImageConfiguration,
+    /// Keys that can be used with the changes endpoints
+    pub change_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageConfiguration {
+    pub base_url: String,
+    pub secure_base_url: String,
+    pub backdrop_sizes: Vec<String>,
+    pub logo_sizes: Vec<String>,
+    pub poster_sizes: Vec<String>,
+    pub profile_sizes: Vec<String>,
+    pub still_sizes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonDetails {
+    /// Whether the person is marked as adult content
+    pub adult: bool,
+
+    /// Alternative names in different languages/scripts
+    #[serde(rename = "also_known_as")]
+    pub also_known_as: Vec<String>,
+
+    /// Biography text (often sourced from Wikipedia or TMDB editors)
+    pub biography: String,
+
+    /// Birth date in YYYY-MM-DD format
+    pub birthday: Option<String>,
+
+    /// Death date in YYYY-MM-DD format (null if still alive)
+    pub deathday: Option<String>,
+
+    /// Gender code (0 = unknown, 1 = female, 2 = male, 3 = non-binary)
+    pub gender: u8,
+
+    /// Official personal or agency homepage URL (often null)
+    pub homepage: Option<String>,
+
+    /// TMDB person ID
+    pub id: u32,
+
+    /// Corresponding IMDb ID (with "nm" prefix)
+    #[serde(rename = "imdb_id")]
+    pub imdb_id: Option<String>,
+
+    /// Primary department this person is known for
+    #[serde(rename = "known_for_department")]
+    pub known_for_department: String,
+
+    /// Primary name used for display
+    pub name: String,
+
+    /// Place of birth (city, country, etc.)
+    #[serde(rename = "place_of_birth")]
+    pub place_of_birth: Option<String>,
+
+    /// Popularity score (higher = more popular)
+    pub popularity: f64,
+
+    /// Relative path to profile image
+    #[serde(rename = "profile_path")]
+    pub profile_path: Option<String>,
+}
+
+impl PersonDetails {
+    /// Returns a one-line summary without the biography, for terse output, e.g.
+    /// `Tom Hanks - TMDB ID: 31, born 1956-07-09 in Concord, California, USA`.
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{} - TMDB ID: {}", self.name, self.id);
+        if let Some(birthday) = &self.birthday {
+            summary.push_str(&format!(", born {birthday}"));
+        }
+        if let Some(place_of_birth) = &self.place_of_birth {
+            summary.push_str(&format!(" in {place_of_birth}"));
+        }
+        summary
+    }
+
+    /// Renders the full details, including the biography, leaving out or labelling unknown
+    /// fields according to `missing`.
+    pub fn render(&self, missing: MissingFields) -> String {
+        [
+            Some(format!("ID: {}", self.id)),
+            Some(format!("Name: {}", self.name)),
+            missing.field("Date of Birth", self.birthday.as_deref()),
+            missing.field("Place of Birth", self.place_of_birth.as_deref()),
+            missing.field("Biography", Some(&self.biography)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("\n")
+    }
+}
+
+/// Implements Display for PersonDetails to show the full details, including the biography.
+impl Display for PersonDetails {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(MissingFields::Label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::{MockResponse, MockTmdb, fixtures};
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn with_client_sends_requests_through_the_injected_client() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"));
+        let client = Client::builder()
+            .default_headers(HeaderMap::from_iter([(
+                AUTHORIZATION,
+                HeaderValue::from_static("Bearer test-token"),
+            )]))
+            .build()
+            .unwrap();
+
+        let tmdb = TmdbClient::with_client(client, mock.base_url().to_string());
+        let person = tmdb.person_details(31, "en-US").await.unwrap().unwrap();
+
+        assert_eq!(person.name, "Tom Hanks");
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path(), "/person/31");
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer test-token")
+        );
+    }
+
+    #[tokio::test]
+    async fn warm_up_fills_the_genre_and_configuration_caches() {
+        let mock = MockTmdb::start().await;
+        mock.json("/genre/movie/list", fixtures::genres())
+            .json("/configuration", fixtures::configuration());
+        let tmdb = mock.client();
+
+        tmdb.warm_up().await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+
+        tmdb.movie_genres().await.unwrap();
+        tmdb.configuration().await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn warm_up_reports_a_failed_prefetch() {
+        let mock = MockTmdb::start().await;
+        mock.json("/genre/movie/list", fixtures::genres());
+
+        assert!(mock.client().warm_up().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn actor_info_merges_search_and_detail_fields() {
+        let mock = MockTmdb::start().await;
+        let mut summary = fixtures::person_summary(31, "Tom Hanks");
+        summary["profile_path"] = "/search.jpg".into();
+        summary["known_for"] = json!([{
+            "id": 13,
+            "media_type": "movie",
+            "title": "Forrest Gump",
+            "release_date": "1994-06-23",
+        }]);
+        mock.json("/search/person", fixtures::single_page(vec![summary]))
+            .json("/person/31", fixtures::person(31, "Tom Hanks"));
+
+        let profile = mock
+            .client()
+            .actor_info("Tom Hanks", None)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // detail-only fields
+        assert_eq!(profile.details.biography, "Tom Hanks is an actor.");
+        assert_eq!(profile.details.birthday.as_deref(), Some("1956-07-09"));
+        // search-only fields, and one the detail endpoint left empty
+        assert_eq!(profile.known_for[0].display_title(), "Forrest Gump");
+        assert_eq!(profile.details.profile_path.as_deref(), Some("/search.jpg"));
+    }
+
+    #[tokio::test]
+    async fn genre_names_fall_back_to_ids_when_the_genre_list_fails() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/genre/movie/list",
+            MockResponse::bytes(502, "text/html", "<html>"),
+        );
+        let client = mock.client();
+
+        let names = client.genre_names().await;
+        assert_eq!(names.name(35), "genre 35");
+        assert!(client.genre_fallback_logged.load(Ordering::Relaxed));
+
+        // once the list loads, names replace the IDs
+        mock.json("/genre/movie/list", fixtures::genres());
+        assert_eq!(client.genre_names().await.name(35), "Comedy");
+        assert_eq!(client.genre_names().await.name(99), "genre 99");
+    }
+
+    #[tokio::test]
+    async fn pages_past_the_last_tmdb_serves_are_clamped() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/popular?page=500",
+            fixtures::page(
+                500,
+                800,
+                16000,
+                vec![fixtures::person_summary(31, "Tom Hanks")],
+            ),
+        );
+
+        let page = mock.client().popular_people(501).await.unwrap();
+
+        assert_eq!(mock.requests()[0].query("page"), Some("500"));
+        assert_eq!(page.page, MAX_PAGE);
+        assert!(!page.has_more());
+        assert!(page.at_page_limit());
+    }
+
+    /// A `/search/person` response as TMDB sends it.
+    fn search_person_response() -> Value {
+        json!({
+            "page": 1,
+            "results": [{
+                "adult": false,
+                "gender": 2,
+                "id": 31,
+                "known_for_department": "Acting",
+                "name": "Tom Hanks",
+                "original_name": "Tom Hanks",
+                "popularity": 64.8,
+                "profile_path": "/xndWFsBlClOJFRdhSt4NBwiPq2o.jpg",
+                "known_for": [
+                    {
+                        "adult": false,
+                        "backdrop_path": "/qdIMHd4sEfJSckfVJfKQvisL02a.jpg",
+                        "id": 13,
+                        "title": "Forrest Gump",
+                        "original_language": "en",
+                        "original_title": "Forrest Gump",
+                        "overview": "A man with a low IQ has accomplished great things in his life.",
+                        "poster_path": "/arw2vcBveWOVZr6pxd9XTd1TdQa.jpg",
+                        "media_type": "movie",
+                        "genre_ids": [35, 18, 10749],
+                        "popularity": 98.2,
+                        "release_date": "1994-06-23",
+                        "video": false,
+                        "vote_average": 8.5,
+                        "vote_count": 27000
+                    },
+                    {
+                        "adult": false,
+                        "backdrop_path": null,
+                        "id": 4613,
+                        "name": "Band of Brothers",
+                        "original_language": "en",
+                        "original_name": "Band of Brothers",
+                        "overview": "Drawn from interviews with survivors of Easy Company.",
+                        "poster_path": "/8JMXquNmdMUy2n2RgW8gfOM0O3l.jpg",
+                        "media_type": "tv",
+                        "genre_ids": [18, 10768],
+                        "popularity": 80.1,
+                        "first_air_date": "2001-09-09",
+                        "vote_average": 8.6,
+                        "vote_count": 3800,
+                        "origin_country": ["US"]
+                    }
+                ]
+            }],
+            "total_pages": 1,
+            "total_results": 1
+        })
+    }
+
+    #[test]
+    fn strict_parsing_accepts_fields_ignored_on_purpose() {
+        let page: Paginated<PersonSummary> = parse_strict(search_person_response()).unwrap();
+        assert_eq!(page.results[0].known_for.len(), 2);
+
+        let external_ids: ExternalIds = parse_strict(json!({
+            "id": 31,
+            "imdb_id": "nm0000158",
+            "facebook_id": null,
+            "instagram_id": "tomhanks",
+            "twitter_id": "tomhanks",
+            "tiktok_id": null,
+            "wikidata_id": "Q2263",
+        }))
+        .unwrap();
+        assert_eq!(external_ids.instagram_id.as_deref(), Some("tomhanks"));
+    }
+
+    #[test]
+    fn strict_parsing_accepts_full_tmdb_payloads() {
+        let movie: MovieFull =
+            parse_strict(fixtures::movie_full(13, "Forrest Gump", "1994-06-23")).unwrap();
+        assert_eq!(movie.title, "Forrest Gump");
+
+        let credits: MovieCredits = parse_strict(fixtures::credits(
+            13,
+            vec![fixtures::cast_member(31, "Tom Hanks", "Forrest Gump", 0)],
+            vec![fixtures::crew_member(24, "Robert Zemeckis", "Director")],
+        ))
+        .unwrap();
+        assert_eq!(credits.cast.len(), 1);
+        assert_eq!(credits.crew.len(), 1);
+
+        let filmography: MovieCreditsResponse =
+            parse_strict(fixtures::movie_credits(vec![fixtures::credit(
+                13,
+                "Forrest Gump",
+                "1994-06-23",
+                "Forrest Gump",
+            )]))
+            .unwrap();
+        assert_eq!(filmography.cast.len(), 1);
+
+        let rated: Paginated<RatedMovie> = parse_strict(fixtures::single_page(vec![{
+            let mut movie = fixtures::movie(13, "Forrest Gump", "1994-06-23");
+            movie["rating"] = json!(9.0);
+            movie
+        }]))
+        .unwrap();
+        assert_eq!(rated.results[0].rating, 9.0);
+
+        let account: AccountDetails = parse_strict(fixtures::account(42, "fan")).unwrap();
+        assert_eq!(account.id, 42);
+
+        let providers: WatchProvidersResponse = parse_strict(json!({
+            "id": 13,
+            "results": { "US": {
+                "link": "https://www.themoviedb.org/movie/13/watch?locale=US",
+                "flatrate": [{
+                    "logo_path": "/netflix.jpg",
+                    "provider_id": 8,
+                    "provider_name": "Netflix",
+                    "display_priority": 0,
+                }],
+            } },
+        }))
+        .unwrap();
+        assert_eq!(
+            providers.results["US"].streaming()[0].provider_name,
+            "Netflix"
+        );
+
+        let images: MovieImages = parse_strict(json!({
+            "id": 13,
+            "backdrops": [],
+            "logos": [],
+            "posters": [{
+                "aspect_ratio": 0.667,
+                "height": 1500,
+                "iso_639_1": "en",
+                "file_path": "/poster.jpg",
+                "vote_average": 5.3,
+                "vote_count": 10,
+                "width": 1000,
+            }],
+        }))
+        .unwrap();
+        assert_eq!(images.posters.len(), 1);
+
+        let mut part = fixtures::movie(11, "Star Wars", "1977-05-25");
+        part["media_type"] = json!("movie");
+        let collection: Collection = parse_strict(json!({
+            "id": 10,
+            "name": "Star Wars Collection",
+            "original_language": "en",
+            "original_name": "Star Wars Collection",
+            "overview": "",
+            "poster_path": null,
+            "backdrop_path": null,
+            "parts": [part],
+        }))
+        .unwrap();
+        assert_eq!(collection.parts.len(), 1);
+    }
+
+    #[test]
+    fn a_movie_without_a_release_date_renders_by_mode() {
+        use crate::tmdb_client::test_server::fixtures;
+
+        let dated: MovieCredit = serde_json::from_value(fixtures::credit(
+            13,
+            "Forrest Gump",
+            "1994-07-06",
+            "Forrest",
+        ))
+        .unwrap();
+        let undated: MovieCredit =
+            serde_json::from_value(fixtures::credit(14, "Untitled", "", "")).unwrap();
+
+        assert_eq!(
+            dated.render(MissingFields::Omit),
+            "Forrest Gump (1994) (as Forrest)"
+        );
+        assert_eq!(dated.render(MissingFields::Label), dated.to_string());
+        assert_eq!(undated.render(MissingFields::Omit), "Untitled");
+        assert_eq!(undated.to_string(), "Untitled");
+        assert_eq!(
+            undated.render(MissingFields::Label),
+            "Untitled (Unknown year)"
+        );
+        assert_eq!(
+            undated.movie.render(MissingFields::Label),
+            "Untitled (Unknown year)"
+        );
+    }
+
+    #[tokio::test]
+    async fn an_empty_person_search_is_not_found() {
+        let mock = MockTmdb::start().await;
+        mock.json("/search/person", fixtures::single_page(vec![]));
+
+        assert_eq!(mock.client().actor_id("Nobody").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn a_person_search_without_results_is_a_decode_error() {
+        let mock = MockTmdb::start().await;
+        mock.json("/search/person", json!({ "page": 1, "total_results": 0 }));
+
+        let err = mock.client().actor_id("Tom Hanks").await.unwrap_err();
+
+        assert!(matches!(err, TmdbError::Decode(_)), "{err:?}");
+        assert!(err.to_string().contains("no `results` array"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn a_person_search_result_without_an_id_is_a_decode_error() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/search/person",
+            fixtures::single_page(vec![json!({ "name": "Tom Hanks", "id": "31" })]),
+        );
+
+        let err = mock.client().actor_id("Tom Hanks").await.unwrap_err();
+
+        assert!(matches!(err, TmdbError::Decode(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn safe_search_hides_adult_known_for_titles() {
+        let mock = MockTmdb::start().await;
+        let mut person = fixtures::person_summary(31, "Tom Hanks");
+        person["known_for"] = json!([
+            { "id": 13, "media_type": "movie", "title": "Forrest Gump", "adult": false },
+            { "id": 666, "media_type": "movie", "title": "Adult Film", "adult": true }
+        ]);
+        mock.json("/search/person", fixtures::single_page(vec![person]));
+
+        let people = mock.client().search_people("Tom Hanks").await.unwrap();
+        let titles: Vec<_> = people.results[0]
+            .known_for
+            .iter()
+            .map(KnownFor::display_title)
+            .collect();
+        assert_eq!(titles, ["Forrest Gump"]);
+
+        let client = mock.client_with(|config| config.safe_search = false);
+        let people = client.search_people("Tom Hanks").await.unwrap();
+        assert_eq!(people.results[0].known_for.len(), 2);
+    }
+
+    #[test]
+    fn retaining_results_lowers_the_total() {
+        let mut page = Paginated {
+            page: 1,
+            total_pages: 3,
+            total_results: 45,
+            results: vec![1, 2, 3, 4, 5],
+        };
+
+        page.retain(|number| number % 2 == 1);
+
+        assert_eq!(page.results, [1, 3, 5]);
+        assert_eq!(page.total_results, 43);
+        assert_eq!(page.total_pages, 3);
+        assert!(page.has_more());
+    }
+
+    #[test]
+    fn strict_parsing_rejects_unexpected_fields() {
+        let mut response = search_person_response();
+        response["results"][0]["birth_name"] = "Thomas Jeffrey Hanks".into();
+        response["results"][0]["known_for"][1]["tagline"] = "".into();
+
+        let err = parse_strict::<Paginated<PersonSummary>>(response).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unexpected response from TMDB: unknown fields in TMDB response: results[0].birth_name, results[0].known_for[1].tagline"
+        );
+    }
+
+    #[test]
+    fn known_for_matches_whole_titles_and_release_years() {
+        let movie: KnownFor = serde_json::from_value(json!({
+            "id": 1366,
+            "media_type": "movie",
+            "title": "Rocky",
+            "release_date": "1976-11-21",
+        }))
+        .unwrap();
+        let show: KnownFor = serde_json::from_value(json!({
+            "id": 1396,
+            "media_type": "tv",
+            "name": "Breaking Bad",
+            "first_air_date": "2008-01-20",
+        }))
+        .unwrap();
+
+        assert!(movie.matches(" rocky "));
+        assert!(movie.matches("1976"));
+        assert!(!movie.matches("Rock"));
+        assert!(!movie.matches("76"));
+        assert!(!movie.matches(""));
+        assert!(show.matches("Breaking Bad"));
+        assert!(show.matches("2008"));
+        assert!(!show.matches("Bad"));
+    }
+
+    #[test]
+    fn array_indexes_are_generalized_in_field_paths() {
+        assert_eq!(
+            any_index("results[12].known_for[0].overview"),
+            "results[].known_for[].overview"
+        );
+        assert_eq!(any_index("id"), "id");
+    }
+
+    #[tokio::test]
+    async fn image_downloads_are_retried_after_a_server_error() {
+        let mock = MockTmdb::start().await;
+        mock.on("/w92/face.jpg", MockResponse::status(503, json!({})))
+            .on(
+                "/w92/face.jpg",
+                MockResponse::bytes(200, "image/jpeg", "jpeg"),
+            );
+
+        let image = mock.client().image_as_base64("/face.jpg").await.unwrap();
+
+        assert_eq!(image, general_purpose::STANDARD.encode("jpeg"));
+        assert_eq!(mock.hits("/w92/face.jpg"), 2);
+    }
+
+    #[tokio::test]
+    async fn a_short_image_body_is_retried_instead_of_encoded() {
+        let mock = MockTmdb::start().await;
+        let short = MockResponse::bytes(200, "image/jpeg", "jp").header("Content-Length", "4");
+        mock.on("/w92/face.jpg", short.clone()).on(
+            "/w92/face.jpg",
+            MockResponse::bytes(200, "image/jpeg", "jpeg"),
+        );
+
+        let image = mock.client().image_as_base64("/face.jpg").await.unwrap();
+
+        assert_eq!(image, general_purpose::STANDARD.encode("jpeg"));
+        assert_eq!(mock.hits("/w92/face.jpg"), 2);
+
+        // a body that is always cut short fails once the retries are used up
+        let mock = MockTmdb::start().await;
+        mock.on("/w92/face.jpg", short);
+
+        let err = mock
+            .client()
+            .image_as_base64("/face.jpg")
+            .await
+            .unwrap_err();
+
+        assert!(is_retryable_image_error(&err), "{err:?}");
+        assert_eq!(mock.hits("/w92/face.jpg"), 1 + IMAGE_MAX_RETRIES as usize);
+    }
+
+    #[tokio::test]
+    async fn api_requests_are_retried_after_a_server_error() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/person/31",
+            MockResponse::bytes(502, "text/html", "<html>"),
+        )
+        .json("/person/31", fixtures::person(31, "Tom Hanks"));
+
+        let person = mock.client().person_details(31, "en-US").await.unwrap();
+
+        assert_eq!(person.unwrap().name, "Tom Hanks");
+        assert_eq!(mock.hits("/person/31"), 2);
+    }
+
+    #[tokio::test]
+    async fn an_exhausted_retry_budget_fails_fast() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/person/31",
+            MockResponse::bytes(500, "text/html", "<html>"),
+        )
+        .on("/w92/face.jpg", MockResponse::status(500, json!({})));
+        let tmdb = mock.client_with(|config| config.retry_budget = 2);
+
+        // the first request retries until its attempts run out, using up the whole budget
+        assert!(tmdb.person_details(31, "en-US").await.is_err());
+        assert_eq!(mock.hits("/person/31"), 1 + API_MAX_RETRIES as usize);
+
+        // later failures, for API requests and images alike, are not retried
+        assert!(tmdb.person_details(31, "en-US").await.is_err());
+        assert_eq!(mock.hits("/person/31"), 2 + API_MAX_RETRIES as usize);
+        assert!(tmdb.image_as_base64("/face.jpg").await.is_err());
+        assert_eq!(mock.hits("/w92/face.jpg"), 1);
+        // running out is reported once, however many requests went without a retry
+        assert_eq!(
+            tmdb.retry_budget().take_changes(),
+            [BudgetChange::Exhausted]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/person/31",
+            MockResponse::status(
+                401,
+                json!({ "success": false, "status_code": 7, "status_message": "Invalid API key" }),
+            ),
+        );
+
+        assert!(mock.client().person_details(31, "en-US").await.is_err());
+        assert_eq!(mock.hits("/person/31"), 1);
+    }
+
+    #[tokio::test]
+    async fn missing_images_are_not_retried() {
+        let mock = MockTmdb::start().await;
+
+        let err = mock
+            .client()
+            .image_as_base64("/gone.jpg")
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(&err, TmdbError::Http(err) if err.status() == Some(StatusCode::NOT_FOUND))
+        );
+        assert_eq!(mock.hits("/w92/gone.jpg"), 1);
+    }
+
+    #[tokio::test]
+    async fn fresh_cached_responses_are_reused_without_a_request() {
+        let mock = MockTmdb::start().await;
+        mock.json("/configuration", fixtures::configuration());
+        let tmdb = mock.client();
+
+        tmdb.configuration().await.unwrap();
+        tmdb.configuration().await.unwrap();
+
+        assert_eq!(mock.hits("/configuration"), 1);
+    }
+
+    /// How long the cached `/configuration` response stays fresh.
+    fn configuration_ttl(tmdb: &TmdbClient) -> Duration {
+        let cache = tmdb.configuration_cache.lock().unwrap();
+        cache.as_ref().unwrap().expires_at - Instant::now()
+    }
+
+    #[tokio::test]
+    async fn max_age_sets_how_long_a_response_is_cached() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/configuration",
+            MockResponse::json(fixtures::configuration())
+                .header("Cache-Control", "public, max-age=3600"),
+        );
+        let tmdb = mock.client();
+
+        tmdb.configuration().await.unwrap();
+
+        let ttl = configuration_ttl(&tmdb);
+        assert!(ttl > Duration::from_secs(3590) && ttl <= Duration::from_secs(3600));
+    }
+
+    #[tokio::test]
+    async fn an_overflowing_max_age_is_capped() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/configuration",
+            MockResponse::json(fixtures::configuration())
+                .header("Cache-Control", &format!("max-age={}", u64::MAX)),
+        );
+        let tmdb = mock.client();
+
+        tmdb.configuration().await.unwrap();
+
+        assert!(configuration_ttl(&tmdb) <= MAX_CACHE_TTL);
+    }
+
+    #[test]
+    fn freshness_follows_cache_control() {
+        let tmdb = TmdbClient::with_client(Client::new(), "http://localhost".to_string());
+        let headers = |value: &str| HeaderMap::from_iter([(CACHE_CONTROL, value.parse().unwrap())]);
+
+        assert_eq!(
+            tmdb.freshness(&headers("max-age=\"60\"")),
+            Duration::from_secs(60)
+        );
+        assert_eq!(tmdb.freshness(&headers("no-store")), Duration::ZERO);
+        assert_eq!(tmdb.freshness(&headers("public")), tmdb.config.cache_ttl);
+        assert_eq!(tmdb.freshness(&HeaderMap::new()), tmdb.config.cache_ttl);
+    }
+
+    #[tokio::test]
+    async fn stale_responses_are_revalidated_with_their_etag() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/genre/movie/list",
+            MockResponse::json(fixtures::genres()).header("ETag", "\"v1\""),
+        )
+        .on(
+            "/genre/movie/list",
+            MockResponse::bytes(304, "application/json", ""),
+        );
+        let tmdb = mock.client_with(|config| config.cache_ttl = Duration::ZERO);
+
+        let first = tmdb.movie_genres().await.unwrap();
+        let second = tmdb.movie_genres().await.unwrap();
+
+        assert_eq!(first.len(), second.len());
+        let requests = mock.requests();
+        assert_eq!(requests[0].header("if-none-match"), None);
+        assert_eq!(requests[1].header("if-none-match"), Some("\"v1\""));
+    }
+
+    #[tokio::test]
+    async fn images_over_the_size_limit_are_rejected() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/w92/big.jpg",
+            MockResponse::bytes(200, "image/jpeg", vec![0; 64]),
+        );
+        let tmdb = mock.client_with(|config| config.max_image_bytes = 32);
+
+        let err = tmdb.image_as_base64("/big.jpg").await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            TmdbError::ImageTooLarge {
+                size: 64,
+                limit: 32
+            }
+        ));
+        assert_eq!(mock.hits("/w92/big.jpg"), 1);
+    }
+
+    #[tokio::test]
+    async fn images_within_the_size_limit_are_encoded() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/w92/small.jpg",
+            MockResponse::bytes(200, "image/jpeg", vec![0; 32]),
+        );
+        let tmdb = mock.client_with(|config| config.max_image_bytes = 32);
+
+        let image = tmdb.image_as_base64("/small.jpg").await.unwrap();
+
+        assert_eq!(image, general_purpose::STANDARD.encode([0; 32]));
+    }
+
+    #[tokio::test]
+    async fn with_config_sends_the_token_and_user_agent() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"));
+        let mut config =
+            TmdbConfig::with_defaults("secret".to_string(), mock.base_url().to_string());
+        config.user_agent = "movie-bot/2.0".to_string();
+
+        TmdbClient::with_config(config)
+            .person_details(31, "en-US")
+            .await
+            .unwrap();
+
+        let request = &mock.requests()[0];
+        assert_eq!(request.header("user-agent"), Some("movie-bot/2.0"));
+        assert_eq!(request.header("authorization"), Some("Bearer secret"));
+        assert_eq!(request.header("accept"), Some("application/json"));
+    }
+
+    #[tokio::test]
+    async fn with_config_applies_the_connection_pool_settings() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"));
+        let mut config =
+            TmdbConfig::with_defaults("secret".to_string(), mock.base_url().to_string());
+        config.pool_max_idle_per_host = Some(0);
+        config.pool_idle_timeout = Some(Duration::from_secs(1));
+        let tmdb = TmdbClient::with_config(config);
+
+        // with no idle connections kept, each request still goes through on a new one
+        for _ in 0..2 {
+            tmdb.person_details(31, "en-US").await.unwrap().unwrap();
+        }
+        assert_eq!(mock.hits("/person/31"), 2);
+    }
+
+    #[test]
+    fn the_week_window_uses_a_fixed_ttl() {
+        // Sunday 23:59:59 UTC, a moment before a calendar week would roll over
+        let sunday = UNIX_EPOCH + Duration::from_secs(1_760_313_599);
+        assert_eq!(TimeWindow::Week.cache_ttl(sunday), WEEK_TRENDING_TTL);
+        assert_eq!(TimeWindow::Day.cache_ttl(sunday), Duration::from_secs(1));
+        assert_eq!(
+            TimeWindow::Day.cache_ttl(sunday + Duration::from_secs(1)),
+            Duration::from_secs(86_400)
+        );
+    }
+
+    #[tokio::test]
+    async fn trending_movies_are_refetched_once_the_cache_expires() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/trending/movie/week",
+            fixtures::single_page(vec![fixtures::movie(13, "Forrest Gump", "1994-06-23")]),
+        );
+        let client = mock.client();
+
+        client.trending_movies(TimeWindow::Week).await.unwrap();
+        client.trending_movies(TimeWindow::Week).await.unwrap();
+        assert_eq!(mock.hits("/trending/movie/week"), 1);
+
+        // the rolling week moved on: the cached entry is past its TTL
+        client
+            .trending_cache
+            .lock()
+            .unwrap()
+            .get_mut(&TimeWindow::Week)
+            .unwrap()
+            .expires_at = Instant::now();
+        let movies = client.trending_movies(TimeWindow::Week).await.unwrap();
+        assert_eq!(mock.hits("/trending/movie/week"), 2);
+        assert_eq!(movies.results[0].title, "Forrest Gump");
+    }
+
+    #[tokio::test]
+    async fn error_envelopes_are_reported_whatever_the_status() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/person/31/movie_credits",
+            MockResponse::status(
+                401,
+                json!({
+                    "success": false,
+                    "status_code": 7,
+                    "status_message": "Invalid API key: You must be granted a valid key."
+                }),
+            ),
+        );
+
+        let err = mock.client().person_movie_credits(31).await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            TmdbError::Api {
+                status: 401,
+                status_code: Some(7),
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "TMDB error 7: Invalid API key: You must be granted a valid key."
+        );
+        assert!(!err.is_unavailable());
+    }
+
+    #[tokio::test]
+    async fn server_error_envelopes_count_as_unavailable() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/trending/movie/day",
+            MockResponse::status(
+                500,
+                json!({ "success": false, "status_code": 11, "status_message": "Internal error" }),
+            ),
+        );
+
+        let err = mock
+            .client()
+            .trending_movies(TimeWindow::Day)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TmdbError::Api { status: 500, .. }));
+        assert!(err.is_unavailable());
+    }
+
+    #[tokio::test]
+    async fn error_statuses_without_an_envelope_stay_http_errors() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/person/31/movie_credits",
+            MockResponse::bytes(502, "text/html", "<html>Bad Gateway</html>"),
+        );
+
+        let err = mock.client().person_movie_credits(31).await.unwrap_err();
+
+        assert!(
+            matches!(&err, TmdbError::Http(err) if err.status() == Some(StatusCode::BAD_GATEWAY))
+        );
+    }
+
+    #[tokio::test]
+    async fn an_html_page_labelled_as_json_is_a_non_json_error() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/person/31/movie_credits",
+            MockResponse::bytes(
+                200,
+                "application/json",
+                "<html><body>Service temporarily unavailable</body></html>",
+            ),
+        );
+
+        let err = mock.client().person_movie_credits(31).await.unwrap_err();
+
+        assert!(matches!(err, TmdbError::NonJson { status: 200 }));
+        assert_eq!(
+            err.to_string(),
+            "Unexpected non-JSON response from TMDB (status 200)"
+        );
+    }
+
+    #[tokio::test]
+    async fn a_binary_body_is_a_non_json_error() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/person/31/movie_credits",
+            MockResponse::bytes(200, "application/octet-stream", [0xff, 0xfe, 0x00, 0x9c]),
+        );
+
+        let err = mock.client().person_movie_credits(31).await.unwrap_err();
+
+        assert!(matches!(err, TmdbError::NonJson { status: 200 }));
+    }
+
+    /// Compresses `data` as a gzip stream of stored (uncompressed) deflate blocks, which
+    /// decoders must accept like any other.
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let crc = !data.iter().fold(!0u32, |crc, byte| {
+            (0..8).fold(crc ^ u32::from(*byte), |crc, _| {
+                (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg())
+            })
+        });
+        let mut out = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff];
+        let mut blocks = data.chunks(usize::from(u16::MAX)).peekable();
+        if blocks.peek().is_none() {
+            out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+        }
+        while let Some(block) = blocks.next() {
+            let len = block.len() as u16;
+            out.push(u8::from(blocks.peek().is_none()));
+            out.extend_from_slice(&len.to_le_bytes());
+            out.extend_from_slice(&(!len).to_le_bytes());
+            out.extend_from_slice(block);
+        }
+        out.extend_from_slice(&crc.to_le_bytes());
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out
+    }
+
+    #[tokio::test]
+    async fn gzip_responses_are_decompressed() {
+        let mock = MockTmdb::start().await;
+        let credits = fixtures::movie_credits(
+            (0..500)
+                .map(|id| fixtures::credit(id, &format!("Movie {id}"), "2000-01-01", "Himself"))
+                .collect(),
+        );
+        mock.on(
+            "/person/31/movie_credits",
+            MockResponse::bytes(
+                200,
+                "application/json;charset=utf-8",
+                gzip(credits.to_string().as_bytes()),
+            )
+            .header("Content-Encoding", "gzip"),
+        );
+
+        let credits = mock.client().person_movie_credits(31).await.unwrap();
+
+        assert_eq!(credits.len(), 500);
+        assert_eq!(credits[499].movie.title, "Movie 499");
+        let accept_encoding = mock.requests()[0]
+            .header("accept-encoding")
+            .unwrap()
+            .to_string();
+        assert!(accept_encoding.contains("gzip"), "{accept_encoding}");
+        assert!(accept_encoding.contains("br"), "{accept_encoding}");
+    }
+
+    #[tokio::test]
+    async fn sustained_503s_open_the_breaker_until_the_cooldown_passes() {
+        let mock = MockTmdb::start().await;
+        let unavailable = MockResponse::status(503, json!({}));
+        mock.on("/person/31/movie_credits", unavailable.clone())
+            .on("/person/31/movie_credits", unavailable)
+            .on(
+                "/person/31/movie_credits",
+                MockResponse::json(fixtures::movie_credits(vec![])),
+            );
+        let client = mock.client_with(|config| {
+            config.breaker_threshold = 2;
+            config.breaker_cooldown = Duration::from_millis(100);
+            config.retry_budget = 0;
+        });
+
+        for _ in 0..2 {
+            client.person_movie_credits(31).await.unwrap_err();
+        }
+        let err = client.person_movie_credits(31).await.unwrap_err();
+        assert!(matches!(err, TmdbError::CircuitOpen { .. }));
+        assert_eq!(mock.hits("/person/31/movie_credits"), 2);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(client.person_movie_credits(31).await.unwrap().is_empty());
+        assert!(client.person_movie_credits(31).await.is_ok());
+        assert_eq!(mock.hits("/person/31/movie_credits"), 4);
+    }
+
+    #[tokio::test]
+    async fn the_account_id_is_looked_up_once() {
+        let mock = MockTmdb::start().await;
+        mock.json("/account", fixtures::account(42, "fan"))
+            .json("/account/42/favorite/movies", fixtures::single_page(vec![]))
+            .json("/account/42/rated/movies", fixtures::single_page(vec![]));
+        let client = mock.client_with(|config| config.session_id = Some("secret-session".into()));
+
+        client.account_favorites().await.unwrap();
+        client.account_rated_movies().await.unwrap();
+        client.account_favorites().await.unwrap();
+
+        assert_eq!(mock.hits("/account"), 1);
+        assert_eq!(mock.hits("/account/42/favorite/movies"), 2);
+        let request = mock.requests().pop().unwrap();
+        assert_eq!(request.query("session_id"), Some("secret-session"));
+    }
+
+    #[tokio::test]
+    async fn account_errors_do_not_reveal_the_session() {
+        let mock = MockTmdb::start().await;
+        mock.json("/account", fixtures::account(42, "fan")).on(
+            "/account/42/favorite/movies",
+            MockResponse::bytes(502, "text/html", "<html>Bad Gateway</html>"),
+        );
+        let client = mock.client_with(|config| {
+            config.session_id = Some("secret-session".into());
+            config.retry_budget = 0;
+        });
+
+        let err = client.account_favorites().await.unwrap_err();
+
+        assert!(
+            matches!(&err, TmdbError::Http(err) if err.status() == Some(StatusCode::BAD_GATEWAY))
+        );
+        assert!(!err.to_string().contains("secret-session"), "{err}");
+        assert!(!format!("{err:?}").contains("secret-session"), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn account_requests_need_a_session() {
+        let mock = MockTmdb::start().await;
+
+        let err = mock.client().account_favorites().await.unwrap_err();
+
+        assert!(matches!(err, TmdbError::NoSession));
+        assert!(mock.requests().is_empty());
     }
 }
@@ -0,0 +1,183 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Longest a breaker stays open, whatever cooldown was configured.
+const MAX_COOLDOWN: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Stops requests to TMDB for a while after it has answered `503 Service Unavailable`
+/// several times in a row, as it does during maintenance windows.
+///
+/// While the breaker is open, requests fail immediately instead of adding load to a service
+/// that is known to be down. Once the cooldown has passed, a single request is let through
+/// as a probe: a response other than a 503 closes the breaker, while another 503 reopens it
+/// straight away. A probe that never answers is replaced by another after a further cooldown.
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+struct State {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    /// Whether a probe was let through since the breaker last opened
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed breaker that opens for `cooldown` after `threshold` consecutive 503s.
+    /// A threshold of 0 disables the breaker.
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            state: Mutex::new(State {
+                consecutive_failures: 0,
+                open_until: None,
+                probing: false,
+            }),
+        }
+    }
+
+    /// Asks to send a request. Returns how much longer the breaker stays open if it may not
+    /// be sent; once the cooldown has passed, only the first caller is let through as a probe.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+        let Some(open_until) = state.open_until else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        if let Some(remaining) = open_until
+            .checked_duration_since(now)
+            .filter(|remaining| !remaining.is_zero())
+        {
+            return Err(remaining);
+        }
+
+        // hold everyone else back while the probe is answered
+        state.probing = true;
+        state.open_until = Some(self.reopen_until(now));
+        Ok(())
+    }
+
+    /// Whether requests are currently held back.
+    pub fn is_open(&self) -> bool {
+        self.state
+            .lock()
+            .unwrap()
+            .open_until
+            .is_some_and(|open_until| open_until > Instant::now())
+    }
+
+    /// Records the outcome of a request: `unavailable` is true for a 503 response.
+    pub fn record(&self, unavailable: bool) {
+        if self.threshold == 0 {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        if !unavailable {
+            if state.open_until.take().is_some() {
+                eprintln!("circuit breaker closed: TMDB is answering again");
+            }
+            state.consecutive_failures = 0;
+            state.probing = false;
+            return;
+        }
+
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.probing {
+            state.probing = false;
+            state.open_until = Some(self.reopen_until(Instant::now()));
+            eprintln!(
+                "circuit breaker reopened: TMDB still answers 503, pausing requests for {:?}",
+                self.cooldown
+            );
+        } else if state.open_until.is_none() && state.consecutive_failures >= self.threshold {
+            state.open_until = Some(self.reopen_until(Instant::now()));
+            eprintln!(
+                "circuit breaker open: TMDB answered 503 {} times in a row, pausing requests for {:?}",
+                state.consecutive_failures, self.cooldown
+            );
+        }
+    }
+
+    /// When a breaker opened at `now` lets a request through again. A cooldown too long to
+    /// add to `now` is capped at `MAX_COOLDOWN` instead of panicking.
+    fn reopen_until(&self, now: Instant) -> Instant {
+        now.checked_add(self.cooldown.min(MAX_COOLDOWN))
+            .unwrap_or(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COOLDOWN: Duration = Duration::from_millis(50);
+
+    #[test]
+    fn opens_after_consecutive_503s_and_closes_after_the_cooldown() {
+        let breaker = CircuitBreaker::new(3, COOLDOWN);
+
+        breaker.record(true);
+        breaker.record(true);
+        assert_eq!(breaker.try_acquire(), Ok(()));
+        breaker.record(true);
+        let remaining = breaker.try_acquire().unwrap_err();
+        assert!(remaining <= COOLDOWN);
+        assert!(breaker.is_open());
+
+        std::thread::sleep(COOLDOWN);
+
+        // only one probe goes through until it is answered
+        assert_eq!(breaker.try_acquire(), Ok(()));
+        assert!(breaker.try_acquire().is_err());
+        breaker.record(false);
+        assert!(!breaker.is_open());
+        assert_eq!(breaker.try_acquire(), Ok(()));
+        assert_eq!(breaker.try_acquire(), Ok(()));
+    }
+
+    #[test]
+    fn a_failed_probe_reopens_the_breaker() {
+        let breaker = CircuitBreaker::new(1, COOLDOWN);
+        breaker.record(true);
+        std::thread::sleep(COOLDOWN);
+
+        assert_eq!(breaker.try_acquire(), Ok(()));
+        breaker.record(true);
+
+        assert!(breaker.try_acquire().is_err());
+    }
+
+    #[test]
+    fn an_unanswered_probe_is_replaced_after_another_cooldown() {
+        let breaker = CircuitBreaker::new(1, COOLDOWN);
+        breaker.record(true);
+        std::thread::sleep(COOLDOWN);
+        assert_eq!(breaker.try_acquire(), Ok(()));
+
+        std::thread::sleep(COOLDOWN);
+
+        assert_eq!(breaker.try_acquire(), Ok(()));
+    }
+
+    #[test]
+    fn a_huge_cooldown_is_capped() {
+        let breaker = CircuitBreaker::new(1, Duration::MAX);
+
+        breaker.record(true);
+
+        let remaining = breaker.try_acquire().unwrap_err();
+        assert!(remaining <= MAX_COOLDOWN);
+    }
+
+    #[test]
+    fn a_zero_threshold_never_opens() {
+        let breaker = CircuitBreaker::new(0, COOLDOWN);
+        for _ in 0..10 {
+            breaker.record(true);
+        }
+        assert_eq!(breaker.try_acquire(), Ok(()));
+    }
+}
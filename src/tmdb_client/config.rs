@@ -0,0 +1,928 @@
+use reqwest::{Url, header::HeaderValue};
+use std::collections::hash_map::RandomState;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::BuildHasher;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use std::{env, fs};
+
+/// Default TMDB API base URL.
+const DEFAULT_BASE_URL: &str = "https://api.themoviedb.org/3";
+
+/// Default TMDB image CDN base URL.
+const DEFAULT_IMAGE_BASE_URL: &str = "https://image.tmdb.org/t/p";
+
+/// Default image size requested from the CDN (see `/configuration` for available sizes).
+const DEFAULT_IMAGE_SIZE: &str = "w92";
+
+/// Default language for localized fields such as titles and biographies.
+const DEFAULT_LANGUAGE: &str = "en-US";
+
+/// Default timeout for JSON API requests.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default `User-Agent` sent with every request, e.g. `techshare-mcp/0.1.0`.
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Default limit on the raw size of a downloaded image, before base64 encoding.
+const DEFAULT_MAX_IMAGE_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Default maximum requests per second sent to TMDB, below TMDB's documented limit.
+const DEFAULT_RATE_LIMIT: f64 = 40.0;
+
+/// Default time a cached response stays fresh when TMDB sends no `Cache-Control: max-age`.
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Default number of retries the whole client may make per minute.
+const DEFAULT_RETRY_BUDGET: u32 = 10;
+
+/// Default number of consecutive `503` responses that pause requests to TMDB.
+const DEFAULT_BREAKER_THRESHOLD: u32 = 5;
+
+/// Default time requests stay paused once TMDB keeps answering `503`.
+const DEFAULT_BREAKER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Default upper bound of the random delay before the first request (no delay).
+const DEFAULT_STARTUP_JITTER: Duration = Duration::ZERO;
+
+/// Default number of biography characters shown before truncating.
+const DEFAULT_MAX_BIOGRAPHY_CHARS: usize = 1500;
+
+/// Default number of pages a tool may fetch from one paginated TMDB endpoint.
+const DEFAULT_MAX_PAGES: u32 = 5;
+
+/// All settings used by `TmdbClient`.
+#[derive(Clone)]
+pub struct TmdbConfig {
+    /// TMDB API read access token, sent as a bearer token
+    pub api_token: String,
+    /// TMDB user session ID, needed for account data such as favorites and ratings.
+    /// The account tools are only offered when it is set.
+    pub session_id: Option<String>,
+    /// Base URL of the TMDB API
+    pub base_url: String,
+    /// Base URL of the TMDB image CDN
+    pub image_base_url: String,
+    /// Image size requested from the CDN (e.g. `w92`, `w185`, `original`)
+    pub image_size: String,
+    /// Language for localized fields (e.g. `en-US`)
+    pub language: String,
+    /// Default ISO 3166-1 region (e.g. `US`) for tools whose `region` input is omitted, or None
+    /// if neither `TMDB_REGION` nor `language` names one
+    pub region: Option<String>,
+    /// Timeout for JSON API requests
+    pub timeout: Duration,
+    /// Value of the `User-Agent` header
+    pub user_agent: String,
+    /// Images larger than this many bytes are rejected instead of being encoded
+    pub max_image_bytes: u64,
+    /// Biographies longer than this many characters are truncated in tool output
+    pub max_biography_chars: usize,
+    /// Maximum requests per second sent to TMDB (0 disables rate limiting)
+    pub rate_limit: f64,
+    /// Whether to fetch the genre list and API configuration at startup
+    pub warmup: bool,
+    /// How long cached responses stay fresh when TMDB doesn't say (`Cache-Control: max-age`)
+    pub cache_ttl: Duration,
+    /// Retries the whole client may make per minute; once used up, failures are not retried
+    /// (0 disables retries)
+    pub retry_budget: u32,
+    /// Consecutive `503 Service Unavailable` responses after which requests are paused
+    /// (0 disables the circuit breaker)
+    pub breaker_threshold: u32,
+    /// How long requests stay paused before TMDB is tried again
+    pub breaker_cooldown: Duration,
+    /// Upper bound of the random delay before the first request, to spread out instances started together
+    pub startup_jitter: Duration,
+    /// Maximum idle connections kept open to TMDB (per host), or None for reqwest's default
+    /// (unlimited). Under sustained load, 8-64 is usually plenty: roughly the number of
+    /// concurrent requests, which `rate_limit` bounds anyway.
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept before closing, or None for reqwest's default (90s).
+    /// 30-120s reuses connections between bursts without holding them open indefinitely.
+    pub pool_idle_timeout: Option<Duration>,
+    /// Whether to offer experimental tools registered at startup (see `ToolRegistry`)
+    pub experimental_tools: bool,
+    /// Whether detail tools may return TMDB's unmodified JSON when called with `raw: true`
+    pub allow_raw: bool,
+    /// Whether to leave adult titles and people out of every search, discover and list result
+    pub safe_search: bool,
+    /// Directory where `filmography_diff` keeps the last filmography it saw for each actor
+    pub snapshot_dir: PathBuf,
+    /// Most pages a tool fetches from one paginated TMDB endpoint in a single call; output
+    /// notes when results beyond it were left out
+    pub max_pages: u32,
+    /// How rendered output shows optional fields TMDB has no value for, or None to let each
+    /// tool keep the way it has always shown them (see `missing_fields_or`)
+    pub missing_fields: Option<MissingFields>,
+    /// Rough limit, in tokens, on the text a tool call returns (None for no limit); longer
+    /// output is cut with a note (see `tools::truncate_output`)
+    pub max_output_tokens: Option<usize>,
+    /// Environment variables that were set but could not be parsed (their defaults were used)
+    invalid_env: Vec<String>,
+    /// Why no API token could be read from the environment, if it couldn't
+    token_problem: Option<String>,
+}
+
+impl TmdbConfig {
+    /// Builds a configuration from environment variables, falling back to defaults.
+    ///
+    /// | Variable                      | Setting                  |
+    /// |-------------------------------|--------------------------|
+    /// | `TMDB_TOKEN_FILE`             | `api_token` (file)       |
+    /// | `TMDB_TOKEN`                  | `api_token`              |
+    /// | `TMDB_SESSION_ID`             | `session_id`             |
+    /// | `TMDB_BASE_URL`               | `base_url`               |
+    /// | `TMDB_IMAGE_BASE_URL`         | `image_base_url`         |
+    /// | `TMDB_IMAGE_SIZE`             | `image_size`             |
+    /// | `TMDB_LANGUAGE`               | `language`               |
+    /// | `TMDB_REGION`                 | `region`                 |
+    /// | `TMDB_TIMEOUT_SECS`           | `timeout`                |
+    /// | `TMDB_USER_AGENT`             | `user_agent`             |
+    /// | `TMDB_MAX_IMAGE_BYTES`        | `max_image_bytes`        |
+    /// | `TMDB_MAX_BIO_CHARS`          | `max_biography_chars`    |
+    /// | `TMDB_RATE_LIMIT`             | `rate_limit`             |
+    /// | `TMDB_WARMUP`                 | `warmup`                 |
+    /// | `TMDB_CACHE_TTL_SECS`         | `cache_ttl`              |
+    /// | `TMDB_RETRY_BUDGET`           | `retry_budget`           |
+    /// | `TMDB_BREAKER_THRESHOLD`      | `breaker_threshold`      |
+    /// | `TMDB_BREAKER_COOLDOWN_SECS`  | `breaker_cooldown`       |
+    /// | `TMDB_STARTUP_JITTER_MS`      | `startup_jitter`         |
+    /// | `TMDB_EXPERIMENTAL_TOOLS`     | `experimental_tools`     |
+    /// | `TMDB_ALLOW_RAW`              | `allow_raw`              |
+    /// | `TMDB_SAFE_SEARCH`            | `safe_search`            |
+    /// | `TMDB_SNAPSHOT_DIR`           | `snapshot_dir`           |
+    /// | `TMDB_MAX_OUTPUT_TOKENS`      | `max_output_tokens`      |
+    /// | `TMDB_MISSING_FIELDS`         | `missing_fields`         |
+    /// | `TMDB_MAX_PAGES`              | `max_pages`              |
+    /// | `TMDB_POOL_MAX_IDLE`          | `pool_max_idle_per_host` |
+    /// | `TMDB_POOL_IDLE_TIMEOUT_SECS` | `pool_idle_timeout`      |
+    ///
+    /// When `TMDB_TOKEN_FILE` is set, the token is read from that file (surrounding whitespace
+    /// is trimmed) in preference to `TMDB_TOKEN`, so it never has to live in the environment.
+    ///
+    /// When `TMDB_REGION` is not set, the region is taken from `TMDB_LANGUAGE` (`US` for `en-US`).
+    ///
+    /// Never fails: a missing token, an unreadable or empty `TMDB_TOKEN_FILE` and values that
+    /// can't be parsed are reported by `validate` instead.
+    pub fn from_env() -> Self {
+        Self::from_vars(&|name| env::var(name).ok())
+    }
+
+    /// Builds a configuration like `from_env`, looking variables up with `lookup`.
+    fn from_vars(lookup: &dyn Fn(&str) -> Option<String>) -> Self {
+        let mut vars = EnvVars {
+            lookup,
+            invalid: Vec::new(),
+        };
+        // start from the defaults, so both constructors agree on them
+        let (api_token, token_problem) = match api_token_from_env(&vars) {
+            Ok(api_token) => (api_token, None),
+            Err(problem) => (String::new(), Some(problem)),
+        };
+        let mut config = Self::with_defaults(api_token, DEFAULT_BASE_URL.to_string());
+        config.token_problem = token_problem;
+        config.session_id = vars.opt("TMDB_SESSION_ID");
+        config.base_url = vars.or("TMDB_BASE_URL", config.base_url);
+        config.image_base_url = vars.or("TMDB_IMAGE_BASE_URL", config.image_base_url);
+        config.image_size = vars.or("TMDB_IMAGE_SIZE", config.image_size);
+        config.language = vars.or("TMDB_LANGUAGE", config.language);
+        config.region = vars
+            .get("TMDB_REGION")
+            .map(|region| region.trim().to_string())
+            .or_else(|| region_of(&config.language));
+        config.timeout =
+            Duration::from_secs(vars.or("TMDB_TIMEOUT_SECS", config.timeout.as_secs()));
+        config.user_agent = vars.or("TMDB_USER_AGENT", config.user_agent);
+        config.max_image_bytes = vars.or("TMDB_MAX_IMAGE_BYTES", config.max_image_bytes);
+        config.max_biography_chars = vars.or("TMDB_MAX_BIO_CHARS", config.max_biography_chars);
+        config.rate_limit = vars.or("TMDB_RATE_LIMIT", config.rate_limit);
+        config.warmup = vars.flag("TMDB_WARMUP", config.warmup);
+        config.cache_ttl =
+            Duration::from_secs(vars.or("TMDB_CACHE_TTL_SECS", config.cache_ttl.as_secs()));
+        config.retry_budget = vars.or("TMDB_RETRY_BUDGET", config.retry_budget);
+        config.breaker_threshold = vars.or("TMDB_BREAKER_THRESHOLD", config.breaker_threshold);
+        config.breaker_cooldown = Duration::from_secs(vars.or(
+            "TMDB_BREAKER_COOLDOWN_SECS",
+            config.breaker_cooldown.as_secs(),
+        ));
+        config.startup_jitter = Duration::from_millis(vars.or(
+            "TMDB_STARTUP_JITTER_MS",
+            config.startup_jitter.as_millis() as u64,
+        ));
+        config.experimental_tools = vars.flag("TMDB_EXPERIMENTAL_TOOLS", config.experimental_tools);
+        config.allow_raw = vars.flag("TMDB_ALLOW_RAW", config.allow_raw);
+        config.safe_search = vars.flag("TMDB_SAFE_SEARCH", config.safe_search);
+        config.snapshot_dir = vars.or("TMDB_SNAPSHOT_DIR", default_snapshot_dir(lookup));
+        config.max_output_tokens = vars.opt("TMDB_MAX_OUTPUT_TOKENS");
+        config.max_pages = vars.or("TMDB_MAX_PAGES", config.max_pages);
+        config.missing_fields = vars.opt("TMDB_MISSING_FIELDS");
+        config.pool_max_idle_per_host = vars.opt("TMDB_POOL_MAX_IDLE");
+        config.pool_idle_timeout = vars
+            .opt("TMDB_POOL_IDLE_TIMEOUT_SECS")
+            .map(Duration::from_secs);
+        config.invalid_env = vars.invalid;
+        config
+    }
+
+    /// Builds a configuration with every other setting at its default, without reading any
+    /// `TMDB_*` environment variables (see `TmdbClient::with_client`).
+    pub fn with_defaults(api_token: String, base_url: String) -> Self {
+        let language = DEFAULT_LANGUAGE.to_string();
+        let region = region_of(&language);
+        Self {
+            api_token,
+            session_id: None,
+            base_url,
+            image_base_url: DEFAULT_IMAGE_BASE_URL.to_string(),
+            image_size: DEFAULT_IMAGE_SIZE.to_string(),
+            language,
+            region,
+            timeout: DEFAULT_TIMEOUT,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            max_image_bytes: DEFAULT_MAX_IMAGE_BYTES,
+            max_biography_chars: DEFAULT_MAX_BIOGRAPHY_CHARS,
+            rate_limit: DEFAULT_RATE_LIMIT,
+            warmup: false,
+            cache_ttl: DEFAULT_CACHE_TTL,
+            retry_budget: DEFAULT_RETRY_BUDGET,
+            breaker_threshold: DEFAULT_BREAKER_THRESHOLD,
+            breaker_cooldown: DEFAULT_BREAKER_COOLDOWN,
+            startup_jitter: DEFAULT_STARTUP_JITTER,
+            experimental_tools: false,
+            allow_raw: false,
+            safe_search: true,
+            snapshot_dir: default_snapshot_dir(&|name| env::var(name).ok()),
+            max_output_tokens: None,
+            max_pages: DEFAULT_MAX_PAGES,
+            missing_fields: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            invalid_env: Vec::new(),
+            token_problem: None,
+        }
+    }
+
+    /// Picks a random delay between zero and `startup_jitter` to wait before the first request,
+    /// so a fleet of servers started at the same time doesn't hit TMDB all at once.
+    pub fn startup_delay(&self) -> Duration {
+        let max_millis = self.startup_jitter.as_millis() as u64;
+        if max_millis == 0 {
+            return Duration::ZERO;
+        }
+        // randomly seeded hasher: random enough for jitter without another dependency
+        let random = RandomState::new().hash_one(std::process::id());
+        Duration::from_millis(random % (max_millis + 1))
+    }
+
+    /// Summarizes the effective settings as one line of `key=value` pairs for the startup log,
+    /// so operators can confirm what took effect. The API token is always redacted.
+    pub fn summary(&self) -> String {
+        let optional = |value: Option<String>| value.unwrap_or_else(|| "default".to_string());
+        [
+            ("token", "<redacted>".to_string()),
+            (
+                "session",
+                if self.session_id.is_some() {
+                    "<redacted>"
+                } else {
+                    "none"
+                }
+                .to_string(),
+            ),
+            ("base_url", self.base_url.clone()),
+            ("image_size", self.image_size.clone()),
+            ("language", self.language.clone()),
+            (
+                "region",
+                self.region.as_deref().unwrap_or("none").to_string(),
+            ),
+            ("timeout", format!("{:?}", self.timeout)),
+            ("cache_ttl", format!("{:?}", self.cache_ttl)),
+            ("rate_limit", self.rate_limit.to_string()),
+            ("retry_budget", self.retry_budget.to_string()),
+            ("breaker_threshold", self.breaker_threshold.to_string()),
+            ("breaker_cooldown", format!("{:?}", self.breaker_cooldown)),
+            ("max_image_bytes", self.max_image_bytes.to_string()),
+            ("warmup", self.warmup.to_string()),
+            ("experimental_tools", self.experimental_tools.to_string()),
+            ("allow_raw", self.allow_raw.to_string()),
+            ("safe_search", self.safe_search.to_string()),
+            ("snapshot_dir", self.snapshot_dir.display().to_string()),
+            ("max_pages", self.max_pages.to_string()),
+            (
+                "missing_fields",
+                optional(
+                    self.missing_fields
+                        .map(|missing| missing.as_str().to_string()),
+                ),
+            ),
+            (
+                "max_output_tokens",
+                self.max_output_tokens
+                    .map_or_else(|| "none".to_string(), |max| max.to_string()),
+            ),
+            (
+                "pool_max_idle",
+                optional(self.pool_max_idle_per_host.map(|max| max.to_string())),
+            ),
+            (
+                "pool_idle_timeout",
+                optional(self.pool_idle_timeout.map(|timeout| format!("{timeout:?}"))),
+            ),
+        ]
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// How a tool shows fields TMDB has no value for: as `TMDB_MISSING_FIELDS` says when it is
+    /// set, otherwise as the tool always has (`default`), so output doesn't change unasked.
+    pub fn missing_fields_or(&self, default: MissingFields) -> MissingFields {
+        self.missing_fields.unwrap_or(default)
+    }
+
+    /// Checks every setting and reports all problems at once, so a misconfigured server
+    /// fails at startup with one clear message instead of on the first request.
+    ///
+    /// Environment variables that could not be parsed are reported too, even though
+    /// `from_env` fell back to their defaults.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems: Vec<String> = self
+            .invalid_env
+            .iter()
+            .map(|name| format!("{name} has an invalid value"))
+            .collect();
+
+        if let Some(problem) = &self.token_problem {
+            problems.push(problem.clone());
+        } else if self.api_token.trim().is_empty() {
+            problems.push("the API token is empty".to_string());
+        } else if HeaderValue::from_str(&format!("Bearer {}", self.api_token)).is_err() {
+            problems.push("the API token contains characters not allowed in a header".to_string());
+        }
+        if self
+            .session_id
+            .as_deref()
+            .is_some_and(|session_id| session_id.is_empty())
+        {
+            problems.push("TMDB_SESSION_ID is set but empty".to_string());
+        }
+        for (name, url) in [
+            ("TMDB_BASE_URL", &self.base_url),
+            ("TMDB_IMAGE_BASE_URL", &self.image_base_url),
+        ] {
+            if !Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+                problems.push(format!("{name} \"{url}\" is not an http(s) URL"));
+            }
+        }
+        if !is_valid_image_size(&self.image_size) {
+            problems.push(format!(
+                "TMDB_IMAGE_SIZE \"{}\" is not an image size like w92, h632 or original",
+                self.image_size
+            ));
+        }
+        if !is_valid_language(&self.language) {
+            problems.push(format!(
+                "TMDB_LANGUAGE \"{}\" is not a language code like en or en-US",
+                self.language
+            ));
+        }
+        if let Some(region) = &self.region
+            && !is_valid_region(region)
+        {
+            problems.push(format!(
+                "TMDB_REGION \"{region}\" is not a two-letter country code like US or GB"
+            ));
+        }
+        if self.timeout.is_zero() {
+            problems.push("TMDB_TIMEOUT_SECS must be greater than 0".to_string());
+        }
+        if HeaderValue::from_str(&self.user_agent).is_err() {
+            problems.push(format!(
+                "TMDB_USER_AGENT \"{}\" is not a valid header value",
+                self.user_agent
+            ));
+        }
+        if self.max_image_bytes == 0 {
+            problems.push("TMDB_MAX_IMAGE_BYTES must be greater than 0".to_string());
+        }
+        if self.max_biography_chars == 0 {
+            problems.push("TMDB_MAX_BIO_CHARS must be greater than 0".to_string());
+        }
+        if self.max_output_tokens == Some(0) {
+            problems.push("TMDB_MAX_OUTPUT_TOKENS must be greater than 0".to_string());
+        }
+        if self.max_pages == 0 {
+            problems.push("TMDB_MAX_PAGES must be greater than 0".to_string());
+        }
+        if self.snapshot_dir.as_os_str().is_empty() {
+            problems.push("TMDB_SNAPSHOT_DIR is set but empty".to_string());
+        }
+        if !self.rate_limit.is_finite() || self.rate_limit < 0.0 {
+            problems.push("TMDB_RATE_LIMIT must be 0 (disabled) or a positive number".to_string());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError { problems })
+        }
+    }
+}
+
+/// How rendered output shows an optional field TMDB has no value for, such as an unknown
+/// birthday (`TMDB_MISSING_FIELDS`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissingFields {
+    /// Leave the field's line out (`omit`)
+    Omit,
+    /// Show the field as `Unknown` (`label`)
+    Label,
+}
+
+impl MissingFields {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MissingFields::Omit => "omit",
+            MissingFields::Label => "label",
+        }
+    }
+
+    /// Renders a `Label: value` line, or for a missing (None or blank) value, `Label: Unknown`
+    /// or nothing depending on the setting.
+    pub fn field(self, label: &str, value: Option<&str>) -> Option<String> {
+        match value.map(str::trim).filter(|value| !value.is_empty()) {
+            Some(value) => Some(format!("{label}: {value}")),
+            None if self == MissingFields::Label => Some(format!("{label}: Unknown")),
+            None => None,
+        }
+    }
+}
+
+impl FromStr for MissingFields {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "omit" => Ok(MissingFields::Omit),
+            "label" => Ok(MissingFields::Label),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Every problem found by `TmdbConfig::validate`.
+#[derive(Debug)]
+pub struct ConfigError {
+    pub problems: Vec<String>,
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid TMDB configuration:")?;
+        for problem in &self.problems {
+            write!(f, "\n  - {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Implements Debug manually so the API token never ends up in logs.
+impl Debug for TmdbConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TmdbConfig")
+            .field("api_token", &"<redacted>")
+            .field(
+                "session_id",
+                &self.session_id.as_ref().map(|_| "<redacted>"),
+            )
+            .field("base_url", &self.base_url)
+            .field("image_base_url", &self.image_base_url)
+            .field("image_size", &self.image_size)
+            .field("language", &self.language)
+            .field("region", &self.region)
+            .field("timeout", &self.timeout)
+            .field("user_agent", &self.user_agent)
+            .field("max_image_bytes", &self.max_image_bytes)
+            .field("max_biography_chars", &self.max_biography_chars)
+            .field("rate_limit", &self.rate_limit)
+            .field("warmup", &self.warmup)
+            .field("cache_ttl", &self.cache_ttl)
+            .field("retry_budget", &self.retry_budget)
+            .field("breaker_threshold", &self.breaker_threshold)
+            .field("breaker_cooldown", &self.breaker_cooldown)
+            .field("startup_jitter", &self.startup_jitter)
+            .field("experimental_tools", &self.experimental_tools)
+            .field("allow_raw", &self.allow_raw)
+            .field("safe_search", &self.safe_search)
+            .field("snapshot_dir", &self.snapshot_dir)
+            .field("max_output_tokens", &self.max_output_tokens)
+            .field("max_pages", &self.max_pages)
+            .field("missing_fields", &self.missing_fields)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .finish()
+    }
+}
+
+/// Reads the API token from the file named by `TMDB_TOKEN_FILE`, or from `TMDB_TOKEN`.
+fn api_token_from_env(vars: &EnvVars) -> Result<String, String> {
+    match vars.get("TMDB_TOKEN_FILE") {
+        Some(path) => {
+            let token = fs::read_to_string(&path).map_err(|err| {
+                format!("TMDB_TOKEN_FILE is set but \"{path}\" could not be read: {err}")
+            })?;
+            let token = token.trim();
+            if token.is_empty() {
+                return Err(format!("TMDB_TOKEN_FILE \"{path}\" is empty"));
+            }
+            Ok(token.to_string())
+        }
+        None => vars
+            .get("TMDB_TOKEN")
+            .ok_or_else(|| "TMDB_TOKEN or TMDB_TOKEN_FILE must be set".to_string()),
+    }
+}
+
+/// Environment variables as seen by `TmdbConfig::from_vars`, with the names of those that
+/// are set but could not be parsed.
+struct EnvVars<'a> {
+    lookup: &'a dyn Fn(&str) -> Option<String>,
+    invalid: Vec<String>,
+}
+
+impl EnvVars<'_> {
+    /// Returns a variable's value, or None if it is unset.
+    fn get(&self, name: &str) -> Option<String> {
+        (self.lookup)(name)
+    }
+
+    /// Reads and parses a variable, returning `default` if it is unset or invalid.
+    /// The names of variables that are set but fail to parse are added to `invalid`.
+    fn or<T: FromStr>(&mut self, name: &str, default: T) -> T {
+        let Some(value) = self.get(name) else {
+            return default;
+        };
+        value.trim().parse().unwrap_or_else(|_| {
+            self.invalid.push(name.to_string());
+            default
+        })
+    }
+
+    /// Reads and parses an optional variable, returning None if it is unset or invalid.
+    /// The names of variables that are set but fail to parse are added to `invalid`.
+    fn opt<T: FromStr>(&mut self, name: &str) -> Option<T> {
+        let value = self.get(name)?;
+        let parsed = value.trim().parse().ok();
+        if parsed.is_none() {
+            self.invalid.push(name.to_string());
+        }
+        parsed
+    }
+
+    /// Returns true if a variable is set to `1`, `true` or `yes`, false if it is set to `0`,
+    /// `false` or `no`, and `default` if it is unset. Any other value is added to `invalid`
+    /// and `default` is used.
+    fn flag(&mut self, name: &str, default: bool) -> bool {
+        let Some(value) = self.get(name) else {
+            return default;
+        };
+        match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" => true,
+            "0" | "false" | "no" => false,
+            _ => {
+                self.invalid.push(name.to_string());
+                default
+            }
+        }
+    }
+}
+
+/// Returns true for TMDB image sizes such as `w92`, `h632` or `original`.
+fn is_valid_image_size(size: &str) -> bool {
+    if size == "original" {
+        return true;
+    }
+    size.strip_prefix('w')
+        .or_else(|| size.strip_prefix('h'))
+        .is_some_and(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Returns true for ISO 639-1 language codes, optionally with an ISO 3166-1 region (`en`, `pt-BR`).
+fn is_valid_language(language: &str) -> bool {
+    let (code, region) = match language.split_once('-') {
+        Some((code, region)) => (code, Some(region)),
+        None => (language, None),
+    };
+    code.len() == 2
+        && code.chars().all(|c| c.is_ascii_lowercase())
+        && region.is_none_or(|region| {
+            region.len() == 2 && region.chars().all(|c| c.is_ascii_uppercase())
+        })
+}
+
+/// Where `filmography_diff` keeps its snapshots unless `TMDB_SNAPSHOT_DIR` is set: the user's
+/// state directory (`$XDG_STATE_HOME`, `~/.local/state`, or `%LOCALAPPDATA%` on Windows), so
+/// other users can't guess the path and create it first. The shared temp directory is only
+/// used when none of those is set; `filmography_diff` refuses it if another user owns it.
+fn default_snapshot_dir(lookup: &dyn Fn(&str) -> Option<String>) -> PathBuf {
+    let absolute = |name: &str| {
+        lookup(name)
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+    };
+    let state_dir = absolute("XDG_STATE_HOME")
+        .or_else(|| absolute("HOME").map(|home| home.join(".local").join("state")))
+        .or_else(|| absolute("LOCALAPPDATA"));
+    match state_dir {
+        Some(state_dir) => state_dir.join(env!("CARGO_PKG_NAME")).join("snapshots"),
+        None => env::temp_dir().join(env!("CARGO_PKG_NAME")),
+    }
+}
+
+/// Region part of a language code (`US` for `en-US`), or None for a bare language like `en`.
+fn region_of(language: &str) -> Option<String> {
+    language
+        .split_once('-')
+        .map(|(_, region)| region.to_string())
+}
+
+/// Returns true for upper-case ISO 3166-1 alpha-2 region codes such as `US` or `GB`.
+fn is_valid_region(region: &str) -> bool {
+    region.len() == 2 && region.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// Normalizes a region code given by a client (`us` becomes `US`), or returns None if it is
+/// not a two-letter ISO 3166-1 code.
+pub fn parse_region(region: &str) -> Option<String> {
+    let region = region.trim().to_ascii_uppercase();
+    is_valid_region(&region).then_some(region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a configuration from the given variables alone.
+    fn from_vars(vars: &[(&str, &str)]) -> TmdbConfig {
+        TmdbConfig::from_vars(&|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn from_env_defaults_match_with_defaults() {
+        let mut config = from_vars(&[("TMDB_TOKEN", "token")]);
+        let defaults = TmdbConfig::with_defaults("token".to_string(), DEFAULT_BASE_URL.to_string());
+        // with_defaults finds the state directory through the real HOME, which from_vars lacks
+        config.snapshot_dir = defaults.snapshot_dir.clone();
+
+        assert_eq!(format!("{config:?}"), format!("{defaults:?}"));
+        assert_eq!(config.api_token, "token");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn snapshots_default_to_the_users_state_directory() {
+        let config = from_vars(&[("TMDB_TOKEN", "token"), ("HOME", "/home/fan")]);
+        assert_eq!(
+            config.snapshot_dir,
+            PathBuf::from("/home/fan/.local/state/techshare-mcp/snapshots")
+        );
+
+        let config = from_vars(&[
+            ("TMDB_TOKEN", "token"),
+            ("HOME", "/home/fan"),
+            ("XDG_STATE_HOME", "/var/state/fan"),
+        ]);
+        assert_eq!(
+            config.snapshot_dir,
+            PathBuf::from("/var/state/fan/techshare-mcp/snapshots")
+        );
+
+        // a relative XDG_STATE_HOME is invalid and ignored, as the spec requires
+        let config = from_vars(&[
+            ("TMDB_TOKEN", "token"),
+            ("HOME", "/home/fan"),
+            ("XDG_STATE_HOME", "state"),
+        ]);
+        assert!(config.snapshot_dir.starts_with("/home/fan/.local/state"));
+
+        let config = from_vars(&[
+            ("TMDB_TOKEN", "token"),
+            ("TMDB_SNAPSHOT_DIR", "/srv/snapshots"),
+        ]);
+        assert_eq!(config.snapshot_dir, PathBuf::from("/srv/snapshots"));
+    }
+
+    #[test]
+    fn startup_delay_is_bounded_by_the_jitter() {
+        let config = from_vars(&[("TMDB_TOKEN", "token")]);
+        assert_eq!(config.startup_delay(), Duration::ZERO);
+
+        let config = from_vars(&[("TMDB_TOKEN", "token"), ("TMDB_STARTUP_JITTER_MS", "50")]);
+        assert_eq!(config.startup_jitter, Duration::from_millis(50));
+        for _ in 0..100 {
+            assert!(config.startup_delay() <= Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn pool_settings_default_to_reqwest_and_can_be_set() {
+        let config = from_vars(&[("TMDB_TOKEN", "token")]);
+        assert_eq!(config.pool_max_idle_per_host, None);
+        assert_eq!(config.pool_idle_timeout, None);
+
+        let config = from_vars(&[
+            ("TMDB_TOKEN", "token"),
+            ("TMDB_POOL_MAX_IDLE", "16"),
+            ("TMDB_POOL_IDLE_TIMEOUT_SECS", "60"),
+        ]);
+        assert_eq!(config.pool_max_idle_per_host, Some(16));
+        assert_eq!(config.pool_idle_timeout, Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn variables_override_the_defaults() {
+        let config = from_vars(&[
+            ("TMDB_TOKEN", "token"),
+            ("TMDB_LANGUAGE", "pt-BR"),
+            ("TMDB_TIMEOUT_SECS", "3"),
+            ("TMDB_SAFE_SEARCH", "no"),
+        ]);
+
+        assert_eq!(config.language, "pt-BR");
+        assert_eq!(config.region.as_deref(), Some("BR"));
+        assert_eq!(config.timeout, Duration::from_secs(3));
+        assert!(!config.safe_search);
+    }
+
+    #[test]
+    fn the_region_variable_overrides_the_language_region() {
+        let config = from_vars(&[
+            ("TMDB_TOKEN", "token"),
+            ("TMDB_LANGUAGE", "en-US"),
+            ("TMDB_REGION", " GB "),
+        ]);
+        assert_eq!(config.region.as_deref(), Some("GB"));
+
+        let config = from_vars(&[("TMDB_TOKEN", "token"), ("TMDB_LANGUAGE", "fr")]);
+        assert_eq!(config.region, None);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn malformed_regions_are_rejected() {
+        let config = from_vars(&[("TMDB_TOKEN", "token"), ("TMDB_REGION", "usa")]);
+        let err = config.validate().unwrap_err();
+        assert_eq!(
+            err.problems,
+            ["TMDB_REGION \"usa\" is not a two-letter country code like US or GB"]
+        );
+
+        assert_eq!(parse_region(" gb"), Some("GB".to_string()));
+        assert_eq!(parse_region("U5"), None);
+        assert_eq!(parse_region(""), None);
+    }
+
+    #[test]
+    fn unparsable_variables_are_reported() {
+        let config = from_vars(&[("TMDB_TOKEN", "token"), ("TMDB_TIMEOUT_SECS", "soon")]);
+
+        assert_eq!(config.timeout, DEFAULT_TIMEOUT);
+        let err = config.validate().unwrap_err();
+        assert_eq!(err.problems, ["TMDB_TIMEOUT_SECS has an invalid value"]);
+    }
+
+    #[test]
+    fn validate_reports_every_problem_at_once() {
+        let mut config =
+            TmdbConfig::with_defaults(" ".to_string(), "ftp://example.com".to_string());
+        config.language = "english".to_string();
+        config.timeout = Duration::ZERO;
+
+        let err = config.validate().unwrap_err();
+
+        assert_eq!(
+            err.problems,
+            [
+                "the API token is empty",
+                "TMDB_BASE_URL \"ftp://example.com\" is not an http(s) URL",
+                "TMDB_LANGUAGE \"english\" is not a language code like en or en-US",
+                "TMDB_TIMEOUT_SECS must be greater than 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_output_redacts_the_token() {
+        let config =
+            TmdbConfig::with_defaults("secret-token".to_string(), DEFAULT_BASE_URL.to_string());
+
+        let debug = format!("{config:?}");
+
+        assert!(!debug.contains("secret-token"));
+        assert!(debug.contains("api_token: \"<redacted>\""));
+    }
+
+    #[test]
+    fn the_summary_redacts_the_token_and_session() {
+        let config = from_vars(&[
+            ("TMDB_TOKEN", "secret-token"),
+            ("TMDB_SESSION_ID", "secret-session"),
+            ("TMDB_LANGUAGE", "en-GB"),
+            ("TMDB_TIMEOUT_SECS", "3"),
+        ]);
+
+        let summary = config.summary();
+
+        assert!(!summary.contains("secret-token"));
+        assert!(!summary.contains("secret-session"));
+        assert!(!summary.contains('\n'));
+        assert!(summary.starts_with("token=<redacted> session=<redacted> "));
+        assert!(summary.contains(" image_size=w92 language=en-GB region=GB timeout=3s "));
+        assert!(summary.contains(" cache_ttl="));
+    }
+
+    /// Writes `contents` to a file unique to this test run and returns its path.
+    fn token_file(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("{name}-{}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn the_token_file_is_trimmed_and_preferred_over_the_variable() {
+        let path = token_file("tmdb-token", "  file-token\n");
+
+        let config = from_vars(&[
+            ("TMDB_TOKEN_FILE", path.to_str().unwrap()),
+            ("TMDB_TOKEN", "env-token"),
+        ]);
+
+        assert_eq!(config.api_token, "file-token");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn token_problems_are_reported_instead_of_panicking() {
+        let config = from_vars(&[]);
+        assert_eq!(
+            config.validate().unwrap_err().problems,
+            ["TMDB_TOKEN or TMDB_TOKEN_FILE must be set"]
+        );
+
+        let path = token_file("tmdb-empty-token", " \n");
+        let config = from_vars(&[("TMDB_TOKEN_FILE", path.to_str().unwrap())]);
+        assert_eq!(
+            config.validate().unwrap_err().problems,
+            [format!("TMDB_TOKEN_FILE \"{}\" is empty", path.display())]
+        );
+        fs::remove_file(&path).unwrap();
+
+        let config = from_vars(&[("TMDB_TOKEN_FILE", path.to_str().unwrap())]);
+        let problems = config.validate().unwrap_err().problems;
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("TMDB_TOKEN_FILE is set but"));
+        assert!(problems[0].contains("could not be read"));
+    }
+
+    #[test]
+    fn missing_fields_defaults_to_each_tools_own_mode() {
+        let unset = from_vars(&[]);
+        assert_eq!(unset.missing_fields, None);
+        assert_eq!(
+            unset.missing_fields_or(MissingFields::Omit),
+            MissingFields::Omit
+        );
+        assert_eq!(
+            unset.missing_fields_or(MissingFields::Label),
+            MissingFields::Label
+        );
+
+        let omit = from_vars(&[("TMDB_MISSING_FIELDS", "OMIT")]);
+        assert_eq!(omit.missing_fields, Some(MissingFields::Omit));
+        assert_eq!(
+            omit.missing_fields_or(MissingFields::Label),
+            MissingFields::Omit
+        );
+    }
+
+    #[test]
+    fn unrecognized_flag_values_are_reported() {
+        let config = from_vars(&[("TMDB_TOKEN", "token"), ("TMDB_SAFE_SEARCH", "maybe")]);
+
+        assert!(config.safe_search);
+        assert_eq!(
+            config.validate().unwrap_err().problems,
+            ["TMDB_SAFE_SEARCH has an invalid value"]
+        );
+    }
+}
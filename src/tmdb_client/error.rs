@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Errors returned by `TmdbClient` operations.
+#[derive(Debug)]
+pub enum TmdbError {
+    /// The HTTP request failed or TMDB answered with an error status.
+    Http(reqwest::Error),
+    /// A downloaded image is larger than the configured limit.
+    ImageTooLarge { size: u64, limit: u64 },
+    /// An image download ended early: fewer bytes arrived than `Content-Length` announced.
+    IncompleteImage { expected: u64, received: u64 },
+    /// TMDB answered with a `"success": false` envelope instead of the requested data.
+    /// `status` is the HTTP status, `status_code` TMDB's own error code.
+    Api {
+        status: u16,
+        status_code: Option<i64>,
+        message: String,
+    },
+    /// The response body was not the JSON that was expected.
+    Decode(serde_json::Error),
+    /// The response was not JSON at all, e.g. an HTML error page from a proxy.
+    NonJson { status: u16 },
+    /// An account endpoint was called without `TMDB_SESSION_ID` configured.
+    NoSession,
+    /// TMDB kept answering `503 Service Unavailable`, so requests are paused for `retry_after`.
+    CircuitOpen { retry_after: Duration },
+}
+
+impl TmdbError {
+    /// Returns true if the server could not be reached or reported an outage (timeouts,
+    /// connection failures and 5xx responses), as opposed to rejecting this particular request.
+    pub fn is_unavailable(&self) -> bool {
+        match self {
+            TmdbError::Http(err) => {
+                err.is_connect()
+                    || err.is_timeout()
+                    || err.status().is_some_and(|status| status.is_server_error())
+            }
+            TmdbError::NonJson { status } | TmdbError::Api { status, .. } => *status >= 500,
+            TmdbError::CircuitOpen { .. } => true,
+            TmdbError::NoSession => false,
+            TmdbError::ImageTooLarge { .. }
+            | TmdbError::IncompleteImage { .. }
+            | TmdbError::Decode(_) => false,
+        }
+    }
+}
+
+impl Display for TmdbError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TmdbError::Http(err) => write!(f, "{err}"),
+            TmdbError::ImageTooLarge { size, limit } => write!(
+                f,
+                "Image is too large to return ({size} bytes, limit is {limit} bytes)"
+            ),
+            TmdbError::IncompleteImage { expected, received } => write!(
+                f,
+                "Image download was cut short ({received} of {expected} bytes received)"
+            ),
+            TmdbError::Api {
+                status_code: Some(status_code),
+                message,
+                ..
+            } => write!(f, "TMDB error {status_code}: {message}"),
+            TmdbError::Api {
+                status_code: None,
+                message,
+                ..
+            } => write!(f, "TMDB error: {message}"),
+            TmdbError::Decode(err) => write!(f, "Unexpected response from TMDB: {err}"),
+            TmdbError::NoSession => write!(
+                f,
+                "Account data needs a TMDB session; set TMDB_SESSION_ID to use it"
+            ),
+            TmdbError::CircuitOpen { retry_after } => write!(
+                f,
+                "TMDB is temporarily unavailable (maintenance?); try again in {}s",
+                retry_after.as_secs_f64().ceil()
+            ),
+            TmdbError::NonJson { status } => {
+                write!(
+                    f,
+                    "Unexpected non-JSON response from TMDB (status {status})"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TmdbError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TmdbError::Http(err) => Some(err),
+            TmdbError::Decode(err) => Some(err),
+            TmdbError::ImageTooLarge { .. }
+            | TmdbError::IncompleteImage { .. }
+            | TmdbError::Api { .. }
+            | TmdbError::NonJson { .. }
+            | TmdbError::CircuitOpen { .. }
+            | TmdbError::NoSession => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for TmdbError {
+    fn from(err: reqwest::Error) -> Self {
+        TmdbError::Http(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outages_are_told_apart_from_rejected_requests() {
+        assert!(TmdbError::NonJson { status: 502 }.is_unavailable());
+        assert!(
+            TmdbError::CircuitOpen {
+                retry_after: Duration::from_secs(5)
+            }
+            .is_unavailable()
+        );
+        assert!(!TmdbError::NonJson { status: 403 }.is_unavailable());
+        assert!(
+            !TmdbError::ImageTooLarge {
+                size: 64,
+                limit: 32
+            }
+            .is_unavailable()
+        );
+        assert!(!TmdbError::NoSession.is_unavailable());
+    }
+}
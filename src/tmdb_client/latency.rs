@@ -0,0 +1,142 @@
+use hdrhistogram::Histogram;
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// A summary is due after every this many recorded requests.
+const LOG_EVERY: u64 = 100;
+
+/// Highest latency tracked precisely, in microseconds. Slower requests are clamped.
+const MAX_TRACKED_MICROS: u64 = 60_000_000;
+
+/// Rolling histogram of TMDB request latencies.
+///
+/// Summaries are not logged here: the MCP handler sends them to clients that enabled logging
+/// (see `McpHandler::handle_set_level_request`).
+pub struct LatencyTracker {
+    state: Mutex<State>,
+}
+
+struct State {
+    histogram: Histogram<u64>,
+    /// Number of recorded requests when a summary was last handed out
+    reported: u64,
+}
+
+/// Latency percentiles computed from the recorded requests.
+#[derive(Debug, Clone, Copy)]
+pub struct LatencySummary {
+    pub count: u64,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl LatencyTracker {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State {
+                histogram: Histogram::new_with_bounds(1, MAX_TRACKED_MICROS, 3)
+                    .expect("latency histogram bounds are valid"),
+                reported: 0,
+            }),
+        }
+    }
+
+    /// Records one request latency.
+    pub fn record(&self, elapsed: Duration) {
+        let micros = (elapsed.as_micros() as u64).clamp(1, MAX_TRACKED_MICROS);
+        self.state
+            .lock()
+            .unwrap()
+            .histogram
+            .saturating_record(micros);
+    }
+
+    /// Computes the current percentiles, or `None` if nothing was recorded yet.
+    pub fn summary(&self) -> Option<LatencySummary> {
+        let state = self.state.lock().unwrap();
+        (!state.histogram.is_empty()).then(|| summarize(&state.histogram))
+    }
+
+    /// Returns the current percentiles once at least `LOG_EVERY` requests were recorded since
+    /// the last summary handed out, and `None` otherwise.
+    pub fn due_summary(&self) -> Option<LatencySummary> {
+        let mut state = self.state.lock().unwrap();
+        let count = state.histogram.len();
+        if count < state.reported + LOG_EVERY {
+            return None;
+        }
+        state.reported = count;
+        Some(summarize(&state.histogram))
+    }
+}
+
+fn summarize(histogram: &Histogram<u64>) -> LatencySummary {
+    LatencySummary {
+        count: histogram.len(),
+        p50: Duration::from_micros(histogram.value_at_quantile(0.50)),
+        p95: Duration::from_micros(histogram.value_at_quantile(0.95)),
+        p99: Duration::from_micros(histogram.value_at_quantile(0.99)),
+        max: Duration::from_micros(histogram.max()),
+    }
+}
+
+impl Display for LatencySummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "tmdb latency: requests={} p50={:?} p95={:?} p99={:?} max={:?}",
+            self.count, self.p50, self.p95, self.p99, self.max
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that `actual` is within the histogram's 0.1% precision of `expected`.
+    fn assert_close(actual: Duration, expected: Duration) {
+        let diff = actual.abs_diff(expected);
+        assert!(
+            diff <= expected / 1000,
+            "expected about {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn summary_reports_percentiles() {
+        let tracker = LatencyTracker::new();
+        assert!(tracker.summary().is_none());
+        for millis in 1..=100 {
+            tracker.record(Duration::from_millis(millis));
+        }
+
+        let summary = tracker.summary().unwrap();
+        assert_eq!(summary.count, 100);
+        assert_close(summary.p50, Duration::from_millis(50));
+        assert_close(summary.p95, Duration::from_millis(95));
+        assert_close(summary.p99, Duration::from_millis(99));
+        assert_close(summary.max, Duration::from_millis(100));
+        assert!(
+            summary
+                .to_string()
+                .starts_with("tmdb latency: requests=100 p50=")
+        );
+    }
+
+    #[test]
+    fn a_summary_is_due_every_hundred_requests() {
+        let tracker = LatencyTracker::new();
+        for _ in 1..LOG_EVERY {
+            tracker.record(Duration::from_millis(5));
+        }
+        assert!(tracker.due_summary().is_none());
+
+        tracker.record(Duration::from_millis(5));
+        assert_eq!(tracker.due_summary().unwrap().count, LOG_EVERY);
+        assert!(tracker.due_summary().is_none());
+    }
+}
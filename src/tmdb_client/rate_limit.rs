@@ -0,0 +1,82 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token-bucket rate limiter shared by all outbound TMDB requests.
+///
+/// The bucket holds up to one second's worth of tokens, so short bursts are allowed
+/// while the sustained rate stays at `requests_per_second`.
+pub struct RateLimiter {
+    requests_per_second: f64,
+    capacity: f64,
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: f64) -> Self {
+        let capacity = requests_per_second.max(1.0);
+        Self {
+            requests_per_second,
+            capacity,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Takes one token, waiting until one is available.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let now = Instant::now();
+                let refill =
+                    now.duration_since(bucket.last_refill).as_secs_f64() * self.requests_per_second;
+                bucket.tokens = (bucket.tokens + refill).min(self.capacity);
+                bucket.last_refill = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.requests_per_second)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn allows_a_burst_then_waits_for_tokens() {
+        let limiter = RateLimiter::new(20.0);
+
+        let started = Instant::now();
+        for _ in 0..20 {
+            limiter.acquire().await;
+        }
+        assert!(started.elapsed() < Duration::from_millis(25));
+
+        // the bucket is empty: the next token takes 1/20 s to refill
+        let started = Instant::now();
+        limiter.acquire().await;
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[tokio::test]
+    async fn a_rate_below_one_still_allows_one_request() {
+        let limiter = RateLimiter::new(0.5);
+
+        let started = Instant::now();
+        limiter.acquire().await;
+        assert!(started.elapsed() < Duration::from_millis(25));
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Time it takes an empty budget to refill completely.
+const REFILL_PERIOD: Duration = Duration::from_secs(60);
+
+/// Token bucket of retry permits shared by every request the client makes.
+///
+/// Each retry takes a permit; permits come back gradually (a full budget per
+/// `REFILL_PERIOD`). When TMDB is struggling and many requests fail at once, the budget
+/// runs out and further failures are returned immediately instead of multiplying the load
+/// with retries. Running out and getting permits back again are recorded as `BudgetChange`s,
+/// so they can be logged once rather than for every request that isn't retried.
+pub struct RetryBudget {
+    capacity: f64,
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    permits: f64,
+    last_refill: Instant,
+    /// Whether the last retry asked for was refused
+    exhausted: bool,
+    /// Changes not yet handed out by `take_changes`
+    changes: Vec<BudgetChange>,
+}
+
+/// A change in whether failed requests are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetChange {
+    /// A retry was refused: failed requests are returned without retrying
+    Exhausted,
+    /// A retry was allowed again after the budget ran out
+    Refilled,
+}
+
+impl Display for BudgetChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exhausted => write!(
+                f,
+                "retry budget exhausted: failed TMDB requests are no longer retried"
+            ),
+            Self::Refilled => write!(
+                f,
+                "retry budget refilled: failed TMDB requests are retried again"
+            ),
+        }
+    }
+}
+
+impl RetryBudget {
+    /// Creates a full budget of `capacity` retries. A capacity of 0 disables retries.
+    pub fn new(capacity: u32) -> Self {
+        let capacity = f64::from(capacity);
+        Self {
+            capacity,
+            bucket: Mutex::new(Bucket {
+                permits: capacity,
+                last_refill: Instant::now(),
+                exhausted: false,
+                changes: Vec::new(),
+            }),
+        }
+    }
+
+    /// Takes one retry permit, returning false without waiting if none is left.
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.last_refill).as_secs_f64()
+            / REFILL_PERIOD.as_secs_f64()
+            * self.capacity;
+        bucket.permits = (bucket.permits + refill).min(self.capacity);
+        bucket.last_refill = now;
+
+        let acquired = bucket.permits >= 1.0;
+        if acquired {
+            bucket.permits -= 1.0;
+        }
+        // a disabled budget never had retries to run out of
+        if self.capacity > 0.0 && bucket.exhausted == acquired {
+            bucket.exhausted = !acquired;
+            let change = if acquired {
+                BudgetChange::Refilled
+            } else {
+                BudgetChange::Exhausted
+            };
+            bucket.changes.push(change);
+        }
+        acquired
+    }
+
+    /// Returns the changes recorded since the last call, oldest first.
+    pub fn take_changes(&self) -> Vec<BudgetChange> {
+        std::mem::take(&mut self.bucket.lock().unwrap().changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_out_and_refilling_are_each_reported_once() {
+        let budget = RetryBudget::new(2);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(budget.take_changes().is_empty());
+
+        assert!(!budget.try_acquire());
+        assert!(!budget.try_acquire());
+        assert_eq!(budget.take_changes(), [BudgetChange::Exhausted]);
+        assert!(budget.take_changes().is_empty());
+
+        // as if a whole refill period had passed since the last request
+        {
+            let mut bucket = budget.bucket.lock().unwrap();
+            bucket.last_refill = Instant::now() - REFILL_PERIOD;
+        }
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert_eq!(budget.take_changes(), [BudgetChange::Refilled]);
+    }
+
+    #[test]
+    fn a_disabled_budget_refuses_retries_without_reporting_changes() {
+        let budget = RetryBudget::new(0);
+
+        assert!(!budget.try_acquire());
+        assert!(!budget.try_acquire());
+        assert!(budget.take_changes().is_empty());
+    }
+}
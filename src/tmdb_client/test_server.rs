@@ -0,0 +1,557 @@
+//! A minimal HTTP server standing in for TMDB in tests.
+//!
+//! Routes map a path (optionally with required query parameters, e.g.
+//! `/search/person?page=2`) to canned responses. Several responses registered for the same
+//! route are served in turn, the last one repeating, so a test can script a failure followed
+//! by a success. Unknown paths get TMDB's own `404` envelope.
+
+use super::{TmdbClient, TmdbConfig};
+use reqwest::Client;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A canned HTTP response.
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Duration,
+}
+
+impl MockResponse {
+    /// A `200 OK` JSON response.
+    pub fn json(body: Value) -> Self {
+        Self::status(200, body)
+    }
+
+    /// A JSON response with the given status.
+    pub fn status(status: u16, body: Value) -> Self {
+        Self::bytes(status, "application/json;charset=utf-8", body.to_string())
+    }
+
+    /// A response with any body and content type.
+    pub fn bytes(status: u16, content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type".to_string(), content_type.to_string())],
+            body: body.into(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Adds a header. A `Content-Length` given here replaces the real one, e.g. to
+    /// announce more bytes than are sent.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Waits this long before answering.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+/// A request the server received.
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    /// Path and query, e.g. `/person/31?language=en-US`
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl MockRequest {
+    pub fn path(&self) -> &str {
+        self.target.split('?').next().unwrap_or_default()
+    }
+
+    /// Value of a query parameter, still percent-encoded.
+    pub fn query(&self, name: &str) -> Option<&str> {
+        query_pairs(&self.target).find_map(|(key, value)| (key == name).then_some(value))
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct Route {
+    pattern: String,
+    responses: VecDeque<MockResponse>,
+}
+
+#[derive(Default)]
+struct State {
+    routes: Vec<Route>,
+    requests: Vec<MockRequest>,
+}
+
+/// A running mock TMDB server, stopped when dropped.
+pub struct MockTmdb {
+    base_url: String,
+    state: Arc<Mutex<State>>,
+    server: tokio::task::JoinHandle<()>,
+}
+
+impl MockTmdb {
+    /// Starts a server on a free local port.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+        let server = tokio::spawn({
+            let state = state.clone();
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(serve(stream, state.clone()));
+                }
+            }
+        });
+        Self {
+            base_url,
+            state,
+            server,
+        }
+    }
+
+    /// Answers requests matching `pattern` with `response`; see the module docs.
+    pub fn on(&self, pattern: &str, response: MockResponse) -> &Self {
+        let mut state = self.state.lock().unwrap();
+        match state
+            .routes
+            .iter_mut()
+            .find(|route| route.pattern == pattern)
+        {
+            Some(route) => route.responses.push_back(response),
+            None => state.routes.push(Route {
+                pattern: pattern.to_string(),
+                responses: VecDeque::from([response]),
+            }),
+        }
+        self
+    }
+
+    /// Answers requests matching `pattern` with `body` as `200 OK` JSON.
+    pub fn json(&self, pattern: &str, body: Value) -> &Self {
+        self.on(pattern, MockResponse::json(body))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// A client with default settings talking to this server, for images too.
+    pub fn client(&self) -> TmdbClient {
+        self.client_with(|_| {})
+    }
+
+    /// A client talking to this server, with settings changed by `configure`.
+    pub fn client_with(&self, configure: impl FnOnce(&mut TmdbConfig)) -> TmdbClient {
+        let client = TmdbClient::with_client(Client::new(), self.base_url.clone());
+        let mut config = client.config.clone();
+        config.image_base_url = self.base_url.clone();
+        configure(&mut config);
+        TmdbClient::from_parts(client.client, config)
+    }
+
+    /// Every request received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Number of requests received for `path` (ignoring the query).
+    pub fn hits(&self, path: &str) -> usize {
+        self.requests()
+            .iter()
+            .filter(|request| request.path() == path)
+            .count()
+    }
+}
+
+impl Drop for MockTmdb {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Splits the query of a request target into raw key/value pairs.
+fn query_pairs(target: &str) -> impl Iterator<Item = (&str, &str)> {
+    target
+        .split_once('?')
+        .map_or("", |(_, query)| query)
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
+/// Whether `target` has the pattern's path and every query parameter the pattern names.
+fn matches(pattern: &str, target: &str) -> bool {
+    let path = |value: &str| value.split('?').next().unwrap_or_default().to_string();
+    path(pattern) == path(target)
+        && query_pairs(pattern).all(|wanted| query_pairs(target).any(|pair| pair == wanted))
+}
+
+/// Picks the response for a request: the matching route naming the most query parameters
+/// wins, and its queue advances unless only the last response is left.
+fn respond(state: &Mutex<State>, request: MockRequest) -> MockResponse {
+    let mut state = state.lock().unwrap();
+    let target = request.target.clone();
+    state.requests.push(request);
+    let route = state
+        .routes
+        .iter_mut()
+        .filter(|route| matches(&route.pattern, &target))
+        .max_by_key(|route| query_pairs(&route.pattern).count());
+    match route {
+        Some(route) if route.responses.len() > 1 => route.responses.pop_front().unwrap(),
+        Some(route) => route.responses[0].clone(),
+        None => MockResponse::status(
+            404,
+            serde_json::json!({
+                "success": false,
+                "status_code": 34,
+                "status_message": "The resource you requested could not be found."
+            }),
+        ),
+    }
+}
+
+/// Reads one request from `stream`, answers it and closes the connection.
+async fn serve(mut stream: TcpStream, state: Arc<Mutex<State>>) {
+    let mut head = Vec::new();
+    let mut buffer = [0; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        match stream.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(read) => head.extend_from_slice(&buffer[..read]),
+        }
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let mut lines = head.split("\r\n");
+    let target = lines
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .unwrap_or("/")
+        .to_string();
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    let response = respond(&state, MockRequest { target, headers });
+    tokio::time::sleep(response.delay).await;
+
+    let mut reply = format!("HTTP/1.1 {} Mock\r\nConnection: close\r\n", response.status);
+    if !response
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+    {
+        reply.push_str(&format!("Content-Length: {}\r\n", response.body.len()));
+    }
+    for (name, value) in &response.headers {
+        reply.push_str(&format!("{name}: {value}\r\n"));
+    }
+    reply.push_str("\r\n");
+
+    let mut bytes = reply.into_bytes();
+    bytes.extend_from_slice(&response.body);
+    let _ = stream.write_all(&bytes).await;
+    let _ = stream.shutdown().await;
+}
+
+/// JSON fixtures shaped like TMDB's responses, with every required field filled in.
+/// Tests adjust individual fields with `value["field"] = json!(...)`.
+pub mod fixtures {
+    use serde_json::{Value, json};
+
+    /// A person's full record, from `/person/{id}` (`PersonDetails`).
+    pub fn person(id: i64, name: &str) -> Value {
+        json!({
+            "adult": false,
+            "also_known_as": [],
+            "biography": format!("{name} is an actor."),
+            "birthday": "1956-07-09",
+            "deathday": null,
+            "gender": 2,
+            "homepage": null,
+            "id": id,
+            "imdb_id": format!("nm{id:07}"),
+            "known_for_department": "Acting",
+            "name": name,
+            "place_of_birth": "Concord, California, USA",
+            "popularity": 10.0,
+            "profile_path": null
+        })
+    }
+
+    /// A person as listed by search and popular endpoints (`PersonSummary`).
+    pub fn person_summary(id: i64, name: &str) -> Value {
+        json!({
+            "id": id,
+            "adult": false,
+            "gender": 2,
+            "name": name,
+            "original_name": name,
+            "known_for_department": "Acting",
+            "popularity": 10.0,
+            "profile_path": null,
+            "known_for": []
+        })
+    }
+
+    /// One page of a list endpoint (`Paginated`).
+    pub fn page(page: u32, total_pages: u32, total_results: u32, results: Vec<Value>) -> Value {
+        json!({
+            "page": page,
+            "total_pages": total_pages,
+            "total_results": total_results,
+            "results": results
+        })
+    }
+
+    /// The only page of a list endpoint.
+    pub fn single_page(results: Vec<Value>) -> Value {
+        let total = results.len() as u32;
+        page(1, 1, total, results)
+    }
+
+    /// The `/genre/movie/list` response.
+    pub fn genres() -> Value {
+        json!({ "genres": [
+            { "id": 28, "name": "Action" },
+            { "id": 35, "name": "Comedy" },
+            { "id": 18, "name": "Drama" },
+            { "id": 878, "name": "Science Fiction" }
+        ] })
+    }
+
+    /// The `/configuration` response.
+    pub fn configuration() -> Value {
+        json!({
+            "images": {
+                "base_url": "http://image.tmdb.org/t/p/",
+                "secure_base_url": "https://image.tmdb.org/t/p/",
+                "backdrop_sizes": ["w300", "original"],
+                "logo_sizes": ["w92", "original"],
+                "poster_sizes": ["w92", "original"],
+                "profile_sizes": ["w45", "original"],
+                "still_sizes": ["w92", "original"]
+            },
+            "change_keys": ["biography"]
+        })
+    }
+
+    /// The session user's `/account` response (`AccountDetails`).
+    pub fn account(id: i64, username: &str) -> Value {
+        json!({
+            "avatar": { "gravatar": { "hash": null }, "tmdb": { "avatar_path": null } },
+            "id": id,
+            "iso_639_1": "en",
+            "iso_3166_1": "US",
+            "name": "",
+            "include_adult": false,
+            "username": username
+        })
+    }
+
+    /// A movie as listed by search, discover and list endpoints (`MovieDetail`).
+    pub fn movie(id: i64, title: &str, release_date: &str) -> Value {
+        json!({
+            "adult": false,
+            "backdrop_path": null,
+            "genre_ids": [],
+            "id": id,
+            "original_language": "en",
+            "original_title": title,
+            "overview": "",
+            "popularity": 10.0,
+            "poster_path": null,
+            "release_date": release_date,
+            "title": title,
+            "video": false,
+            "vote_average": 7.0,
+            "vote_count": 1000
+        })
+    }
+
+    /// A movie a person acted in, from `/person/{id}/movie_credits` (`MovieCredit`).
+    pub fn credit(id: i64, title: &str, release_date: &str, character: &str) -> Value {
+        let mut credit = movie(id, title, release_date);
+        credit["character"] = json!(character);
+        credit["credit_id"] = json!(format!("credit-{id}-{character}"));
+        credit["order"] = json!(0);
+        credit
+    }
+
+    /// A person's `/person/{id}/movie_credits` response listing `cast`.
+    pub fn movie_credits(cast: Vec<Value>) -> Value {
+        json!({ "id": 1, "cast": cast, "crew": [] })
+    }
+
+    /// A movie's full record, from `/movie/{id}` (`MovieFull`).
+    pub fn movie_full(id: i64, title: &str, release_date: &str) -> Value {
+        json!({
+            "adult": false,
+            "id": id,
+            "imdb_id": format!("tt{id:07}"),
+            "origin_country": ["US"],
+            "original_language": "en",
+            "title": title,
+            "original_title": title,
+            "tagline": null,
+            "overview": "",
+            "release_date": release_date,
+            "runtime": 120,
+            "genres": [],
+            "budget": 0,
+            "revenue": 0,
+            "popularity": 10.0,
+            "vote_average": 7.0,
+            "vote_count": 1000,
+            "poster_path": null,
+            "backdrop_path": null,
+            "homepage": null,
+            "status": "Released",
+            "spoken_languages": [],
+            "production_countries": [],
+            "belongs_to_collection": null,
+            "production_companies": [],
+            "video": false
+        })
+    }
+
+    /// A cast member of a movie (`CastMember`).
+    pub fn cast_member(id: i64, name: &str, character: &str, order: u32) -> Value {
+        json!({
+            "adult": false,
+            "gender": 2,
+            "id": id,
+            "known_for_department": "Acting",
+            "name": name,
+            "original_name": name,
+            "popularity": 10.0,
+            "profile_path": null,
+            "cast_id": order + 1,
+            "character": character,
+            "credit_id": format!("cast-{id}-{order}"),
+            "order": order
+        })
+    }
+
+    /// A movie's credits, from `/movie/{id}/credits` (`MovieCredits`).
+    pub fn credits(movie_id: i64, cast: Vec<Value>, crew: Vec<Value>) -> Value {
+        json!({ "id": movie_id, "cast": cast, "crew": crew })
+    }
+
+    /// A crew member of a movie (`CrewMember`).
+    pub fn crew_member(id: i64, name: &str, job: &str) -> Value {
+        let department = if job == "Director" {
+            "Directing"
+        } else {
+            "Writing"
+        };
+        json!({
+            "adult": false,
+            "gender": 2,
+            "id": id,
+            "known_for_department": department,
+            "name": name,
+            "original_name": name,
+            "popularity": 5.0,
+            "profile_path": null,
+            "credit_id": format!("crew-{id}-{job}"),
+            "department": department,
+            "job": job
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn serves_queued_responses_in_turn_then_repeats_the_last() {
+        let mock = MockTmdb::start().await;
+        mock.on("/ping", MockResponse::status(503, json!({})))
+            .json("/ping", json!({"ok": true}));
+        let client = Client::new();
+        let url = format!("{}/ping", mock.base_url());
+
+        let statuses = [
+            client.get(&url).send().await.unwrap().status(),
+            client.get(&url).send().await.unwrap().status(),
+            client.get(&url).send().await.unwrap().status(),
+        ];
+
+        assert_eq!(statuses.map(|status| status.as_u16()), [503, 200, 200]);
+        assert_eq!(mock.hits("/ping"), 3);
+    }
+
+    #[tokio::test]
+    async fn prefers_the_route_naming_more_query_parameters() {
+        let mock = MockTmdb::start().await;
+        mock.json("/list", json!({"page": 1}))
+            .json("/list?page=2", json!({"page": 2}));
+        let client = Client::new();
+
+        let page: Value = client
+            .get(format!("{}/list?language=en&page=2", mock.base_url()))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+
+        assert_eq!(page, json!({"page": 2}));
+        assert_eq!(mock.requests()[0].query("language"), Some("en"));
+    }
+
+    #[tokio::test]
+    async fn unknown_paths_get_tmdbs_not_found_envelope() {
+        let mock = MockTmdb::start().await;
+
+        let tmdb = mock.client_with(|config| config.language = "fr-FR".to_string());
+        let person = tmdb.person_details(1, &tmdb.config().language).await;
+
+        assert!(person.unwrap().is_none());
+        assert_eq!(mock.requests()[0].query("language"), Some("fr-FR"));
+    }
+
+    #[tokio::test]
+    async fn sends_extra_headers_after_the_delay() {
+        let mock = MockTmdb::start().await;
+        mock.on(
+            "/slow",
+            MockResponse::bytes(200, "text/plain", "done")
+                .header("ETag", "\"v1\"")
+                .delay(Duration::from_millis(50)),
+        );
+        let started = std::time::Instant::now();
+
+        let response = mock
+            .client()
+            .client
+            .get(format!("{}/slow", mock.base_url()))
+            .send()
+            .await
+            .unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert_eq!(response.headers()["etag"], "\"v1\"");
+        assert_eq!(response.text().await.unwrap(), "done");
+    }
+}
@@ -1,9 +1,745 @@
+mod compare_movies;
+mod compare_popularity;
+mod degrees_of_separation;
+mod export_filmography;
+mod featured_actors;
+mod filmography_diff;
+mod find_by_imdb_id;
+mod format;
+mod frequent_director;
+mod get_acclaimed_roles;
+mod get_actor_films_by_genre;
+mod get_actor_highlights;
 mod get_actor_info;
+mod get_actor_links;
+mod get_actor_movies_in_year;
+mod get_alternative_titles;
+mod get_best_film;
+mod get_career_summary;
+mod get_costars_in_film;
+mod get_crew_credits;
+mod get_favorite_movies;
+mod get_filmography_csv;
+mod get_frequent_costars;
+mod get_movie_credits;
+mod get_movie_details;
+mod get_movie_images;
 mod get_movies_by_actor;
+mod get_multilingual_bio;
+mod get_production_companies;
+mod get_rated_movies;
+mod get_top_cast;
+mod get_trending_movies;
+mod get_tv_season;
+mod get_upcoming_for_actor;
+mod list_genres;
+mod movies_with_both_actors;
+mod on_this_day;
+mod rating_trend;
+mod recommend_for_actor;
+mod registry;
+mod search_by_department;
+mod search_movies;
+mod server_info;
+mod summarize_movie;
+mod top_grossing;
+mod trending_actors_latest;
+mod trending_in_genre;
+mod watch_order;
+mod where_to_watch_actor;
 
-use crate::tools::{get_actor_info::GetActorInfo, get_movies_by_actor::GetMoviesByActor};
-use rust_mcp_sdk::tool_box;
+use crate::tmdb_client::{Genre, MovieCredit, Paginated};
+use crate::tools::{
+    compare_movies::CompareMovies, compare_popularity::ComparePopularity,
+    degrees_of_separation::DegreesOfSeparation, export_filmography::ExportFilmography,
+    featured_actors::FeaturedActors, filmography_diff::FilmographyDiff,
+    find_by_imdb_id::FindByImdbId, frequent_director::FrequentDirector,
+    get_acclaimed_roles::GetAcclaimedRoles, get_actor_films_by_genre::GetActorFilmsByGenre,
+    get_actor_highlights::GetActorHighlights, get_actor_info::GetActorInfo,
+    get_actor_links::GetActorLinks, get_actor_movies_in_year::GetActorMoviesInYear,
+    get_alternative_titles::GetAlternativeTitles, get_best_film::GetBestFilm,
+    get_career_summary::GetCareerSummary, get_costars_in_film::GetCostarsInFilm,
+    get_crew_credits::GetCrewCredits, get_filmography_csv::GetFilmographyCsv,
+    get_frequent_costars::GetFrequentCostars, get_movie_credits::GetMovieCredits,
+    get_movie_images::GetMovieImages, get_movies_by_actor::GetMoviesByActor,
+    get_multilingual_bio::GetMultilingualBio, get_production_companies::GetProductionCompanies,
+    get_top_cast::GetTopCast, get_trending_movies::GetTrendingMovies, get_tv_season::GetTvSeason,
+    get_upcoming_for_actor::GetUpcomingForActor, list_genres::ListGenres,
+    movies_with_both_actors::MoviesWithBothActors, on_this_day::OnThisDay,
+    rating_trend::RatingTrend, recommend_for_actor::RecommendForActor,
+    search_by_department::SearchByDepartment, search_movies::SearchMovies, server_info::ServerInfo,
+    summarize_movie::SummarizeMovie, top_grossing::TopGrossing,
+    trending_actors_latest::TrendingActorsLatest, trending_in_genre::TrendingInGenre,
+    watch_order::WatchOrder, where_to_watch_actor::WhereToWatchActor,
+};
+use crate::tools::{
+    get_actor_info::ActorInfoOutput, get_favorite_movies::GetFavoriteMovies,
+    get_rated_movies::GetRatedMovies, get_top_cast::TopCastOutput,
+    summarize_movie::MovieSummaryOutput,
+};
+pub use registry::ToolRegistry;
+use rust_mcp_sdk::{
+    macros::JsonSchema,
+    schema::{CallToolError, CallToolMeta, CallToolResult, ContentBlock, Tool, ToolOutputSchema},
+    tool_box,
+};
+use serde_json::{Map, Value};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
 
 // List of tools provided by this server
 // To add a new tool, create it in the `/tools/` folder and include it in the list below.
-tool_box!(TmdbTools, [GetActorInfo, GetMoviesByActor]);
+// Tools that are only offered in some deployments are added through `ToolRegistry` instead.
+tool_box!(
+    TmdbTools,
+    [
+        GetActorInfo,
+        GetMoviesByActor,
+        FindByImdbId,
+        FeaturedActors,
+        ComparePopularity,
+        CompareMovies,
+        GetTrendingMovies,
+        GetUpcomingForActor,
+        GetBestFilm,
+        GetAlternativeTitles,
+        GetFrequentCostars,
+        GetMovieCredits,
+        GetMovieImages,
+        GetFilmographyCsv,
+        OnThisDay,
+        GetActorLinks,
+        GetTvSeason,
+        GetCareerSummary,
+        MoviesWithBothActors,
+        GetMultilingualBio,
+        SummarizeMovie,
+        TrendingInGenre,
+        GetCrewCredits,
+        GetActorMoviesInYear,
+        DegreesOfSeparation,
+        GetTopCast,
+        GetActorHighlights,
+        ServerInfo,
+        SearchByDepartment,
+        RecommendForActor,
+        RatingTrend,
+        WhereToWatchActor,
+        FilmographyDiff,
+        WatchOrder,
+        GetAcclaimedRoles,
+        ListGenres,
+        GetActorFilmsByGenre,
+        GetProductionCompanies,
+        TrendingActorsLatest,
+        SearchMovies,
+        TopGrossing,
+        FrequentDirector,
+        ExportFilmography,
+        GetCostarsInFilm
+    ]
+);
+
+/// How much detail a tool puts in its text output, requested per call through `_meta.format`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// The full output (the default)
+    #[default]
+    Detailed,
+    /// A short summary, for clients with little room for tool output
+    Terse,
+}
+
+impl ResponseFormat {
+    /// Reads the `format` hint from a tool call's `_meta`, falling back to `Detailed`
+    /// when it is absent or not recognized.
+    pub fn from_meta(meta: Option<&CallToolMeta>) -> Self {
+        let format = meta
+            .and_then(|meta| meta.extra.as_ref())
+            .and_then(|extra| extra.get("format"))
+            .and_then(Value::as_str);
+        match format {
+            Some(format) if format.eq_ignore_ascii_case("terse") => ResponseFormat::Terse,
+            _ => ResponseFormat::Detailed,
+        }
+    }
+}
+
+/// Returns all `TmdbTools` tools, with an output schema attached to the ones that return
+/// structured content. `ToolRegistry::tools` adds the tools registered at runtime.
+pub fn list_tools() -> Vec<Tool> {
+    TmdbTools::tools()
+        .into_iter()
+        .map(with_output_schema)
+        .collect()
+}
+
+/// Attaches the output schema of the tool's structured content, if it returns any.
+pub fn with_output_schema(mut tool: Tool) -> Tool {
+    tool.output_schema = structured_output_schema(&tool.name).map(to_output_schema);
+    tool
+}
+
+/// JSON schema of the structured content returned by a tool, if it returns any.
+fn structured_output_schema(tool_name: &str) -> Option<Map<String, Value>> {
+    match tool_name {
+        name if name == GetActorInfo::tool_name() => Some(ActorInfoOutput::json_schema()),
+        name if name == SummarizeMovie::tool_name() => Some(MovieSummaryOutput::json_schema()),
+        name if name == GetTopCast::tool_name() => Some(TopCastOutput::json_schema()),
+        name if name == GetActorMoviesInYear::tool_name()
+            || name == GetFavoriteMovies::tool_name()
+            || name == GetRatedMovies::tool_name()
+            || name == GetTrendingMovies::tool_name()
+            || name == MoviesWithBothActors::tool_name()
+            || name == RecommendForActor::tool_name()
+            || name == SearchByDepartment::tool_name()
+            || name == SearchMovies::tool_name()
+            || name == TrendingInGenre::tool_name() =>
+        {
+            Some(PageInfo::json_schema())
+        }
+        _ => None,
+    }
+}
+
+/// Structured content returned by the tools that list one page of a TMDB search or discover
+/// request, so clients can tell whether TMDB has more results. Also their output schema.
+#[derive(::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct PageInfo {
+    /// Page listed, starting at 1
+    pub page: u32,
+    /// Number of pages TMDB has for the request
+    pub total_pages: u32,
+    /// Number of results across all pages
+    pub total_results: u32,
+    /// Whether TMDB has pages after this one
+    pub has_more: bool,
+}
+
+impl PageInfo {
+    pub fn of<T>(paginated: &Paginated<T>) -> Self {
+        Self {
+            page: paginated.page,
+            total_pages: paginated.total_pages,
+            total_results: paginated.total_results,
+            has_more: paginated.has_more(),
+        }
+    }
+
+    /// Attaches the pagination to a tool result as its structured content.
+    pub fn attach_to(&self, result: CallToolResult) -> CallToolResult {
+        let structured = serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_object().cloned())
+            .unwrap_or_default();
+        result.with_structured_content(structured)
+    }
+}
+
+/// Orders tallied results by count (highest first), then alphabetically by name (see
+/// [`format::compare_names`]), so tools that aggregate through a `HashMap` still render the
+/// same output on every call.
+pub fn by_count_then_name(a: (u32, &str), b: (u32, &str)) -> Ordering {
+    b.0.cmp(&a.0).then_with(|| format::compare_names(a.1, b.1))
+}
+
+/// Keeps the first credit of each movie, in order. An actor credited for several roles in
+/// one movie has a credit per role, but tools that count, rate or look up movies want each
+/// movie once. Takes credits by value or by reference.
+pub fn unique_movies<C: Borrow<MovieCredit>>(credits: impl IntoIterator<Item = C>) -> Vec<C> {
+    let mut seen = HashSet::new();
+    credits
+        .into_iter()
+        .filter(|credit| seen.insert(credit.borrow().movie.id))
+        .collect()
+}
+
+/// Common spellings that differ from TMDB's genre names.
+const GENRE_ALIASES: &[(&str, &str)] =
+    &[("sci-fi", "Science Fiction"), ("scifi", "Science Fiction")];
+
+/// Finds a genre by name, ignoring case and accepting a few common aliases such as "sci-fi".
+pub fn find_genre<'a>(genres: &'a [Genre], name: &str) -> Option<&'a Genre> {
+    let name = name.trim();
+    let name = GENRE_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, genre)| genre);
+    genres
+        .iter()
+        .find(|genre| genre.name.eq_ignore_ascii_case(name))
+}
+
+/// The error for a genre name `find_genre` did not recognise, listing the valid genres.
+pub fn unknown_genre(genres: &[Genre], name: &str) -> CallToolError {
+    let names = genres
+        .iter()
+        .map(|genre| genre.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    CallToolError::from_message(format!(
+        "Unknown genre \"{}\". Valid genres: {names}",
+        name.trim()
+    ))
+}
+
+/// The error for a `raw: true` call when the server was not started with `TMDB_ALLOW_RAW`.
+pub fn raw_not_allowed() -> CallToolError {
+    CallToolError::from_message(
+        "Raw TMDB responses are disabled on this server; set TMDB_ALLOW_RAW=true to allow them",
+    )
+}
+
+/// Returns the biography to display: a placeholder when it is empty, or the first
+/// `max_chars` characters (cut at a word boundary) followed by a truncation marker.
+pub fn summarize_biography(biography: &str, max_chars: usize) -> String {
+    let biography = biography.trim();
+    if biography.is_empty() {
+        return "No biography available".to_string();
+    }
+    if biography.chars().count() <= max_chars {
+        return biography.to_string();
+    }
+
+    let cut: String = biography.chars().take(max_chars).collect();
+    let cut = match cut.rfind(char::is_whitespace) {
+        Some(index) if index > 0 => &cut[..index],
+        _ => cut.as_str(),
+    };
+    format!("{}...(truncated)", cut.trim_end())
+}
+
+/// Characters per token assumed by `estimate_tokens`, a common rule of thumb for English text.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Roughly estimates how many LLM tokens a text takes up.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Cuts the text content of a tool result so it stays within about `max_tokens` tokens (see
+/// `estimate_tokens`), ending it with a note. Text blocks past the budget are dropped; images,
+/// other blocks and structured content are left as they are.
+pub fn truncate_output(mut result: CallToolResult, max_tokens: usize) -> CallToolResult {
+    let total: usize = result
+        .content
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::TextContent(text) => Some(estimate_tokens(&text.text)),
+            _ => None,
+        })
+        .sum();
+    if total <= max_tokens {
+        return result;
+    }
+
+    let note = format!("\n(output truncated to fit {max_tokens} tokens)");
+    let mut remaining = max_tokens
+        .saturating_mul(CHARS_PER_TOKEN)
+        .saturating_sub(note.chars().count());
+    let mut truncated = false;
+    result.content.retain_mut(|block| {
+        let ContentBlock::TextContent(text) = block else {
+            return true;
+        };
+        if truncated {
+            return false;
+        }
+        let length = text.text.chars().count();
+        if length <= remaining {
+            remaining -= length;
+            return true;
+        }
+
+        // cut at the last line break that fits, so list items are not split
+        let cut: String = text.text.chars().take(remaining).collect();
+        let cut = match cut.rfind('\n') {
+            Some(index) if index > 0 => &cut[..index],
+            _ => cut.as_str(),
+        };
+        text.text = format!("{}{note}", cut.trim_end());
+        truncated = true;
+        true
+    });
+    result
+}
+
+/// Returns the key facts of a result's structured content that its text never mentions, so
+/// `McpHandler` can catch, in debug builds, a tool that updated one without the other.
+///
+/// The facts checked are the top-level `id`, `*_id`, `name` and `title` fields, and the `id`
+/// of each object in a top-level array (e.g. every cast member). Error results and results
+/// without structured content have nothing to check.
+pub fn structured_mismatches(result: &CallToolResult) -> Vec<String> {
+    let Some(structured) = result.structured_content.as_ref() else {
+        return Vec::new();
+    };
+    if result.is_error == Some(true) {
+        return Vec::new();
+    }
+    let text = result_text(result);
+    let mentions_number = |number: &Value| {
+        let number = number.to_string();
+        text.split(|c: char| !c.is_ascii_digit())
+            .any(|word| word == number)
+    };
+
+    let mut mismatches = Vec::new();
+    for (key, value) in structured {
+        match value {
+            Value::Number(_)
+                if (key == "id" || key.ends_with("_id")) && !mentions_number(value) =>
+            {
+                mismatches.push(format!("{key} {value} is not in the text"));
+            }
+            Value::String(name)
+                if (key == "name" || key == "title") && !text.contains(name.as_str()) =>
+            {
+                mismatches.push(format!("{key} \"{name}\" is not in the text"));
+            }
+            Value::Array(items) => {
+                for id in items.iter().filter_map(|item| item.get("id")) {
+                    if id.is_number() && !mentions_number(id) {
+                        mismatches.push(format!("{key} entry {id} is not in the text"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    mismatches
+}
+
+/// Joins the text blocks of a tool result, one per line.
+pub fn result_text(result: &CallToolResult) -> String {
+    result
+        .content
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::TextContent(text) => Some(text.text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Sub-requests of a batch tool that failed. Batch tools keep going when one item fails and
+/// report these after the results they could fetch, so one bad ID never aborts the whole call.
+#[derive(Debug, Default)]
+pub struct BatchFailures(Vec<(String, String)>);
+
+impl BatchFailures {
+    /// Records that `item` (e.g. `"Tom Hanks"` or `"movie 550"`) could not be fetched.
+    pub fn push(&mut self, item: impl Into<String>, error: impl Display) {
+        self.0.push((item.into(), error.to_string()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Implements Display to list each failed item with the reason, one per line.
+impl Display for BatchFailures {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not fetch {} item(s):", self.0.len())?;
+        for (item, error) in &self.0 {
+            write!(f, "\n- {item}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts an object schema generated by the `JsonSchema` derive into a tool output schema.
+fn to_output_schema(schema: Map<String, Value>) -> ToolOutputSchema {
+    let properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .filter_map(|(name, value)| Some((name.clone(), value.as_object()?.clone())))
+                .collect()
+        });
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| {
+            required
+                .iter()
+                .filter_map(|name| name.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    ToolOutputSchema::new(required, properties, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::fixtures;
+
+    #[test]
+    fn empty_biographies_get_a_placeholder() {
+        assert_eq!(summarize_biography("  \n ", 100), "No biography available");
+    }
+
+    #[test]
+    fn long_biographies_are_cut_at_a_word_boundary() {
+        assert_eq!(
+            summarize_biography("Thomas Jeffrey Hanks is an American actor", 22),
+            "Thomas Jeffrey Hanks...(truncated)"
+        );
+        assert_eq!(summarize_biography("Short.", 20), "Short.");
+    }
+
+    #[test]
+    fn long_output_is_cut_at_a_line_break_within_the_budget() {
+        let lines: Vec<String> = (1..=20).map(|n| format!("{n:02}. A movie title")).collect();
+        let text = lines.join("\n");
+        let result = CallToolResult::text_content(vec![text.clone().into()]);
+        assert_eq!(estimate_tokens(&text), 90);
+
+        let short = truncate_output(result.clone(), 90);
+        assert_eq!(result_text(&short), text);
+
+        let cut = result_text(&truncate_output(result, 30));
+        assert!(cut.chars().count() <= 30 * CHARS_PER_TOKEN, "{cut}");
+        assert_eq!(
+            cut,
+            format!(
+                "{}\n(output truncated to fit 30 tokens)",
+                lines[..4].join("\n")
+            )
+        );
+    }
+
+    #[test]
+    fn blocks_past_the_budget_are_dropped_but_images_kept() {
+        let mut result = CallToolResult::text_content(vec![]);
+        result.content = vec![
+            ContentBlock::text_content("a".repeat(40)),
+            ContentBlock::image_content("aGk=".to_string(), "image/png".to_string()),
+            ContentBlock::text_content("b".repeat(40)),
+            ContentBlock::text_content("c".repeat(40)),
+        ];
+
+        let cut = truncate_output(result, 25);
+
+        assert_eq!(cut.content.len(), 3);
+        assert!(matches!(cut.content[1], ContentBlock::ImageContent(_)));
+        assert_eq!(
+            result_text(&cut),
+            format!(
+                "{}\n{}\n(output truncated to fit 25 tokens)",
+                "a".repeat(40),
+                // 100 characters, less the first block and the 36 of the note
+                "b".repeat(24)
+            )
+        );
+    }
+
+    #[test]
+    fn a_huge_budget_does_not_overflow() {
+        let result = CallToolResult::text_content(vec!["Forrest Gump".into()]);
+
+        let kept = truncate_output(result, usize::MAX);
+
+        assert_eq!(result_text(&kept), "Forrest Gump");
+    }
+
+    #[test]
+    fn structured_tools_advertise_an_output_schema() {
+        let tools = list_tools();
+        let actor_info = tools
+            .iter()
+            .find(|tool| tool.name == GetActorInfo::tool_name())
+            .unwrap();
+        let schema = actor_info.output_schema.as_ref().unwrap();
+        assert!(schema.required.contains(&"biography_truncated".to_string()));
+        assert!(!schema.required.contains(&"birthday".to_string()));
+        assert!(
+            schema
+                .properties
+                .as_ref()
+                .unwrap()
+                .contains_key("known_for")
+        );
+
+        let text_only = tools
+            .iter()
+            .find(|tool| tool.name == GetBestFilm::tool_name())
+            .unwrap();
+        assert!(text_only.output_schema.is_none());
+    }
+
+    #[test]
+    fn terse_output_is_requested_through_meta_format() {
+        let meta =
+            |value: serde_json::Value| -> CallToolMeta { serde_json::from_value(value).unwrap() };
+
+        let terse = meta(serde_json::json!({ "format": "TERSE" }));
+        assert_eq!(
+            ResponseFormat::from_meta(Some(&terse)),
+            ResponseFormat::Terse
+        );
+
+        let unknown = meta(serde_json::json!({ "format": "verbose" }));
+        assert_eq!(
+            ResponseFormat::from_meta(Some(&unknown)),
+            ResponseFormat::Detailed
+        );
+        let absent = meta(serde_json::json!({ "progressToken": 1 }));
+        assert_eq!(
+            ResponseFormat::from_meta(Some(&absent)),
+            ResponseFormat::Detailed
+        );
+        assert_eq!(ResponseFormat::from_meta(None), ResponseFormat::Detailed);
+    }
+
+    #[test]
+    fn batch_failures_list_each_item_with_its_reason() {
+        let mut failures = BatchFailures::default();
+        assert!(failures.is_empty());
+
+        failures.push("movie 550", "timed out");
+        failures.push("Brad Pitt", "TMDB is unavailable");
+
+        assert_eq!(failures.len(), 2);
+        assert_eq!(
+            failures.to_string(),
+            "Could not fetch 2 item(s):\n- movie 550: timed out\n- Brad Pitt: TMDB is unavailable"
+        );
+    }
+
+    #[test]
+    fn unique_movies_keeps_the_first_credit_of_each_movie() {
+        let credits: Vec<MovieCredit> = [
+            fixtures::credit(83542, "Cloud Atlas", "2012-10-26", "Zachry"),
+            fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump"),
+            fixtures::credit(83542, "Cloud Atlas", "2012-10-26", "Dr. Henry Goose"),
+        ]
+        .into_iter()
+        .map(|credit| serde_json::from_value(credit).unwrap())
+        .collect();
+
+        let borrowed = unique_movies(&credits);
+        let characters: Vec<_> = borrowed.iter().map(|c| c.character.as_str()).collect();
+        assert_eq!(characters, ["Zachry", "Forrest Gump"]);
+
+        let owned = unique_movies(credits.clone());
+        assert_eq!(owned.len(), 2);
+        assert_eq!(owned[1].movie.id, 13);
+    }
+
+    /// `value` marked as adult content.
+    fn adult(mut value: serde_json::Value) -> serde_json::Value {
+        value["adult"] = true.into();
+        value
+    }
+
+    #[tokio::test]
+    async fn safe_search_hides_adult_entries_from_every_tool() {
+        use crate::tmdb_client::test_server::MockTmdb;
+        use crate::tmdb_client::{TimeWindow, TmdbClient};
+        use serde_json::json;
+
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/13/credits",
+            fixtures::credits(
+                13,
+                vec![
+                    fixtures::cast_member(31, "Tom Hanks", "Forrest Gump", 0),
+                    adult(fixtures::cast_member(666, "Adult Star", "Extra", 1)),
+                ],
+                vec![],
+            ),
+        )
+        .json("/person/488", fixtures::person(488, "Steven Spielberg"))
+        .json(
+            "/person/488/combined_credits",
+            json!({ "id": 488, "cast": [], "crew": [
+                { "id": 578, "media_type": "movie", "title": "Jaws", "release_date": "1975-06-20",
+                  "department": "Directing", "job": "Director" },
+                { "id": 667, "media_type": "movie", "title": "Adult Film", "release_date": "1980-01-01",
+                  "department": "Directing", "job": "Director", "adult": true }
+            ] }),
+        )
+        .json(
+            "/find/tt0075148",
+            json!({
+                "movie_results": [
+                    fixtures::movie(1366, "Rocky", "1976-11-21"),
+                    adult(fixtures::movie(668, "Adult Rocky", "1976-11-21")),
+                ],
+                "person_results": [adult(fixtures::person_summary(669, "Adult Star"))]
+            }),
+        )
+        .json(
+            "/trending/movie/week",
+            fixtures::page(
+                1,
+                1,
+                3,
+                vec![
+                    fixtures::movie(438631, "Dune", "2021-09-15"),
+                    adult(fixtures::movie(670, "Adult Dune", "2021-09-15")),
+                    fixtures::movie(693134, "Dune: Part Two", "2024-02-27"),
+                ],
+            ),
+        );
+
+        let run_all = |client: TmdbClient| async move {
+            let top_cast = get_top_cast::GetTopCast {
+                movie_id: 13,
+                limit: None,
+            }
+            .invoke(&client)
+            .await
+            .unwrap();
+            let crew = get_crew_credits::GetCrewCredits { person_id: 488 }
+                .invoke(&client)
+                .await
+                .unwrap();
+            let found = find_by_imdb_id::FindByImdbId {
+                imdb_id: "tt0075148".to_string(),
+            }
+            .invoke(&client)
+            .await
+            .unwrap();
+            let trending = get_trending_movies::GetTrendingMovies {
+                time_window: TimeWindow::Week,
+            }
+            .invoke(&client)
+            .await
+            .unwrap();
+            [top_cast, crew, found, trending]
+        };
+
+        let results = run_all(mock.client()).await;
+        for result in &results {
+            assert!(!result_text(result).contains("Adult"), "{result:?}");
+        }
+        assert_eq!(
+            result_text(&results[2]),
+            "Movie: Rocky (1976) - TMDB ID: 1366"
+        );
+        let trending = results[3].structured_content.as_ref().unwrap();
+        assert_eq!(trending["total_results"], 2);
+
+        let results = run_all(mock.client_with(|config| config.safe_search = false)).await;
+        for result in &results {
+            assert!(result_text(result).contains("Adult"), "{result:?}");
+        }
+        let trending = results[3].structured_content.as_ref().unwrap();
+        assert_eq!(trending["total_results"], 3);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieFull, TmdbClient},
+    tools::format::{self, format_usd},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "compare_movies",
+    title = "Compare Two Movies",
+    description = concat!(
+        "Compare two movies side by side by their TMDB IDs. ",
+        "Shows release year, runtime, rating, budget, revenue, spoken languages and production countries, ",
+        "and which movie is rated higher.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct CompareMovies {
+    /// TMDB ID of the first movie
+    #[json_schema(minimum = 1)]
+    pub movie_id_a: i64,
+    /// TMDB ID of the second movie
+    #[json_schema(minimum = 1)]
+    pub movie_id_b: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl CompareMovies {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // fetch both movies concurrently
+        let (movie_a, movie_b) = tokio::join!(
+            tmdb_client.movie_details(self.movie_id_a),
+            tmdb_client.movie_details(self.movie_id_b),
+        );
+        let movie_a = movie_a.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let movie_b = movie_b.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // return an error message naming whichever id could not be found
+        let (Some(movie_a), Some(movie_b)) = (movie_a, movie_b) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!(
+                    "Could not find both movies (IDs {} and {})",
+                    self.movie_id_a, self.movie_id_b
+                ),
+            )));
+        };
+
+        let config = tmdb_client.config();
+        let missing = config.missing_fields_or(MissingFields::Label);
+        Ok(CallToolResult::text_content(vec![
+            render_comparison(&movie_a, &movie_b, &config.language, missing).into(),
+        ]))
+    }
+}
+
+/// Renders a side-by-side table of the two movies followed by the higher-rated one.
+/// A value TMDB doesn't know shows as `Unknown`; with `MissingFields::Omit`, a row neither
+/// movie has a value for is left out.
+fn render_comparison(
+    a: &MovieFull,
+    b: &MovieFull,
+    language: &str,
+    missing: MissingFields,
+) -> String {
+    let rows = [
+        (
+            "Release year",
+            a.release_year().map(str::to_string),
+            b.release_year().map(str::to_string),
+        ),
+        (
+            "Runtime",
+            format_runtime(a.runtime),
+            format_runtime(b.runtime),
+        ),
+        (
+            "Rating",
+            Some(format_rating(a, language)),
+            Some(format_rating(b, language)),
+        ),
+        ("Budget", format_amount(a.budget), format_amount(b.budget)),
+        (
+            "Revenue",
+            format_amount(a.revenue),
+            format_amount(b.revenue),
+        ),
+        ("Languages", format_languages(a), format_languages(b)),
+        ("Countries", format_countries(a), format_countries(b)),
+    ];
+
+    let mut result = format!("Field | {} | {}\n", a.title, b.title);
+    for (field, value_a, value_b) in rows {
+        if value_a.is_none() && value_b.is_none() && missing == MissingFields::Omit {
+            continue;
+        }
+        let value_a = value_a.as_deref().unwrap_or("Unknown");
+        let value_b = value_b.as_deref().unwrap_or("Unknown");
+        result.push_str(&format!("{field} | {value_a} | {value_b}\n"));
+    }
+
+    let verdict = match a.vote_average.total_cmp(&b.vote_average) {
+        std::cmp::Ordering::Greater => format!("Higher rated: {}", a.title),
+        std::cmp::Ordering::Less => format!("Higher rated: {}", b.title),
+        std::cmp::Ordering::Equal => "Both movies are rated the same".to_string(),
+    };
+    result.push_str(&verdict);
+
+    result
+}
+
+fn format_runtime(runtime: Option<u32>) -> Option<String> {
+    runtime
+        .filter(|minutes| *minutes > 0)
+        .map(|minutes| format!("{minutes} min"))
+}
+
+/// TMDB uses 0 for an unknown budget or revenue.
+fn format_amount(amount: i64) -> Option<String> {
+    (amount > 0).then(|| format_usd(amount))
+}
+
+fn format_rating(movie: &MovieFull, language: &str) -> String {
+    format!(
+        "{} ({} votes)",
+        format::format_rating(movie.vote_average, language),
+        movie.vote_count
+    )
+}
+
+/// Lists the spoken languages by English name, falling back to the ISO code.
+fn format_languages(movie: &MovieFull) -> Option<String> {
+    format_names(movie.spoken_languages.iter().map(|language| {
+        if language.english_name.is_empty() {
+            language.iso_639_1.as_str()
+        } else {
+            language.english_name.as_str()
+        }
+    }))
+}
+
+/// Lists the production countries by ISO code (e.g. `US, FR`), which stays compact in the table.
+fn format_countries(movie: &MovieFull) -> Option<String> {
+    format_names(
+        movie
+            .production_countries
+            .iter()
+            .map(|country| country.iso_3166_1.as_str()),
+    )
+}
+
+/// Joins names with commas; TMDB leaves the list empty when it doesn't know.
+fn format_names<'a>(names: impl Iterator<Item = &'a str>) -> Option<String> {
+    let names: Vec<_> = names.collect();
+    (!names.is_empty()).then(|| names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    #[tokio::test]
+    async fn compares_two_movies_side_by_side() {
+        let mock = MockTmdb::start().await;
+        let mut rocky = fixtures::movie_full(1366, "Rocky", "1976-11-21");
+        rocky["vote_average"] = 7.8.into();
+        rocky["budget"] = 1_000_000.into();
+        let mut creed = fixtures::movie_full(312221, "Creed", "2015-11-25");
+        creed["runtime"] = serde_json::Value::Null;
+        mock.json("/movie/1366", rocky).json("/movie/312221", creed);
+
+        let result = CompareMovies {
+            movie_id_a: 1366,
+            movie_id_b: 312221,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Field | Rocky | Creed\nRelease year | 1976 | 2015\n"));
+        assert!(text.contains("Runtime | 120 min | Unknown\n"));
+        assert!(text.contains("Rating | 7.8 (1000 votes) | 7.0 (1000 votes)\n"));
+        assert!(text.contains("Budget | $1,000,000 | Unknown\n"));
+        assert!(text.ends_with("Higher rated: Rocky"));
+    }
+
+    /// Compares two movies that both lack a budget and revenue, while only the second lacks
+    /// a runtime.
+    async fn compare_budgetless_pair(missing_fields: Option<MissingFields>) -> String {
+        let mock = MockTmdb::start().await;
+        let rocky = fixtures::movie_full(1366, "Rocky", "1976-11-21");
+        let mut creed = fixtures::movie_full(312221, "Creed", "2015-11-25");
+        creed["runtime"] = serde_json::Value::Null;
+        mock.json("/movie/1366", rocky).json("/movie/312221", creed);
+        let client = mock.client_with(|config| config.missing_fields = missing_fields);
+
+        let result = CompareMovies {
+            movie_id_a: 1366,
+            movie_id_b: 312221,
+        }
+        .invoke(&client)
+        .await
+        .unwrap();
+        result_text(&result)
+    }
+
+    #[tokio::test]
+    async fn rows_neither_movie_knows_are_labelled_by_default() {
+        let text = compare_budgetless_pair(None).await;
+
+        assert!(text.contains("Budget | Unknown | Unknown\n"));
+        assert!(text.contains("Runtime | 120 min | Unknown\n"));
+    }
+
+    #[tokio::test]
+    async fn omit_mode_drops_rows_neither_movie_knows() {
+        let text = compare_budgetless_pair(Some(MissingFields::Omit)).await;
+
+        assert!(!text.contains("Budget"));
+        assert!(!text.contains("Revenue"));
+        // a value only one movie lacks still needs its cell
+        assert!(text.contains("Runtime | 120 min | Unknown\n"));
+    }
+
+    #[tokio::test]
+    async fn label_mode_keeps_every_row() {
+        let text = compare_budgetless_pair(Some(MissingFields::Label)).await;
+
+        assert!(text.contains("Budget | Unknown | Unknown\n"));
+        assert!(text.contains("Revenue | Unknown | Unknown\n"));
+    }
+
+    #[tokio::test]
+    async fn a_missing_movie_is_an_error() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/1366",
+            fixtures::movie_full(1366, "Rocky", "1976-11-21"),
+        );
+
+        let result = CompareMovies {
+            movie_id_a: 1366,
+            movie_id_b: 404,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("Could not find both movies (IDs 1366 and 404)"));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{tmdb_client::TmdbClient, tools::BatchFailures};
+use futures::future::join_all;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "compare_popularity",
+    title = "Compare Actor Popularity",
+    description = concat!(
+        "Compare several actors by their current TMDB popularity score. ",
+        "Provide a list of actor names; the tool returns them ranked from most to least popular, ",
+        "and lists any names that could not be found or looked up separately.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct ComparePopularity {
+    /// Names of the actors to compare, e.g. ["Tom Hanks", "Meryl Streep"]
+    #[json_schema(min_length = 2)]
+    pub actor_names: Vec<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl ComparePopularity {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // ignore blank and repeated names; a comparison needs two actors
+        let mut names: Vec<&str> = Vec::new();
+        for name in self.actor_names.iter().map(|name| name.trim()) {
+            if !name.is_empty() && !names.iter().any(|seen| seen.eq_ignore_ascii_case(name)) {
+                names.push(name);
+            }
+        }
+        if names.len() < 2 {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                "Provide at least two different actor names to compare",
+            )));
+        }
+
+        // look up every actor concurrently
+        let responses = join_all(names.iter().map(|name| tmdb_client.actor_info(name, None))).await;
+
+        let mut found = Vec::new();
+        let mut not_found = Vec::new();
+        let mut failures = BatchFailures::default();
+        for (name, response) in names.iter().copied().zip(responses) {
+            match response {
+                Ok(Some(actor)) => found.push(actor),
+                Ok(None) => not_found.push(name),
+                // keep comparing the others; the failure is reported with the results
+                Err(err) => failures.push(name, err),
+            }
+        }
+
+        if found.is_empty() {
+            let message = if failures.is_empty() {
+                "None of the given actors were found".to_string()
+            } else {
+                format!("None of the given actors could be compared. {failures}")
+            };
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                message,
+            )));
+        }
+
+        // most popular first
+        found.sort_by(|a, b| b.details.popularity.total_cmp(&a.details.popularity));
+
+        let mut result = String::from("Rank | Name | Popularity\n");
+        for (index, actor) in found.iter().enumerate() {
+            result.push_str(&format!(
+                "{} | {} | {:.2}\n",
+                index + 1,
+                actor.details.name,
+                actor.details.popularity
+            ));
+        }
+        if !not_found.is_empty() {
+            result.push_str(&format!("\nNot found: {}", not_found.join(", ")));
+        }
+        if !failures.is_empty() {
+            result.push_str(&format!("\n\n{failures}"));
+        }
+
+        Ok(CallToolResult::text_content(vec![
+            result.trim_end().to_string().into(),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    fn tool(actor_names: &[&str]) -> ComparePopularity {
+        ComparePopularity {
+            actor_names: actor_names.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    /// Serves a search result and details for an actor with the given popularity.
+    fn mock_actor(mock: &MockTmdb, id: i64, name: &str, popularity: f64) {
+        let mut person = fixtures::person(id, name);
+        person["popularity"] = popularity.into();
+        mock.json(
+            &format!("/search/person?query={}", name.replace(' ', "+")),
+            fixtures::single_page(vec![fixtures::person_summary(id, name)]),
+        )
+        .json(&format!("/person/{id}"), person);
+    }
+
+    #[tokio::test]
+    async fn ranks_actors_by_popularity() {
+        let mock = MockTmdb::start().await;
+        mock_actor(&mock, 31, "Tom Hanks", 40.5);
+        mock_actor(&mock, 5064, "Meryl Streep", 55.25);
+        mock.json("/search/person?query=Nobody", fixtures::single_page(vec![]));
+
+        let result = tool(&["Tom Hanks", "Meryl Streep", "Nobody"])
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Rank | Name | Popularity\n1 | Meryl Streep | 55.25\n2 | Tom Hanks | 40.50\n\nNot found: Nobody"
+        );
+    }
+
+    #[tokio::test]
+    async fn one_failed_lookup_does_not_abort_the_comparison() {
+        let mock = MockTmdb::start().await;
+        mock_actor(&mock, 31, "Tom Hanks", 40.5);
+        mock_actor(&mock, 5064, "Meryl Streep", 55.25);
+        mock.json(
+            "/search/person?query=Brad+Pitt",
+            fixtures::single_page(vec![fixtures::person_summary(287, "Brad Pitt")]),
+        )
+        .on(
+            "/person/287",
+            MockResponse::status(
+                500,
+                serde_json::json!({
+                    "success": false,
+                    "status_code": 11,
+                    "status_message": "Internal error: Something went wrong, contact TMDb."
+                }),
+            ),
+        );
+
+        let result = tool(&["Tom Hanks", "Brad Pitt", "Meryl Streep"])
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, None);
+        let text = result_text(&result);
+        assert!(text.starts_with(
+            "Rank | Name | Popularity\n1 | Meryl Streep | 55.25\n2 | Tom Hanks | 40.50\n\n"
+        ));
+        assert!(text.contains("Could not fetch 1 item(s):\n- Brad Pitt: "));
+        assert!(text.contains("Something went wrong"));
+    }
+
+    #[tokio::test]
+    async fn fewer_than_two_different_names_are_rejected() {
+        let mock = MockTmdb::start().await;
+
+        let result = tool(&["Tom Hanks", " tom hanks ", ""])
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("at least two different actor names"));
+        assert!(mock.requests().is_empty());
+    }
+}
@@ -0,0 +1,426 @@
+use crate::{
+    tmdb_client::{MovieDetail, PersonSummary, TmdbClient},
+    tools::BatchFailures,
+};
+use futures::stream::{self, StreamExt};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Number of movies between the two actors searched when `max_depth` is omitted.
+const DEFAULT_DEPTH: u32 = 2;
+
+/// Deepest search allowed; each extra level multiplies the number of requests.
+const MAX_DEPTH: u32 = 3;
+
+/// Number of each actor's most popular movies whose casts are followed.
+const MAX_MOVIES_PER_ACTOR: usize = 5;
+
+/// Number of top-billed cast members followed from each movie.
+const MAX_CAST_PER_MOVIE: usize = 10;
+
+/// Hard limit on the TMDB requests one search may make.
+const MAX_REQUESTS: usize = 60;
+
+/// Number of cast lists fetched at the same time.
+const CONCURRENT_REQUESTS: usize = 4;
+
+#[mcp_tool(
+    name = "degrees_of_separation",
+    title = "Degrees of Separation",
+    description = concat!(
+        "Find how two actors are connected through the movies they appeared in, ",
+        "Bacon-number style: e.g. actor A was in a movie with actor X, who was in a movie with actor B. ",
+        "Specify both actors by name and optionally `max_depth`, the most movies allowed in the chain ",
+        "(default 2, at most 3). Only the most popular movies and top-billed cast are followed, ",
+        "so obscure connections may be missed.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct DegreesOfSeparation {
+    /// Name of the first actor
+    #[json_schema(min_length = 1)]
+    pub actor_name_a: String,
+    /// Name of the second actor
+    #[json_schema(min_length = 1)]
+    pub actor_name_b: String,
+    /// Most movies allowed in the chain between the two actors (default 2, at most 3)
+    #[json_schema(minimum = 1)]
+    pub max_depth: Option<u32>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl DegreesOfSeparation {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let max_depth = self.max_depth.unwrap_or(DEFAULT_DEPTH);
+        if max_depth > MAX_DEPTH {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("`max_depth` must be at most {MAX_DEPTH}"),
+            )));
+        }
+
+        // resolve both actors
+        let (actor_a, actor_b) = tokio::join!(
+            find_actor(tmdb_client, &self.actor_name_a),
+            find_actor(tmdb_client, &self.actor_name_b),
+        );
+        let actor_a = actor_a.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor_a) = actor_a else {
+            return Ok(actor_not_found(&self.actor_name_a));
+        };
+        let actor_b = actor_b.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor_b) = actor_b else {
+            return Ok(actor_not_found(&self.actor_name_b));
+        };
+
+        if actor_a.id == actor_b.id {
+            return Ok(CallToolResult::text_content(vec![
+                format!(
+                    "\"{}\" and \"{}\" are the same person",
+                    self.actor_name_a.trim(),
+                    self.actor_name_b.trim()
+                )
+                .into(),
+            ]));
+        }
+
+        // every movie of the second actor: reaching any of them completes the chain
+        let target_movies: HashSet<i64> = tmdb_client
+            .person_movie_credits(actor_b.id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?
+            .into_iter()
+            .map(|credit| credit.movie.id)
+            .collect();
+
+        let mut search = Search::new(tmdb_client, &actor_a);
+        let outcome = search.run(&target_movies, max_depth).await;
+
+        let mut result = match outcome {
+            Outcome::Found { person_id, movie } => {
+                let mut steps = search.path_to(person_id);
+                steps.push((person_id, movie, actor_b.id));
+                search.names.insert(actor_b.id, actor_b.name.clone());
+
+                let lines = steps
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (from, movie, to))| {
+                        format!(
+                            "{}. {} and {} in {movie}",
+                            index + 1,
+                            search.names[from],
+                            search.names[to]
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let movies = if steps.len() == 1 { "movie" } else { "movies" };
+                format!(
+                    "{} and {} are connected through {} {movies}:\n{lines}",
+                    actor_a.name,
+                    actor_b.name,
+                    steps.len()
+                )
+            }
+            Outcome::NotFound => format!(
+                "No connection between {} and {} within depth {max_depth}",
+                actor_a.name, actor_b.name
+            ),
+            Outcome::OutOfRequests => format!(
+                "No connection between {} and {} was found before the search limit of {MAX_REQUESTS} requests was reached",
+                actor_a.name, actor_b.name
+            ),
+        };
+
+        if !search.failures.is_empty() {
+            result.push_str(&format!("\n\n{}", search.failures));
+        }
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+/// Error result for an actor name without search results.
+fn actor_not_found(name: &str) -> CallToolResult {
+    CallToolResult::with_error(CallToolError::from_message(format!(
+        "No actors matching the name \"{}\" were found",
+        name.trim()
+    )))
+}
+
+/// Looks up an actor by name, returning the best match.
+async fn find_actor(
+    tmdb_client: &TmdbClient,
+    name: &str,
+) -> Result<Option<PersonSummary>, crate::tmdb_client::TmdbError> {
+    let people = tmdb_client.search_people(name.trim()).await?;
+    Ok(people.results.into_iter().next())
+}
+
+/// How a search ended.
+enum Outcome {
+    /// `person_id` (reached from the first actor) appeared in `movie` (title and year) with
+    /// the second actor.
+    Found { person_id: i64, movie: String },
+    /// Every path up to the maximum depth was followed without reaching the second actor.
+    NotFound,
+    /// The request limit was reached before the search finished.
+    OutOfRequests,
+}
+
+/// State of a breadth-first search from one actor through movies and their casts.
+struct Search<'a> {
+    tmdb_client: &'a TmdbClient,
+    /// Requests made so far, checked against `MAX_REQUESTS`
+    requests: usize,
+    /// Names of the people reached, by TMDB ID
+    names: HashMap<i64, String>,
+    /// For each person reached, the person and movie they were reached through
+    reached_from: HashMap<i64, (i64, String)>,
+    /// Movies whose casts have already been followed
+    followed_movies: HashSet<i64>,
+    failures: BatchFailures,
+    start_id: i64,
+}
+
+impl<'a> Search<'a> {
+    fn new(tmdb_client: &'a TmdbClient, start: &PersonSummary) -> Self {
+        Self {
+            tmdb_client,
+            requests: 0,
+            names: HashMap::from([(start.id, start.name.clone())]),
+            reached_from: HashMap::new(),
+            followed_movies: HashSet::new(),
+            failures: BatchFailures::default(),
+            start_id: start.id,
+        }
+    }
+
+    /// Searches level by level: at depth `d`, each person `d - 1` movies away from the start
+    /// has their filmography compared against `target_movies`, and the casts of their top
+    /// movies become the next level.
+    async fn run(&mut self, target_movies: &HashSet<i64>, max_depth: u32) -> Outcome {
+        let mut level = vec![self.start_id];
+        for depth in 1..=max_depth {
+            let mut next_level = Vec::new();
+            for person_id in level {
+                if self.requests >= MAX_REQUESTS {
+                    return Outcome::OutOfRequests;
+                }
+                let Some(mut movies) = self.filmography(person_id).await else {
+                    continue;
+                };
+                movies.sort_by(|a, b| b.popularity.total_cmp(&a.popularity));
+
+                // a movie shared with the second actor completes the chain
+                if let Some(movie) = movies
+                    .iter()
+                    .find(|movie| target_movies.contains(&movie.id))
+                {
+                    return Outcome::Found {
+                        person_id,
+                        movie: movie.to_string().trim_end().to_string(),
+                    };
+                }
+
+                if depth < max_depth {
+                    next_level.extend(self.costars(person_id, &movies).await);
+                }
+            }
+            level = next_level;
+        }
+        Outcome::NotFound
+    }
+
+    /// Fetches a person's movies, recording a failure instead of stopping the search.
+    async fn filmography(&mut self, person_id: i64) -> Option<Vec<MovieDetail>> {
+        self.requests += 1;
+        match self.tmdb_client.person_movie_credits(person_id).await {
+            Ok(credits) => Some(credits.into_iter().map(|credit| credit.movie).collect()),
+            Err(err) => {
+                self.failures
+                    .push(format!("movies of {}", self.names[&person_id]), err);
+                None
+            }
+        }
+    }
+
+    /// Follows the casts of a person's most popular movies not followed yet, returning the
+    /// IDs of the people reached for the first time.
+    async fn costars(&mut self, person_id: i64, movies: &[MovieDetail]) -> Vec<i64> {
+        let budget = MAX_REQUESTS.saturating_sub(self.requests);
+        let movies: Vec<MovieDetail> = movies
+            .iter()
+            .filter(|movie| self.followed_movies.insert(movie.id))
+            .take(MAX_MOVIES_PER_ACTOR.min(budget))
+            .cloned()
+            .collect();
+        self.requests += movies.len();
+
+        let tmdb_client = self.tmdb_client;
+        let casts = stream::iter(movies)
+            .map(|movie| async move {
+                let cast = tmdb_client.movie_credits(movie.id).await;
+                (movie, cast)
+            })
+            .buffered(CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut reached = Vec::new();
+        for (movie, cast) in casts {
+            let credits = match cast {
+                Ok(Some(credits)) => credits,
+                Ok(None) => continue,
+                Err(err) => {
+                    self.failures.push(format!("cast of {}", movie.title), err);
+                    continue;
+                }
+            };
+            for member in credits.cast.into_iter().take(MAX_CAST_PER_MOVIE) {
+                if self.names.contains_key(&member.id) {
+                    continue;
+                }
+                self.names.insert(member.id, member.name);
+                self.reached_from.insert(
+                    member.id,
+                    (person_id, movie.to_string().trim_end().to_string()),
+                );
+                reached.push(member.id);
+            }
+        }
+        reached
+    }
+
+    /// Returns the chain of (person, movie, person) steps from the start to `person_id`.
+    fn path_to(&self, person_id: i64) -> Vec<(i64, String, i64)> {
+        let mut steps = Vec::new();
+        let mut current = person_id;
+        while let Some((from, movie)) = self.reached_from.get(&current) {
+            steps.push((*from, movie.clone(), current));
+            current = *from;
+        }
+        steps.reverse();
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    /// Kevin Bacon and Tom Hanks share Apollo 13; Tom Hanks and Meg Ryan share Sleepless in
+    /// Seattle.
+    async fn tiny_graph() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/search/person?query=Kevin+Bacon",
+            fixtures::single_page(vec![fixtures::person_summary(4724, "Kevin Bacon")]),
+        )
+        .json(
+            "/search/person?query=Meg+Ryan",
+            fixtures::single_page(vec![fixtures::person_summary(5344, "Meg Ryan")]),
+        )
+        .json(
+            "/person/4724/movie_credits",
+            fixtures::movie_credits(vec![fixtures::credit(
+                568,
+                "Apollo 13",
+                "1995-06-30",
+                "Jack",
+            )]),
+        )
+        .json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                fixtures::credit(568, "Apollo 13", "1995-06-30", "Jim"),
+                fixtures::credit(858, "Sleepless in Seattle", "1993-06-24", "Sam"),
+            ]),
+        )
+        .json(
+            "/person/5344/movie_credits",
+            fixtures::movie_credits(vec![fixtures::credit(
+                858,
+                "Sleepless in Seattle",
+                "1993-06-24",
+                "Annie",
+            )]),
+        )
+        .json(
+            "/movie/568/credits",
+            fixtures::credits(
+                568,
+                vec![
+                    fixtures::cast_member(31, "Tom Hanks", "Jim Lovell", 0),
+                    fixtures::cast_member(4724, "Kevin Bacon", "Jack Swigert", 1),
+                ],
+                vec![],
+            ),
+        );
+        mock
+    }
+
+    fn tool(max_depth: Option<u32>) -> DegreesOfSeparation {
+        DegreesOfSeparation {
+            actor_name_a: "Kevin Bacon".to_string(),
+            actor_name_b: "Meg Ryan".to_string(),
+            max_depth,
+        }
+    }
+
+    #[tokio::test]
+    async fn finds_the_path_of_movies_linking_two_actors() {
+        let mock = tiny_graph().await;
+
+        let result = tool(None).invoke(&mock.client()).await.unwrap();
+
+        let text = result_text(&result);
+        assert!(
+            text.starts_with("Kevin Bacon and Meg Ryan are connected through 2 movies:\n"),
+            "{text}"
+        );
+        assert!(text.contains("1. Kevin Bacon and Tom Hanks in Apollo 13 (1995)"));
+        assert!(text.contains("2. Tom Hanks and Meg Ryan in Sleepless in Seattle (1993)"));
+    }
+
+    #[tokio::test]
+    async fn reports_no_connection_within_the_depth() {
+        let mock = tiny_graph().await;
+
+        let result = tool(Some(1)).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "No connection between Kevin Bacon and Meg Ryan within depth 1"
+        );
+        assert_eq!(mock.hits("/movie/568/credits"), 0);
+    }
+
+    #[tokio::test]
+    async fn a_depth_above_the_cap_is_rejected_without_requests() {
+        let mock = tiny_graph().await;
+
+        let result = tool(Some(MAX_DEPTH + 1))
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(result_text(&result), "`max_depth` must be at most 3");
+        assert!(mock.requests().is_empty());
+    }
+}
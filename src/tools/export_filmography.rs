@@ -0,0 +1,137 @@
+use crate::{
+    mcp_handler::Exports,
+    tmdb_client::{GenreNames, MovieCredit, TmdbClient},
+    tools::{format::compare_names, unique_movies},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult, ContentBlock, Resource, ResourceLink},
+};
+use serde_json::{Value, json};
+
+#[mcp_tool(
+    name = "export_filmography",
+    title = "Export Filmography as a JSON Resource",
+    description = concat!(
+        "Export an actor's filmography, by TMDB actor ID, as a JSON document and publish it as an ",
+        "MCP resource at `tmdb://export/actor/{id}`. Returns the URI; read the document with the ",
+        "resources API. Exporting the same actor again refreshes the document.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct ExportFilmography {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl ExportFilmography {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+        exports: &Exports,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let language = &tmdb_client.config().language;
+        let (actor, credits, genres) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.person_movie_credits(self.actor_id),
+            tmdb_client.genre_names(),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor) = actor else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // one entry per movie,
+        // in release order with undated movies last, then by title
+        let mut credits = unique_movies(credits);
+        credits.sort_by(|a, b| {
+            let (a, b) = (&a.movie, &b.movie);
+            (a.release_date.is_empty(), &a.release_date)
+                .cmp(&(b.release_date.is_empty(), &b.release_date))
+                .then_with(|| compare_names(&a.title, &b.title))
+                .then(a.id.cmp(&b.id))
+        });
+
+        let document = json!({
+            "actor": { "id": actor.id, "name": actor.name },
+            "movies": credits
+                .iter()
+                .map(|credit| movie_entry(credit, &genres))
+                .collect::<Vec<_>>(),
+        });
+        let text = serde_json::to_string_pretty(&document)
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let uri = format!("tmdb://export/actor/{}", actor.id);
+        let name = format!("{}-filmography.json", actor.id);
+        let title = format!("{}'s filmography", actor.name);
+        let description = format!(
+            "{} movies with release dates, characters, ratings and genres, exported from TMDB",
+            credits.len()
+        );
+        let size = text.len() as i64;
+        exports.publish(
+            Resource {
+                annotations: None,
+                description: Some(description.clone()),
+                icons: vec![],
+                meta: None,
+                mime_type: Some("application/json".into()),
+                name: name.clone(),
+                size: Some(size),
+                title: Some(title.clone()),
+                uri: uri.clone(),
+            },
+            text,
+        );
+
+        let summary = format!(
+            "Exported {}'s filmography ({} movies) as JSON to {uri}. Read it with the resources API.",
+            actor.name,
+            credits.len()
+        );
+        Ok(CallToolResult::from_content(vec![
+            ContentBlock::text_content(summary),
+            ContentBlock::resource_link(ResourceLink::new(
+                vec![],
+                name,
+                uri,
+                None,
+                Some(description),
+                None,
+                Some("application/json".into()),
+                Some(size),
+                Some(title),
+            )),
+        ]))
+    }
+}
+
+/// Builds the document entry for one movie, with genre names rather than IDs.
+fn movie_entry(credit: &MovieCredit, genre_names: &GenreNames) -> Value {
+    let movie = &credit.movie;
+    json!({
+        "id": movie.id,
+        "title": movie.title,
+        "release_date": (!movie.release_date.is_empty()).then_some(&movie.release_date),
+        "character": (!credit.character.is_empty()).then_some(&credit.character),
+        "vote_average": movie.vote_average,
+        "vote_count": movie.vote_count,
+        "genres": movie
+            .genre_ids
+            .iter()
+            .map(|id| genre_names.name(*id))
+            .collect::<Vec<_>>(),
+    })
+}
@@ -0,0 +1,68 @@
+use crate::tmdb_client::TmdbClient;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Well-known actors with stable TMDB IDs, used as known-good inputs for demos.
+const FEATURED_ACTORS: &[(i64, &str)] = &[
+    (16483, "Sylvester Stallone"),
+    (31, "Tom Hanks"),
+    (5064, "Meryl Streep"),
+    (5292, "Denzel Washington"),
+    (6384, "Keanu Reeves"),
+    (1245, "Scarlett Johansson"),
+];
+
+#[mcp_tool(
+    name = "featured_actors",
+    title = "Featured Actors",
+    description = concat!(
+        "List a few well-known actors along with their TMDB IDs. ",
+        "Use these as example inputs for `get_actor_info` (by name) or `get_movies_by_actor` (by ID).",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct FeaturedActors {}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl FeaturedActors {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        _tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // the list is bundled with the server, so no api call is needed
+        let result = FEATURED_ACTORS
+            .iter()
+            .map(|(id, name)| format!("{name} - TMDB ID: {id}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::MockTmdb;
+    use crate::tools::result_text;
+
+    #[tokio::test]
+    async fn lists_the_bundled_actors_without_calling_tmdb() {
+        let mock = MockTmdb::start().await;
+
+        let result = FeaturedActors {}.invoke(&mock.client()).await.unwrap();
+
+        let text = result_text(&result);
+        assert_eq!(text.lines().count(), FEATURED_ACTORS.len());
+        assert_eq!(text.lines().nth(1), Some("Tom Hanks - TMDB ID: 31"));
+        assert!(mock.requests().is_empty());
+    }
+}
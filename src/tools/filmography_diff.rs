@@ -0,0 +1,283 @@
+use crate::{tmdb_client::TmdbClient, tools::unique_movies};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use std::{fs, io};
+use time::OffsetDateTime;
+
+#[mcp_tool(
+    name = "filmography_diff",
+    title = "Filmography Changes",
+    description = concat!(
+        "Report the films added to an actor's TMDB filmography since this tool last looked, by TMDB actor ID. ",
+        "The first call for an actor only saves the current filmography as a baseline; ",
+        "each later call lists what was added since the previous call and saves the new list.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct FilmographyDiff {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+/// An actor's filmography as last seen, stored as JSON in the snapshot directory.
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    /// UTC date the snapshot was taken, e.g. `2024-05-01`
+    taken_on: String,
+    films: Vec<SnapshotFilm>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotFilm {
+    id: i64,
+    /// Title and year, e.g. `Cast Away (2000)`
+    label: String,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl FilmographyDiff {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let language = &tmdb_client.config().language;
+        let (actor, credits) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.person_movie_credits(self.actor_id),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor) = actor else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let current = Snapshot {
+            taken_on: OffsetDateTime::now_utc().date().to_string(),
+            films: unique_movies(&credits)
+                .into_iter()
+                .map(|credit| SnapshotFilm {
+                    id: credit.movie.id,
+                    label: credit.movie.to_string().trim_end().to_string(),
+                })
+                .collect(),
+        };
+
+        let snapshot_dir = &tmdb_client.config().snapshot_dir;
+        let path = snapshot_dir.join(format!("filmography-{}.json", self.actor_id));
+        // snapshots in a directory someone else controls can't be trusted, nor kept there
+        let private_dir = prepare_snapshot_dir(snapshot_dir);
+        let previous = match &private_dir {
+            Ok(()) => read_snapshot(&path),
+            Err(_) => None,
+        };
+
+        let mut result = match &previous {
+            None => format!(
+                "Saved {}'s current filmography ({} films) as a baseline; call again later to see films added since",
+                actor.name,
+                current.films.len()
+            ),
+            Some(previous) => {
+                let added = added_films(previous, &current);
+                if added.is_empty() {
+                    format!(
+                        "No films were added to {}'s filmography since {}",
+                        actor.name, previous.taken_on
+                    )
+                } else {
+                    let lines = added
+                        .iter()
+                        .map(|film| format!("- {} - TMDB ID: {}", film.label, film.id))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    format!(
+                        "{} film(s) added to {}'s filmography since {}:\n{lines}",
+                        added.len(),
+                        actor.name,
+                        previous.taken_on
+                    )
+                }
+            }
+        };
+
+        // the diff is still worth returning if the new snapshot can't be saved
+        if let Err(err) = private_dir.and_then(|()| write_snapshot(&path, &current)) {
+            eprintln!("could not save snapshot {}: {err}", path.display());
+            result.push_str(&format!(
+                "\n\nThe filmography could not be saved ({err}), so the next call will compare against the same earlier list"
+            ));
+        }
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+/// Films in `current` that were not in `previous`, in `current`'s order.
+fn added_films<'a>(previous: &Snapshot, current: &'a Snapshot) -> Vec<&'a SnapshotFilm> {
+    let known: HashSet<i64> = previous.films.iter().map(|film| film.id).collect();
+    current
+        .films
+        .iter()
+        .filter(|film| !known.contains(&film.id))
+        .collect()
+}
+
+/// Reads a saved snapshot, or returns None if there is none yet. An unreadable snapshot is
+/// logged and treated as missing, so the next call starts a fresh baseline.
+fn read_snapshot(path: &Path) -> Option<Snapshot> {
+    let contents = fs::read(path).ok()?;
+    match serde_json::from_slice(&contents) {
+        Ok(snapshot) => Some(snapshot),
+        Err(err) => {
+            eprintln!("ignoring unreadable snapshot {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+/// Creates the snapshot directory readable by its owner only, or checks that an existing one
+/// belongs to this user, so nobody else on the machine can plant or read snapshots there.
+#[cfg(unix)]
+fn prepare_snapshot_dir(dir: &Path) -> io::Result<()> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(dir)?;
+    // SAFETY: geteuid has no preconditions and cannot fail
+    let user = unsafe { libc::geteuid() };
+    if fs::metadata(dir)?.uid() != user {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} belongs to another user", dir.display()),
+        ));
+    }
+    Ok(())
+}
+
+/// Creates the snapshot directory. Outside Unix it is in the user's own profile by default.
+#[cfg(not(unix))]
+fn prepare_snapshot_dir(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)
+}
+
+/// Saves a snapshot, writing a temporary file first so a crash never leaves a partial one.
+fn write_snapshot(path: &Path, snapshot: &Snapshot) -> io::Result<()> {
+    let temporary = path.with_extension("json.tmp");
+    fs::write(&temporary, serde_json::to_vec(snapshot)?)?;
+    fs::rename(&temporary, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use std::path::PathBuf;
+
+    /// A snapshot directory that doesn't exist yet, unique to this test run.
+    fn fresh_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    async fn tom_hanks() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json(
+                "/person/31/movie_credits",
+                fixtures::movie_credits(vec![fixtures::credit(
+                    13,
+                    "Forrest Gump",
+                    "1994-06-23",
+                    "Forrest",
+                )]),
+            )
+            .json(
+                "/person/31/movie_credits",
+                fixtures::movie_credits(vec![
+                    fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest"),
+                    fixtures::credit(8358, "Cast Away", "2000-12-22", "Chuck Noland"),
+                ]),
+            );
+        mock
+    }
+
+    #[tokio::test]
+    async fn reports_films_added_since_the_baseline() {
+        let mock = tom_hanks().await;
+        let dir = fresh_dir("filmography-diff-added").join("snapshots");
+        let client = mock.client_with(|config| config.snapshot_dir = dir.clone());
+        let tool = FilmographyDiff { actor_id: 31 };
+
+        let first = tool.invoke(&client).await.unwrap();
+        let second = tool.invoke(&client).await.unwrap();
+
+        assert!(result_text(&first).starts_with("Saved Tom Hanks's current filmography (1 films)"));
+        let today = OffsetDateTime::now_utc().date();
+        assert_eq!(
+            result_text(&second),
+            format!(
+                "1 film(s) added to Tom Hanks's filmography since {today}:\n\
+                 - Cast Away (2000) - TMDB ID: 8358"
+            )
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&dir).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+        let _ = fs::remove_dir_all(dir.parent().unwrap());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn a_directory_owned_by_another_user_is_refused() {
+        // root can hand a directory to another user; anyone else finds one owned by root
+        let dir = if unsafe { libc::geteuid() } == 0 {
+            let dir = fresh_dir("filmography-diff-foreign");
+            fs::create_dir(&dir).unwrap();
+            std::os::unix::fs::chown(&dir, Some(65534), Some(65534)).unwrap();
+            dir
+        } else {
+            PathBuf::from("/")
+        };
+        let mock = tom_hanks().await;
+        let client = mock.client_with(|config| config.snapshot_dir = dir.clone());
+
+        let result = FilmographyDiff { actor_id: 31 }
+            .invoke(&client)
+            .await
+            .unwrap();
+
+        assert!(
+            result_text(&result).contains(&format!(
+                "The filmography could not be saved ({} belongs to another user)",
+                dir.display()
+            )),
+            "{}",
+            result_text(&result)
+        );
+        assert!(!dir.join("filmography-31.json").exists());
+        if dir != Path::new("/") {
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+}
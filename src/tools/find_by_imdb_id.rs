@@ -0,0 +1,150 @@
+use crate::tmdb_client::TmdbClient;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "find_by_imdb_id",
+    title = "Find by IMDb ID",
+    description = concat!(
+        "Look up a movie or person on TMDB using their IMDb ID. ",
+        "Movie IDs start with `tt` (e.g. tt0075148) and person IDs start with `nm` (e.g. nm0000230). ",
+        "Returns the matching TMDB ID, which can be used with the other tools.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct FindByImdbId {
+    /// The IMDb ID to look up: `tt` followed by digits for a title, `nm` followed by digits for a person.
+    #[json_schema(min_length = 9)]
+    pub imdb_id: String,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl FindByImdbId {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let imdb_id = self.imdb_id.trim();
+
+        // reject malformed ids before making a request
+        if !is_valid_imdb_id(imdb_id) {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!(
+                    "\"{imdb_id}\" is not a valid IMDb ID. Expected `tt` or `nm` followed by digits, e.g. tt0075148"
+                ),
+            )));
+        }
+
+        let results = tmdb_client
+            .find_by_imdb(imdb_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // list every match along with its TMDB id
+        let lines = results
+            .movie_results
+            .iter()
+            .map(|movie| {
+                format!(
+                    "Movie: {} - TMDB ID: {}",
+                    movie.to_string().trim(),
+                    movie.id
+                )
+            })
+            .chain(
+                results
+                    .person_results
+                    .iter()
+                    .map(|person| format!("Person: {} - TMDB ID: {}", person.name, person.id)),
+            )
+            .collect::<Vec<_>>();
+
+        if lines.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("Nothing on TMDB matches the IMDb ID \"{imdb_id}\""),
+            )));
+        }
+
+        Ok(CallToolResult::text_content(vec![lines.join("\n").into()]))
+    }
+}
+
+/// Returns true if `imdb_id` looks like an IMDb title (`tt`) or name (`nm`) ID.
+fn is_valid_imdb_id(imdb_id: &str) -> bool {
+    let digits = imdb_id
+        .strip_prefix("tt")
+        .or_else(|| imdb_id.strip_prefix("nm"));
+
+    matches!(digits, Some(digits) if digits.len() >= 7 && digits.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    fn tool(imdb_id: &str) -> FindByImdbId {
+        FindByImdbId {
+            imdb_id: imdb_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn recognizes_title_and_name_ids() {
+        assert!(is_valid_imdb_id("tt0075148"));
+        assert!(is_valid_imdb_id("nm0000230"));
+        assert!(!is_valid_imdb_id("tt123"));
+        assert!(!is_valid_imdb_id("ch0000001"));
+        assert!(!is_valid_imdb_id("nm00002x0"));
+    }
+
+    #[tokio::test]
+    async fn lists_the_matching_movie_with_its_tmdb_id() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/find/tt0075148?external_source=imdb_id",
+            json!({
+                "movie_results": [fixtures::movie(1366, "Rocky", "1976-11-21")],
+                "person_results": []
+            }),
+        );
+
+        let result = tool(" tt0075148 ").invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(result_text(&result), "Movie: Rocky (1976) - TMDB ID: 1366");
+    }
+
+    #[tokio::test]
+    async fn malformed_ids_are_rejected_without_a_request() {
+        let mock = MockTmdb::start().await;
+
+        let result = tool("tt12").invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unknown_ids_are_reported() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/find/nm9999999",
+            json!({ "movie_results": [], "person_results": [] }),
+        );
+
+        let result = tool("nm9999999").invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("Nothing on TMDB matches"));
+    }
+}
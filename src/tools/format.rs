@@ -0,0 +1,223 @@
+//! Locale-aware formatting of dates and numbers, and ordering of names, in tool output.
+//!
+//! The locale is the configured TMDB language (`TMDB_LANGUAGE`, e.g. `en-US` or `en-GB`).
+
+use std::cmp::Ordering;
+
+/// English month names, January first.
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Languages that write decimals with a comma, e.g. `7,5`.
+const DECIMAL_COMMA_LANGUAGES: &[&str] = &[
+    "cs", "da", "de", "es", "fi", "fr", "it", "nb", "nl", "pl", "pt", "ru", "sv", "tr",
+];
+
+/// Formats a TMDB `YYYY-MM-DD` date for display in the given locale:
+/// `December 25, 1990` for `en-US`, `25 December 1990` for other English locales,
+/// and the unambiguous `1990-12-25` otherwise.
+///
+/// Empty dates become `Unknown`; dates that don't parse are returned unchanged.
+pub fn format_date(date: &str, language: &str) -> String {
+    let date = date.trim();
+    if date.is_empty() {
+        return "Unknown".to_string();
+    }
+    let Some((year, month, day)) = parse_date(date) else {
+        return date.to_string();
+    };
+
+    let (code, region) = split_locale(language);
+    let month_name = MONTHS[usize::from(month) - 1];
+    match (code, region) {
+        ("en", Some("US")) => format!("{month_name} {day}, {year}"),
+        ("en", _) => format!("{day} {month_name} {year}"),
+        _ => date.to_string(),
+    }
+}
+
+/// Formats a dollar amount with thousands separators, e.g. `$1,200,000`.
+/// TMDB uses 0 for unknown amounts.
+pub fn format_usd(amount: i64) -> String {
+    if amount <= 0 {
+        return "Unknown".to_string();
+    }
+    let digits = amount.to_string();
+    let mut result = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            result.push(',');
+        }
+        result.push(digit);
+    }
+    format!("${result}")
+}
+
+/// Formats a rating (TMDB's 0-10 vote average) to one decimal place, using the locale's
+/// decimal separator, e.g. `7.5` for `en-US` or `7,5` for `fr-FR`.
+pub fn format_rating(rating: f64, language: &str) -> String {
+    let formatted = format!("{rating:.1}");
+    let (code, _) = split_locale(language);
+    if DECIMAL_COMMA_LANGUAGES.contains(&code) {
+        formatted.replace('.', ",")
+    } else {
+        formatted
+    }
+}
+
+/// Orders names and titles alphabetically the way a reader expects: ignoring case and
+/// accents, so `Ángel` sorts with the A's and `Zoë` after `Zoe` rather than after every
+/// unaccented name. Names that fold to the same letters fall back to byte order, so the
+/// ordering stays total and stable.
+pub fn compare_names(a: &str, b: &str) -> Ordering {
+    fold_name(a).cmp(&fold_name(b)).then_with(|| a.cmp(b))
+}
+
+/// Lowercases a name and strips the accents of Latin letters, spelling out ligatures
+/// (`ß` becomes `ss`, `Æ` becomes `ae`). Accents typed as combining marks (`e` followed by
+/// U+0301) are dropped too, so both spellings of `é` fold alike. Other scripts are kept as
+/// they are.
+fn fold_name(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        let base = match c {
+            // combining diacritical marks
+            '\u{0300}'..='\u{036f}' => continue,
+            'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+            'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+            'ď' | 'đ' | 'ð' => 'd',
+            'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+            'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+            'ĥ' | 'ħ' => 'h',
+            'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+            'ĵ' => 'j',
+            'ķ' => 'k',
+            'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+            'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+            'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+            'ŕ' | 'ŗ' | 'ř' => 'r',
+            'ś' | 'ŝ' | 'ş' | 'š' => 's',
+            'ţ' | 'ť' | 'ŧ' => 't',
+            'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+            'ŵ' => 'w',
+            'ý' | 'ÿ' | 'ŷ' => 'y',
+            'ź' | 'ż' | 'ž' => 'z',
+            'æ' => {
+                folded.push_str("ae");
+                continue;
+            }
+            'œ' => {
+                folded.push_str("oe");
+                continue;
+            }
+            'ß' => {
+                folded.push_str("ss");
+                continue;
+            }
+            'þ' => {
+                folded.push_str("th");
+                continue;
+            }
+            other => other,
+        };
+        folded.push(base);
+    }
+    folded
+}
+
+/// Parses `YYYY-MM-DD` into its parts, returning None if it is not a valid calendar-ish date.
+fn parse_date(date: &str) -> Option<(u16, u8, u8)> {
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month: u8 = parts.next()?.parse().ok()?;
+    let day: u8 = parts.next()?.parse().ok()?;
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some((year, month, day))
+}
+
+/// Splits a locale such as `en-US` into its language and optional region.
+fn split_locale(language: &str) -> (&str, Option<&str>) {
+    match language.split_once('-') {
+        Some((code, region)) => (code, Some(region)),
+        None => (language, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_follow_the_us_and_uk_conventions() {
+        assert_eq!(format_date("1990-12-25", "en-US"), "December 25, 1990");
+        assert_eq!(format_date("1990-12-25", "en-GB"), "25 December 1990");
+        assert_eq!(format_date("1990-12-25", "en"), "25 December 1990");
+    }
+
+    #[test]
+    fn other_locales_and_odd_dates_keep_the_iso_form() {
+        assert_eq!(format_date("1990-12-25", "de-DE"), "1990-12-25");
+        assert_eq!(format_date(" ", "en-US"), "Unknown");
+        assert_eq!(format_date("1990-13-01", "en-US"), "1990-13-01");
+        assert_eq!(format_date("1990", "en-US"), "1990");
+    }
+
+    #[test]
+    fn accented_names_sort_with_their_base_letters() {
+        let mut names = vec![
+            "Zoë Kravitz",
+            "Zoe Saldaña",
+            "Ángela Molina",
+            "Bérénice Bejo",
+            "Be\u{301}atrice Dalle",
+            "Benicio del Toro",
+            "alan Arkin",
+            "Łukasz Simlat",
+            "Æon Flux",
+        ];
+        names.sort_by(|a, b| compare_names(a, b));
+
+        assert_eq!(
+            names,
+            [
+                "Æon Flux",
+                "alan Arkin",
+                "Ángela Molina",
+                "Be\u{301}atrice Dalle",
+                "Benicio del Toro",
+                "Bérénice Bejo",
+                "Łukasz Simlat",
+                // the diaeresis is ignored, so Kravitz comes before Saldaña
+                "Zoë Kravitz",
+                "Zoe Saldaña",
+            ]
+        );
+    }
+
+    #[test]
+    fn precomposed_and_decomposed_accents_fold_alike() {
+        assert_eq!(fold_name("Zoe\u{308}"), "zoe");
+        assert_eq!(fold_name("Zoë"), fold_name("Zoe\u{308}"));
+        assert_eq!(fold_name("E\u{301}DITH"), "edith");
+        // equal folds still order consistently
+        assert_ne!(compare_names("Zoë", "Zoe\u{308}"), Ordering::Equal);
+    }
+
+    #[test]
+    fn ratings_have_one_decimal_in_the_locale_separator() {
+        assert_eq!(format_rating(7.46, "en-US"), "7.5");
+        assert_eq!(format_rating(7.46, "fr-FR"), "7,5");
+        assert_eq!(format_rating(8.0, "en-GB"), "8.0");
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    tmdb_client::TmdbClient,
+    tools::{BatchFailures, by_count_then_name, unique_movies},
+};
+use futures::stream::{self, StreamExt};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::HashMap;
+
+/// Number of the actor's most popular movies whose directors are looked up.
+const MAX_MOVIES: usize = 20;
+
+/// Number of credit lists fetched at the same time.
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// Number of directors listed in the result.
+const MAX_DIRECTORS: usize = 5;
+
+#[mcp_tool(
+    name = "frequent_director",
+    title = "Get an Actor's Most Frequent Director",
+    description = concat!(
+        "Find the director an actor has worked with most often, by TMDB actor ID, followed by ",
+        "their other frequent directors. Looks at the directors of the actor's 20 most popular movies.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct FrequentDirector {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl FrequentDirector {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let movies = tmdb_client
+            .person_movie_credits(self.actor_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        if movies.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movies were found for actor ID {}", self.actor_id),
+            )));
+        }
+
+        // only inspect the most popular movies (each once) to keep the number of requests reasonable
+        let mut movies = unique_movies(movies);
+        movies.sort_by(|a, b| b.movie.popularity.total_cmp(&a.movie.popularity));
+        movies.truncate(MAX_MOVIES);
+
+        // fetch the credits a few at a time
+        let movie_ids: Vec<i64> = movies.iter().map(|credit| credit.movie.id).collect();
+        let credits = stream::iter(movie_ids)
+            .map(|movie_id| async move { (movie_id, tmdb_client.movie_credits(movie_id).await) })
+            .buffer_unordered(CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        // count the movies each director made with the actor, keyed by person id; a movie with
+        // co-directors counts for each of them
+        let mut tally: HashMap<i64, (String, u32)> = HashMap::new();
+        let mut failures = BatchFailures::default();
+        let mut without_director = 0;
+        // movies whose credits were fetched; TMDB may have none for a movie
+        let mut inspected = 0;
+        for (movie_id, credits) in credits {
+            let credits = match credits {
+                Ok(Some(credits)) => credits,
+                Ok(None) => continue,
+                Err(err) => {
+                    failures.push(format!("credits of movie {movie_id}"), err);
+                    continue;
+                }
+            };
+            inspected += 1;
+            let mut directors = credits.directors().peekable();
+            if directors.peek().is_none() {
+                without_director += 1;
+            }
+            for director in directors {
+                tally
+                    .entry(director.id)
+                    .or_insert((director.name.clone(), 0))
+                    .1 += 1;
+            }
+        }
+
+        if inspected == 0 {
+            let message = if failures.is_empty() {
+                format!(
+                    "TMDB has no credits for the movies of actor ID {}",
+                    self.actor_id
+                )
+            } else {
+                failures.to_string()
+            };
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                message,
+            )));
+        }
+
+        let mut directors: Vec<_> = tally.into_iter().collect();
+        directors.sort_by(|(_, (name_a, count_a)), (_, (name_b, count_b))| {
+            by_count_then_name((*count_a, name_a), (*count_b, name_b))
+        });
+        directors.truncate(MAX_DIRECTORS);
+
+        let mut result = match directors.first() {
+            None => format!("None of the {inspected} movies inspected lists a director"),
+            Some((id, (name, count))) => {
+                let list = directors
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (id, (name, count)))| {
+                        let films = if *count == 1 { "film" } else { "films" };
+                        format!("{}. {name} - {count} {films} (TMDB ID: {id})", index + 1)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!(
+                    "Most frequent director: {name} (TMDB ID: {id}), {count} of {inspected} movies\n\nDirectors across {inspected} movies:\n{list}"
+                )
+            }
+        };
+        if without_director > 0 && !directors.is_empty() {
+            result.push_str(&format!(
+                "\n({without_director} of the movies list no director)"
+            ));
+        }
+        if !failures.is_empty() {
+            result.push_str(&format!("\n\n{failures}"));
+        }
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    fn credits(movie_id: i64, directors: &[(i64, &str)]) -> serde_json::Value {
+        let crew = directors
+            .iter()
+            .map(|(id, name)| fixtures::crew_member(*id, name, "Director"))
+            .collect();
+        fixtures::credits(movie_id, vec![], crew)
+    }
+
+    #[tokio::test]
+    async fn tallies_directors_over_the_movies_with_credits() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest"),
+                fixtures::credit(8358, "Cast Away", "2000-12-22", "Chuck"),
+                fixtures::credit(2, "Codirected", "2001-01-01", ""),
+                fixtures::credit(3, "Undirected", "2002-01-01", ""),
+                // TMDB has no credits for this one
+                fixtures::credit(4, "Uncredited", "2003-01-01", ""),
+            ]),
+        )
+        .json("/movie/13/credits", credits(13, &[(24, "Robert Zemeckis")]))
+        .json(
+            "/movie/8358/credits",
+            credits(8358, &[(24, "Robert Zemeckis")]),
+        )
+        .json(
+            "/movie/2/credits",
+            credits(2, &[(24, "Robert Zemeckis"), (488, "Steven Spielberg")]),
+        )
+        .json("/movie/3/credits", credits(3, &[]));
+
+        let result = FrequentDirector { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Most frequent director: Robert Zemeckis (TMDB ID: 24), 3 of 4 movies\n\n\
+             Directors across 4 movies:\n\
+             1. Robert Zemeckis - 3 films (TMDB ID: 24)\n\
+             2. Steven Spielberg - 1 film (TMDB ID: 488)\n\
+             (1 of the movies list no director)"
+        );
+    }
+
+    #[tokio::test]
+    async fn movies_without_credits_are_not_reported_as_inspected() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![fixtures::credit(4, "Uncredited", "2003-01-01", "")]),
+        );
+
+        let result = FrequentDirector { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("TMDB has no credits for the movies of actor ID 31"));
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieCredit, TmdbClient},
+    tools::{format::format_rating, unique_movies},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Rating a film needs by default to count as acclaimed.
+const DEFAULT_MIN_RATING: f64 = 7.5;
+
+/// Votes a film needs by default, so a few enthusiastic votes don't make a film acclaimed.
+const DEFAULT_MIN_VOTE_COUNT: u32 = 1000;
+
+#[mcp_tool(
+    name = "get_acclaimed_roles",
+    title = "Get an Actor's Acclaimed Roles",
+    description = concat!(
+        "Get an actor's most critically regarded work by TMDB actor ID: every film in their ",
+        "filmography rated at least `min_rating` (default 7.5) by at least `min_vote_count` ",
+        "(default 1000) TMDB users, highest rated first. TMDB has no awards data, so this ",
+        "approximates acclaim from ratings.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetAcclaimedRoles {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+    /// Lowest TMDB rating (0-10) a film may have (default 7.5)
+    #[json_schema(minimum = 0)]
+    pub min_rating: Option<f64>,
+    /// Fewest votes a film may have (default 1000)
+    pub min_vote_count: Option<u32>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetAcclaimedRoles {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let min_rating = self.min_rating.unwrap_or(DEFAULT_MIN_RATING);
+        if min_rating > 10.0 {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                "`min_rating` must be at most 10",
+            )));
+        }
+        let min_vote_count = self.min_vote_count.unwrap_or(DEFAULT_MIN_VOTE_COUNT);
+
+        let language = &tmdb_client.config().language;
+        let (actor, credits) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.person_movie_credits(self.actor_id),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor) = actor else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let acclaimed = acclaimed_roles(&credits, min_rating, min_vote_count);
+        let thresholds = format!(
+            "rated at least {}/10 with {min_vote_count}+ votes",
+            format_rating(min_rating, language)
+        );
+        if acclaimed.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("None of {}'s films are {thresholds}", actor.name).into(),
+            ]));
+        }
+
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = acclaimed
+            .iter()
+            .enumerate()
+            .map(|(index, credit)| {
+                format!(
+                    "{}. {} - {}/10 ({} votes) - TMDB ID: {}",
+                    index + 1,
+                    credit.render(missing),
+                    format_rating(credit.movie.vote_average, language),
+                    credit.movie.vote_count,
+                    credit.movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!(
+                "{}'s most critically regarded work ({thresholds}):\n{result}",
+                actor.name
+            )
+            .into(),
+        ]))
+    }
+}
+
+/// Returns the credits meeting both thresholds, each movie once, highest rated first
+/// (ties broken by vote count).
+fn acclaimed_roles(
+    credits: &[MovieCredit],
+    min_rating: f64,
+    min_vote_count: u32,
+) -> Vec<&MovieCredit> {
+    let mut acclaimed: Vec<&MovieCredit> = unique_movies(credits)
+        .into_iter()
+        .filter(|credit| {
+            credit.movie.vote_average >= min_rating && credit.movie.vote_count >= min_vote_count
+        })
+        .collect();
+    acclaimed.sort_by(|a, b| {
+        b.movie
+            .vote_average
+            .total_cmp(&a.movie.vote_average)
+            .then(b.movie.vote_count.cmp(&a.movie.vote_count))
+    });
+    acclaimed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn rated_credit(id: i64, title: &str, vote_average: f64, vote_count: u32) -> Value {
+        let mut credit = fixtures::credit(id, title, "1994-06-23", "");
+        credit["vote_average"] = json!(vote_average);
+        credit["vote_count"] = json!(vote_count);
+        credit
+    }
+
+    async fn tom_hanks() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json(
+                "/person/31/movie_credits",
+                fixtures::movie_credits(vec![
+                    rated_credit(13, "Forrest Gump", 8.5, 27000),
+                    rated_credit(99999, "Obscure Short", 9.9, 12),
+                    rated_credit(2280, "Big", 7.0, 4000),
+                    rated_credit(857, "Saving Private Ryan", 8.2, 15000),
+                    rated_credit(8358, "Cast Away", 7.5, 1000),
+                ]),
+            );
+        mock
+    }
+
+    fn tool(min_rating: Option<f64>, min_vote_count: Option<u32>) -> GetAcclaimedRoles {
+        GetAcclaimedRoles {
+            actor_id: 31,
+            min_rating,
+            min_vote_count,
+        }
+    }
+
+    #[tokio::test]
+    async fn only_films_meeting_both_thresholds_are_listed() {
+        let mock = tom_hanks().await;
+
+        let result = tool(None, None).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks's most critically regarded work (rated at least 7.5/10 with 1000+ votes):\n\
+             1. Forrest Gump (1994) - 8.5/10 (27000 votes) - TMDB ID: 13\n\
+             2. Saving Private Ryan (1994) - 8.2/10 (15000 votes) - TMDB ID: 857\n\
+             3. Cast Away (1994) - 7.5/10 (1000 votes) - TMDB ID: 8358"
+        );
+    }
+
+    #[tokio::test]
+    async fn the_thresholds_can_be_changed() {
+        let mock = tom_hanks().await;
+
+        let result = tool(Some(9.0), Some(10))
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks's most critically regarded work (rated at least 9.0/10 with 10+ votes):\n\
+             1. Obscure Short (1994) - 9.9/10 (12 votes) - TMDB ID: 99999"
+        );
+
+        let result = tool(Some(8.0), Some(20000))
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+        assert!(result_text(&result).contains("1. Forrest Gump"));
+        assert!(!result_text(&result).contains("Saving Private Ryan"));
+
+        let result = tool(Some(9.5), Some(1000))
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+        assert_eq!(
+            result_text(&result),
+            "None of Tom Hanks's films are rated at least 9.5/10 with 1000+ votes"
+        );
+    }
+
+    #[tokio::test]
+    async fn a_rating_above_ten_is_rejected_before_any_request() {
+        let mock = tom_hanks().await;
+
+        let result = tool(Some(11.0), None).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(mock.requests().is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieCredit, TmdbClient},
+    tools::{find_genre, unique_movies, unknown_genre},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "get_actor_films_by_genre",
+    title = "Get an Actor's Films in a Genre",
+    description = concat!(
+        "List the films in an actor's filmography that belong to a genre, newest first, ",
+        "e.g. \"what horror movies has this actor been in\". ",
+        "Specify the actor by TMDB actor ID and the `genre` by name, such as \"Horror\" or \"Comedy\".",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetActorFilmsByGenre {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+    /// Genre name, e.g. "Horror" (case-insensitive)
+    #[json_schema(min_length = 1)]
+    pub genre: String,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetActorFilmsByGenre {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let genres = tmdb_client
+            .movie_genres()
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(genre) = find_genre(&genres, &self.genre) else {
+            return Ok(CallToolResult::with_error(unknown_genre(
+                &genres,
+                &self.genre,
+            )));
+        };
+
+        let language = &tmdb_client.config().language;
+        let (actor, credits) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.person_movie_credits(self.actor_id),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor) = actor else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let films = films_in_genre(&credits, genre.id);
+        if films.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("{} has no {} films on TMDB", actor.name, genre.name).into(),
+            ]));
+        }
+
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = films
+            .iter()
+            .enumerate()
+            .map(|(index, credit)| {
+                format!(
+                    "{}. {} - TMDB ID: {}",
+                    index + 1,
+                    credit.render(missing),
+                    credit.movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!("{}'s {} films:\n{result}", actor.name, genre.name).into(),
+        ]))
+    }
+}
+
+/// Returns the credits whose movie is tagged with `genre_id`, each movie once, newest first
+/// (undated films last).
+fn films_in_genre(credits: &[MovieCredit], genre_id: u32) -> Vec<&MovieCredit> {
+    let mut films: Vec<&MovieCredit> = unique_movies(credits)
+        .into_iter()
+        .filter(|credit| credit.movie.genre_ids.contains(&genre_id))
+        .collect();
+    films.sort_by(|a, b| {
+        let (a, b) = (&a.movie.release_date, &b.movie.release_date);
+        a.is_empty().cmp(&b.is_empty()).then_with(|| b.cmp(a))
+    });
+    films
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn credit_in(genre_ids: &[u32], id: i64, title: &str, release_date: &str) -> Value {
+        let mut credit = fixtures::credit(id, title, release_date, "");
+        credit["genre_ids"] = json!(genre_ids);
+        credit
+    }
+
+    /// Serves the genre list and an actor whose filmography mixes action, comedy and drama.
+    fn mock_mixed_filmography(mock: &MockTmdb) {
+        mock.json("/genre/movie/list", fixtures::genres())
+            .json(
+                "/person/16483",
+                fixtures::person(16483, "Sylvester Stallone"),
+            )
+            .json(
+                "/person/16483/movie_credits",
+                fixtures::movie_credits(vec![
+                    credit_in(&[18], 1366, "Rocky", "1976-11-21"),
+                    credit_in(&[28, 12], 1368, "First Blood", "1982-10-22"),
+                    credit_in(&[35, 28], 9735, "Tango & Cash", "1989-12-22"),
+                    credit_in(&[35], 11866, "Stop! Or My Mom Will Shoot", "1992-02-21"),
+                    credit_in(&[28], 27578, "The Expendables", "2010-08-03"),
+                    // a second role in the same movie is listed once
+                    credit_in(&[28], 27578, "The Expendables", "2010-08-03"),
+                ]),
+            );
+    }
+
+    async fn films_by_genre(mock: &MockTmdb, genre: &str) -> CallToolResult {
+        GetActorFilmsByGenre {
+            actor_id: 16483,
+            genre: genre.to_string(),
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn a_mixed_filmography_is_filtered_to_one_genre() {
+        let mock = MockTmdb::start().await;
+        mock_mixed_filmography(&mock);
+
+        let result = films_by_genre(&mock, "action").await;
+
+        assert_eq!(
+            result_text(&result),
+            "Sylvester Stallone's Action films:\n\
+             1. The Expendables (2010) - TMDB ID: 27578\n\
+             2. Tango & Cash (1989) - TMDB ID: 9735\n\
+             3. First Blood (1982) - TMDB ID: 1368"
+        );
+    }
+
+    #[tokio::test]
+    async fn a_genre_without_films_says_so() {
+        let mock = MockTmdb::start().await;
+        mock_mixed_filmography(&mock);
+
+        let result = films_by_genre(&mock, "Science Fiction").await;
+
+        assert_eq!(result.is_error, None);
+        assert_eq!(
+            result_text(&result),
+            "Sylvester Stallone has no Science Fiction films on TMDB"
+        );
+    }
+
+    #[tokio::test]
+    async fn an_unknown_genre_lists_the_valid_ones() {
+        let mock = MockTmdb::start().await;
+        mock_mixed_filmography(&mock);
+
+        let result = films_by_genre(&mock, "Horror").await;
+
+        assert_eq!(result.is_error, Some(true));
+        let text = result_text(&result);
+        assert!(text.contains("Unknown genre \"Horror\""));
+        assert!(text.contains("Action"));
+        assert_eq!(mock.hits("/person/16483/movie_credits"), 0);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::tmdb_client::TmdbClient;
+use futures::future::join_all;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult, ContentBlock},
+};
+
+/// Number of posters returned per call.
+const MAX_POSTERS: usize = 4;
+
+#[mcp_tool(
+    name = "get_actor_highlights",
+    title = "Get Actor Highlights",
+    description = concat!(
+        "Get a visual snapshot of an actor's career: a short intro plus the posters of the titles ",
+        "they are best known for, returned inline. ",
+        "Specify either `actor_id` or `actor_name` (but not both). With an ID, the actor's most ",
+        "popular movies are used instead, as TMDB only lists best-known titles in name searches.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetActorHighlights {
+    /// TMDB ID of the actor. Provide either this or `actor_name`.
+    #[json_schema(minimum = 1)]
+    pub actor_id: Option<i64>,
+    /// Name of the actor. Provide either this or `actor_id`.
+    #[json_schema(min_length = 1)]
+    pub actor_name: Option<String>,
+}
+
+/// A title shown in the highlights, e.g. `Forrest Gump (1994)`, with its poster if it has one.
+struct Highlight {
+    label: String,
+    poster_path: Option<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetActorHighlights {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let (name, department, highlights): (String, String, Vec<Highlight>) =
+            match (self.actor_id, self.actor_name.as_deref()) {
+                (None, Some(actor_name)) => {
+                    let profile = tmdb_client
+                        .actor_info(actor_name, None)
+                        .await
+                        .map_err(|err| CallToolError::from_message(err.to_string()))?;
+                    let Some(profile) = profile else {
+                        return Ok(CallToolResult::with_error(CallToolError::from_message(
+                            format!("No actors matching the name \"{actor_name}\" were found"),
+                        )));
+                    };
+                    let highlights = profile
+                        .known_for
+                        .into_iter()
+                        .map(|title| Highlight {
+                            label: with_year(
+                                title.display_title(),
+                                title
+                                    .release_date
+                                    .as_deref()
+                                    .or(title.first_air_date.as_deref()),
+                            ),
+                            poster_path: title.poster_path,
+                        })
+                        .collect();
+                    (
+                        profile.details.name,
+                        profile.details.known_for_department,
+                        highlights,
+                    )
+                }
+                (Some(actor_id), None) => {
+                    let language = &tmdb_client.config().language;
+                    let (actor, movies) = tokio::join!(
+                        tmdb_client.person_details(actor_id, language),
+                        tmdb_client.person_movie_credits(actor_id),
+                    );
+                    let actor =
+                        actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+                    let Some(actor) = actor else {
+                        return Ok(CallToolResult::with_error(CallToolError::from_message(
+                            format!("No actor with the TMDB ID {actor_id} was found"),
+                        )));
+                    };
+                    let mut movies =
+                        movies.map_err(|err| CallToolError::from_message(err.to_string()))?;
+                    movies.sort_by(|a, b| b.movie.popularity.total_cmp(&a.movie.popularity));
+                    let highlights = movies
+                        .into_iter()
+                        .take(MAX_POSTERS)
+                        .map(|credit| Highlight {
+                            label: with_year(&credit.movie.title, Some(&credit.movie.release_date)),
+                            poster_path: credit.movie.poster_path,
+                        })
+                        .collect();
+                    (actor.name, actor.known_for_department, highlights)
+                }
+                _ => {
+                    return Ok(CallToolResult::with_error(CallToolError::from_message(
+                        "Provide exactly one of `actor_id` or `actor_name`",
+                    )));
+                }
+            };
+
+        if highlights.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("No highlights are listed for {name} on TMDB").into(),
+            ]));
+        }
+
+        let titles = highlights
+            .iter()
+            .map(|highlight| highlight.label.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let intro = if department.is_empty() {
+            format!("{name} is best known for {titles}.")
+        } else {
+            format!("{name} ({department}) is best known for {titles}.")
+        };
+
+        // fetch the posters concurrently, keeping the titles in order
+        let with_posters: Vec<(&str, &str)> = highlights
+            .iter()
+            .filter_map(|highlight| {
+                Some((highlight.label.as_str(), highlight.poster_path.as_deref()?))
+            })
+            .take(MAX_POSTERS)
+            .collect();
+        let posters = join_all(
+            with_posters
+                .iter()
+                .map(|(_, poster_path)| tmdb_client.image_as_base64(poster_path)),
+        )
+        .await;
+
+        let mut content = vec![ContentBlock::text_content(intro)];
+        for ((label, _), poster) in with_posters.iter().zip(posters) {
+            match poster {
+                Ok(image_data) => {
+                    content.push(ContentBlock::image_content(image_data, "image/jpeg".into()))
+                }
+                Err(err) => content.push(ContentBlock::text_content(format!(
+                    "(Poster of {label} could not be loaded: {err})"
+                ))),
+            }
+        }
+
+        Ok(CallToolResult::from_content(content))
+    }
+}
+
+/// Appends the year of `date` (YYYY-MM-DD) to the title, if known.
+fn with_year(title: &str, date: Option<&str>) -> String {
+    match date.and_then(|date| date.get(0..4)) {
+        Some(year) => format!("{title} ({year})"),
+        None => title.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    /// A known-for movie with a poster at `/poster-{id}.jpg`.
+    fn known_for(id: i64, title: &str) -> Value {
+        json!({
+            "id": id,
+            "media_type": "movie",
+            "title": title,
+            "release_date": "1994-06-23",
+            "poster_path": format!("/poster-{id}.jpg"),
+        })
+    }
+
+    /// Serves Tom Hanks's search result listing `titles` known-for movies, and the posters of
+    /// the first `posters` of them.
+    async fn tom_hanks_known_for(titles: i64, posters: i64) -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        let mut summary = fixtures::person_summary(31, "Tom Hanks");
+        summary["known_for"] = (1..=titles)
+            .map(|id| known_for(id, &format!("Movie {id}")))
+            .collect();
+        mock.json("/search/person", fixtures::single_page(vec![summary]))
+            .json("/person/31", fixtures::person(31, "Tom Hanks"));
+        for id in 1..=posters {
+            mock.on(
+                &format!("/w92/poster-{id}.jpg"),
+                MockResponse::bytes(200, "image/jpeg", "jpeg"),
+            );
+        }
+        mock
+    }
+
+    fn by_name() -> GetActorHighlights {
+        GetActorHighlights {
+            actor_id: None,
+            actor_name: Some("Tom Hanks".to_string()),
+        }
+    }
+
+    fn image_blocks(result: &CallToolResult) -> usize {
+        result
+            .content
+            .iter()
+            .filter(|block| matches!(block, ContentBlock::ImageContent(_)))
+            .count()
+    }
+
+    #[tokio::test]
+    async fn one_poster_per_known_for_title() {
+        let mock = tom_hanks_known_for(3, 3).await;
+
+        let result = by_name().invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(image_blocks(&result), 3);
+        assert!(result_text(&result).starts_with(
+            "Tom Hanks (Acting) is best known for Movie 1 (1994), Movie 2 (1994), Movie 3 (1994)."
+        ));
+    }
+
+    #[tokio::test]
+    async fn posters_are_capped() {
+        let mock = tom_hanks_known_for(6, 6).await;
+
+        let result = by_name().invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(image_blocks(&result), MAX_POSTERS);
+        assert_eq!(mock.hits("/w92/poster-5.jpg"), 0);
+        assert_eq!(mock.hits("/w92/poster-6.jpg"), 0);
+    }
+
+    #[tokio::test]
+    async fn by_id_the_most_popular_movies_are_shown() {
+        let mock = MockTmdb::start().await;
+        let credits = (1..=5)
+            .map(|id| {
+                let mut credit = fixtures::credit(id, &format!("Movie {id}"), "2000-01-01", "");
+                credit["popularity"] = json!(id as f64);
+                credit["poster_path"] = json!(format!("/poster-{id}.jpg"));
+                credit
+            })
+            .collect();
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json("/person/31/movie_credits", fixtures::movie_credits(credits));
+        for id in 1..=5 {
+            mock.on(
+                &format!("/w92/poster-{id}.jpg"),
+                MockResponse::bytes(200, "image/jpeg", "jpeg"),
+            );
+        }
+
+        let result = GetActorHighlights {
+            actor_id: Some(31),
+            actor_name: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(image_blocks(&result), MAX_POSTERS);
+        assert!(result_text(&result).starts_with(
+            "Tom Hanks (Acting) is best known for Movie 5 (2000), Movie 4 (2000), Movie 3 (2000), Movie 2 (2000)."
+        ));
+        assert_eq!(mock.hits("/w92/poster-1.jpg"), 0);
+    }
+
+    #[tokio::test]
+    async fn a_failed_poster_is_reported_in_its_place() {
+        let mock = tom_hanks_known_for(2, 1).await;
+
+        let result = by_name().invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(image_blocks(&result), 1);
+        assert!(result_text(&result).contains("(Poster of Movie 2 (1994) could not be loaded: "));
+    }
+}
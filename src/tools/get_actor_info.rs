@@ -1,9 +1,15 @@
-use crate::tmdb_client::TmdbClient;
+use crate::{
+    tmdb_client::{MissingFields, PersonDetails, TmdbClient},
+    tools::{ResponseFormat, raw_not_allowed, summarize_biography},
+};
 use rust_mcp_sdk::{
     macros::{JsonSchema, mcp_tool},
     schema::{CallToolError, CallToolResult, ContentBlock},
 };
 
+/// Language used when the configured language has no biography.
+const FALLBACK_LANGUAGE: &str = "en-US";
+
 #[mcp_tool(
     name = "get_actor_info",
     title="Get Actor Information",
@@ -11,7 +17,8 @@ use rust_mcp_sdk::{
        "This tool retrieves data such as actor id, biography, filmography, and other relevant ",
        "information to provide a comprehensive profile of the actor.",
        "Use this tool when you want to learn more about a specific actor or explore their career.",
-       "Simply provide the actor's name, and the tool will fetch all available details."),
+       "Simply provide the actor's name, and the tool will fetch all available details. ",
+       "If several people share the name, add a `disambiguator`: a title or year they are known for."),
     icons = [
         (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
         mime_type = "image/png",
@@ -21,7 +28,79 @@ use rust_mcp_sdk::{
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
 pub struct GetActorInfo {
     /// The name of the actor.
+    #[json_schema(min_length = 1)]
     pub actor_name: String,
+    /// The full title of a movie or show the actor is known for, or the year it was released,
+    /// to pick the right one when several people share the name (e.g. "Jurassic Park" or "1993").
+    pub disambiguator: Option<String>,
+    /// Return TMDB's unmodified JSON for the person instead of the formatted profile (only if
+    /// the server allows it)
+    pub raw: Option<bool>,
+}
+
+/// Structured content returned by `get_actor_info`. Also the source of the tool's output schema.
+#[derive(::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct ActorInfoOutput {
+    /// TMDB person ID
+    pub id: u32,
+    /// Primary name used for display
+    pub name: String,
+    /// Biography, possibly truncated (see `biography_truncated`)
+    pub biography: String,
+    /// Length of the full biography in characters
+    pub biography_length: u64,
+    /// Whether `biography` was truncated for display
+    pub biography_truncated: bool,
+    /// Language the biography is written in (differs from the configured language after a fallback)
+    pub biography_language: String,
+    /// Birth date in YYYY-MM-DD format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<String>,
+    /// Death date in YYYY-MM-DD format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deathday: Option<String>,
+    /// Place of birth (city, country, etc.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_of_birth: Option<String>,
+    /// Primary department this person is known for
+    pub known_for_department: String,
+    /// Popularity score (higher = more popular)
+    pub popularity: f64,
+    /// Corresponding IMDb ID (with "nm" prefix)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imdb_id: Option<String>,
+    /// Alternative names in different languages/scripts
+    pub also_known_as: Vec<String>,
+    /// Titles of the movies and shows the person is best known for
+    pub known_for: Vec<String>,
+}
+
+impl ActorInfoOutput {
+    fn new(
+        details: &PersonDetails,
+        biography: String,
+        biography_language: String,
+        known_for: Vec<String>,
+        max_biography_chars: usize,
+    ) -> Self {
+        let biography_length = details.biography.trim().chars().count() as u64;
+        Self {
+            id: details.id,
+            name: details.name.clone(),
+            biography_truncated: biography_length > max_biography_chars as u64,
+            biography,
+            biography_language,
+            biography_length,
+            birthday: details.birthday.clone(),
+            deathday: details.deathday.clone(),
+            place_of_birth: details.place_of_birth.clone(),
+            known_for_department: details.known_for_department.clone(),
+            popularity: details.popularity,
+            imdb_id: details.imdb_id.clone(),
+            also_known_as: details.also_known_as.clone(),
+            known_for,
+        }
+    }
 }
 
 // Implements the `invoke` function, which is executed whenever the client calls this tool.
@@ -30,15 +109,21 @@ impl GetActorInfo {
     pub async fn invoke(
         &self,
         tmdb_client: &TmdbClient,
+        format: ResponseFormat,
     ) -> std::result::Result<CallToolResult, CallToolError> {
+        let raw = self.raw.unwrap_or(false);
+        if raw && !tmdb_client.config().allow_raw {
+            return Ok(CallToolResult::with_error(raw_not_allowed()));
+        }
+
         // make an api call and get actor details from tmdb
         let response = tmdb_client
-            .actor_info(&self.actor_name)
+            .actor_info(&self.actor_name, self.disambiguator.as_deref())
             .await
             .map_err(|err| CallToolError::from_message(err.to_string()))?;
 
         // return an error message if no actor with that name was found
-        let Some(actor_details) = response else {
+        let Some(profile) = response else {
             return Ok(CallToolResult::with_error(CallToolError::from_message(
                 format!(
                     "No actors matching the name \"{}\" were found",
@@ -47,15 +132,456 @@ impl GetActorInfo {
             )));
         };
 
-        // get the actor profile image as base64 encoded image and return it in the result
-        let image_data = tmdb_client
-            .image_as_base64(&actor_details.profile_path.as_ref().unwrap())
+        // raw: the matched person's details exactly as TMDB sent them
+        if raw {
+            let body = tmdb_client
+                .person_details_raw(i64::from(profile.details.id))
+                .await
+                .map_err(|err| CallToolError::from_message(err.to_string()))?;
+            let Some(body) = body else {
+                return Ok(CallToolResult::with_error(CallToolError::from_message(
+                    format!(
+                        "No person with the TMDB ID {} was found",
+                        profile.details.id
+                    ),
+                )));
+            };
+            return Ok(CallToolResult::text_content(vec![body.into()]));
+        }
+
+        let known_for: Vec<String> = profile
+            .known_for
+            .iter()
+            .map(|title| title.display_title().to_string())
+            .filter(|title| !title.is_empty())
+            .collect();
+        // say so when the hint didn't identify anyone and the most popular match was used
+        let unmatched_hint = self
+            .disambiguator
+            .as_deref()
+            .filter(|hint| !profile.known_for.iter().any(|title| title.matches(hint)));
+        let mut actor_details = profile.details;
+
+        // fall back to the English biography when none exists in the configured language
+        let mut biography_language = tmdb_client.config().language.clone();
+        if actor_details.biography.trim().is_empty() && biography_language != FALLBACK_LANGUAGE {
+            let english = tmdb_client
+                .person_details(actor_details.id.into(), FALLBACK_LANGUAGE)
+                .await
+                .map_err(|err| CallToolError::from_message(err.to_string()))?;
+            if let Some(english) = english
+                && !english.biography.trim().is_empty()
+            {
+                actor_details.biography = english.biography;
+                biography_language = FALLBACK_LANGUAGE.to_string();
+            }
+        }
+
+        // keep the biography readable: note when it is missing and shorten very long ones
+        let max_biography_chars = tmdb_client.config().max_biography_chars;
+        let mut display_details = actor_details.clone();
+        display_details.biography =
+            summarize_biography(&actor_details.biography, max_biography_chars);
+
+        // structured copy of the details, including the full biography length
+        let output = ActorInfoOutput::new(
+            &actor_details,
+            display_details.biography.clone(),
+            biography_language.clone(),
+            known_for.clone(),
+            max_biography_chars,
+        );
+        let structured = serde_json::to_value(&output)
+            .ok()
+            .and_then(|value| value.as_object().cloned())
+            .unwrap_or_default();
+
+        // terse: a one-line summary, without the biography or profile image
+        if format == ResponseFormat::Terse {
+            return Ok(
+                CallToolResult::text_content(vec![actor_details.summary().into()])
+                    .with_structured_content(structured),
+            );
+        }
+
+        // with TMDB_MISSING_FIELDS=omit, leave out the biography line rather than noting it is missing
+        let missing_fields = tmdb_client.config().missing_fields_or(MissingFields::Label);
+        if missing_fields == MissingFields::Omit && actor_details.biography.trim().is_empty() {
+            display_details.biography.clear();
+        }
+
+        // mention when the biography is not in the requested language
+        let mut text = display_details.render(missing_fields);
+        if let Some(hint) = unmatched_hint {
+            text.push_str(&format!(
+                "\n(No \"{}\" is known for \"{hint}\"; showing the most popular match instead)",
+                self.actor_name
+            ));
+        }
+        if !known_for.is_empty() {
+            text.push_str(&format!("\nKnown for: {}", known_for.join(", ")));
+        }
+        if biography_language != tmdb_client.config().language {
+            text.push_str(&format!(
+                "\n(No biography available in {}; showing the {biography_language} biography instead)",
+                tmdb_client.config().language
+            ));
+        }
+
+        // get the actor profile image as base64 encoded image, but still return the
+        // details with a note if there is no image or it can't be downloaded
+        let mut content = vec![ContentBlock::text_content(text)]; // actor info as string
+        match actor_details.profile_path.as_deref() {
+            None => content.push(ContentBlock::text_content(
+                "(No profile image available)".to_string(),
+            )),
+            Some(profile_path) => match tmdb_client.image_as_base64(profile_path).await {
+                // actor profile image as base64 blob
+                Ok(image_data) => {
+                    content.push(ContentBlock::image_content(image_data, "image/jpeg".into()))
+                }
+                Err(err) if err.is_unavailable() => {
+                    content.push(ContentBlock::text_content(format!(
+                        "(Profile image could not be loaded: the image server is unavailable ({err}))"
+                    )))
+                }
+                Err(err) => content.push(ContentBlock::text_content(format!(
+                    "(Profile image could not be loaded: {err})"
+                ))),
+            },
+        }
+
+        Ok(CallToolResult::from_content(content).with_structured_content(structured))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    /// Serves a search for "Tom Hanks" and his details, with a profile image at `/hanks.jpg`.
+    async fn mock_actor() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        let mut person = fixtures::person(31, "Tom Hanks");
+        person["profile_path"] = "/hanks.jpg".into();
+        mock.json(
+            "/search/person",
+            fixtures::single_page(vec![fixtures::person_summary(31, "Tom Hanks")]),
+        )
+        .json("/person/31", person);
+        mock
+    }
+
+    fn tool(actor_name: &str) -> GetActorInfo {
+        GetActorInfo {
+            actor_name: actor_name.to_string(),
+            disambiguator: None,
+            raw: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn terse_output_is_one_line_without_the_image() {
+        let mock = mock_actor().await;
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Terse)
+            .await
+            .unwrap();
+
+        assert_eq!(result.content.len(), 1);
+        let text = result_text(&result);
+        assert!(text.contains("Tom Hanks"));
+        assert!(!text.contains('\n'));
+        assert!(result.structured_content.is_some());
+        assert_eq!(mock.hits("/w92/hanks.jpg"), 0);
+    }
+
+    #[tokio::test]
+    async fn detailed_output_is_the_person_details_display() {
+        let mock = mock_actor().await;
+        let person: PersonDetails =
+            serde_json::from_value(fixtures::person(31, "Tom Hanks")).unwrap();
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        let ContentBlock::TextContent(text) = &result.content[0] else {
+            panic!("expected the details first");
+        };
+        assert_eq!(text.text, person.to_string());
+    }
+
+    #[tokio::test]
+    async fn terse_output_is_the_person_details_summary() {
+        let mock = mock_actor().await;
+        let person: PersonDetails =
+            serde_json::from_value(fixtures::person(31, "Tom Hanks")).unwrap();
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Terse)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks - TMDB ID: 31, born 1956-07-09 in Concord, California, USA"
+        );
+        assert_eq!(result_text(&result), person.summary());
+    }
+
+    #[tokio::test]
+    async fn returns_the_profile_image_with_the_details() {
+        let mock = mock_actor().await;
+        mock.on(
+            "/w92/hanks.jpg",
+            MockResponse::bytes(200, "image/jpeg", "jpeg"),
+        );
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert!(result_text(&result).contains("Name: Tom Hanks"));
+        assert!(matches!(
+            result.content.last(),
+            Some(ContentBlock::ImageContent(image)) if image.mime_type == "image/jpeg"
+        ));
+    }
+
+    #[tokio::test]
+    async fn a_failed_image_download_still_returns_the_details() {
+        let mock = mock_actor().await;
+        mock.on(
+            "/w92/hanks.jpg",
+            MockResponse::status(503, serde_json::json!({})),
+        );
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.contains("Name: Tom Hanks"));
+        assert!(
+            text.contains("(Profile image could not be loaded: the image server is unavailable")
+        );
+        // the first attempt and both retries
+        assert_eq!(mock.hits("/w92/hanks.jpg"), 3);
+    }
+
+    #[tokio::test]
+    async fn an_empty_biography_is_noted() {
+        let mock = MockTmdb::start().await;
+        let mut person = fixtures::person(31, "Tom Hanks");
+        person["biography"] = "".into();
+        mock.json(
+            "/search/person",
+            fixtures::single_page(vec![fixtures::person_summary(31, "Tom Hanks")]),
+        )
+        .json("/person/31", person);
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert!(result_text(&result).contains("Biography: No biography available"));
+    }
+
+    #[tokio::test]
+    async fn the_biography_is_cut_to_the_configured_length() {
+        let mock = mock_actor().await;
+        let tmdb = mock.client_with(|config| config.max_biography_chars = 12);
+
+        let result = tool("Tom Hanks")
+            .invoke(&tmdb, ResponseFormat::Detailed)
             .await
             .unwrap();
 
-        return Ok(CallToolResult::from_content(vec![
-            ContentBlock::text_content(actor_details.to_string()), // actor info as string
-            ContentBlock::image_content(image_data, "image/jpeg".into()), // actor profile image as base64 blob
-        ]));
+        assert!(result_text(&result).contains("Biography: Tom Hanks...(truncated)"));
+        let structured = result.structured_content.unwrap();
+        assert_eq!(structured["biography_truncated"], true);
+        assert_eq!(structured["biography_length"], 22);
+    }
+
+    #[tokio::test]
+    async fn structured_content_matches_the_output_schema() {
+        let mock = mock_actor().await;
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        let structured = result.structured_content.unwrap();
+        let schema = ActorInfoOutput::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        for field in structured.keys() {
+            assert!(
+                properties.contains_key(field),
+                "{field} is not in the schema"
+            );
+        }
+        for field in schema["required"].as_array().unwrap() {
+            assert!(
+                structured.contains_key(field.as_str().unwrap()),
+                "{field} is missing"
+            );
+        }
+        assert_eq!(structured["id"], 31);
+        assert_eq!(structured["name"], "Tom Hanks");
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_the_english_biography() {
+        let mock = MockTmdb::start().await;
+        let mut french = fixtures::person(31, "Tom Hanks");
+        french["biography"] = "".into();
+        mock.json(
+            "/search/person",
+            fixtures::single_page(vec![fixtures::person_summary(31, "Tom Hanks")]),
+        )
+        .json("/person/31?language=fr-FR", french)
+        .json(
+            "/person/31?language=en-US",
+            fixtures::person(31, "Tom Hanks"),
+        );
+        let tmdb = mock.client_with(|config| config.language = "fr-FR".to_string());
+
+        let result = tool("Tom Hanks")
+            .invoke(&tmdb, ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert!(result_text(&result).contains("Biography: Tom Hanks is an actor."));
+        let structured = result.structured_content.unwrap();
+        assert_eq!(structured["biography_language"], "en-US");
+        assert_eq!(mock.hits("/person/31"), 2);
+    }
+
+    #[tokio::test]
+    async fn a_localized_biography_needs_no_fallback() {
+        let mock = mock_actor().await;
+        let tmdb = mock.client_with(|config| config.language = "fr-FR".to_string());
+
+        let result = tool("Tom Hanks")
+            .invoke(&tmdb, ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.structured_content.unwrap()["biography_language"],
+            "fr-FR"
+        );
+        assert_eq!(mock.hits("/person/31"), 1);
+    }
+
+    #[tokio::test]
+    async fn a_missing_profile_image_is_noted() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/search/person",
+            fixtures::single_page(vec![fixtures::person_summary(31, "Tom Hanks")]),
+        )
+        .json("/person/31", fixtures::person(31, "Tom Hanks"));
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert!(result_text(&result).contains("(No profile image available)"));
+        assert_eq!(result.is_error, None);
+    }
+
+    #[tokio::test]
+    async fn a_rejected_image_is_not_reported_as_an_outage() {
+        let mock = mock_actor().await;
+
+        let result = tool("Tom Hanks")
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.contains("Name: Tom Hanks"));
+        assert!(text.contains("(Profile image could not be loaded: "));
+        assert!(!text.contains("unavailable"));
+        assert_eq!(mock.hits("/w92/hanks.jpg"), 1);
+    }
+
+    /// Serves two people named "Chris Smith": the popular one known for "Rocky Horror" (1975),
+    /// listed first, and an obscure one known for "Rocky" (1976).
+    async fn mock_namesakes() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        let known_for = |id: i64, title: &str, release_date: &str| {
+            serde_json::json!([{
+                "id": id,
+                "media_type": "movie",
+                "title": title,
+                "release_date": release_date,
+            }])
+        };
+        let mut popular = fixtures::person_summary(2, "Chris Smith");
+        popular["popularity"] = 50.0.into();
+        popular["known_for"] = known_for(11, "Rocky Horror", "1975-08-14");
+        let mut obscure = fixtures::person_summary(1, "Chris Smith");
+        obscure["popularity"] = 5.0.into();
+        obscure["known_for"] = known_for(10, "Rocky", "1976-11-21");
+        mock.json(
+            "/search/person",
+            fixtures::single_page(vec![popular, obscure]),
+        )
+        .json("/person/1", fixtures::person(1, "Chris Smith"))
+        .json("/person/2", fixtures::person(2, "Chris Smith"));
+        mock
+    }
+
+    async fn pick(mock: &MockTmdb, disambiguator: &str) -> CallToolResult {
+        GetActorInfo {
+            disambiguator: Some(disambiguator.to_string()),
+            ..tool("Chris Smith")
+        }
+        .invoke(&mock.client(), ResponseFormat::Terse)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn a_disambiguator_picks_between_namesakes() {
+        let mock = mock_namesakes().await;
+
+        // the whole title, not a part of it
+        pick(&mock, "rocky").await;
+        // a release year
+        pick(&mock, "1975").await;
+
+        assert_eq!(mock.hits("/person/1"), 1);
+        assert_eq!(mock.hits("/person/2"), 1);
+    }
+
+    #[tokio::test]
+    async fn an_unmatched_disambiguator_falls_back_to_the_most_popular() {
+        let mock = mock_namesakes().await;
+
+        let result = GetActorInfo {
+            disambiguator: Some("Rocky II".to_string()),
+            ..tool("Chris Smith")
+        }
+        .invoke(&mock.client(), ResponseFormat::Detailed)
+        .await
+        .unwrap();
+
+        assert_eq!(mock.hits("/person/2"), 1);
+        assert!(result_text(&result).contains(
+            "(No \"Chris Smith\" is known for \"Rocky II\"; showing the most popular match instead)"
+        ));
     }
 }
@@ -0,0 +1,219 @@
+use crate::tmdb_client::{ExternalIds, TmdbClient};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "get_actor_links",
+    title = "Get Actor Links",
+    description = concat!(
+        "Get links to an actor's official website, IMDb page and social media profiles ",
+        "(Instagram, X/Twitter, Facebook, TikTok) by TMDB actor ID.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetActorLinks {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetActorLinks {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // the homepage is on the person details, the handles on their external ids
+        let language = &tmdb_client.config().language;
+        let (details, external_ids) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.person_external_ids(self.actor_id),
+        );
+        let details = details.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let external_ids =
+            external_ids.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let (Some(details), Some(external_ids)) = (details, external_ids) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+
+        let mut links = Vec::new();
+        if let Some(homepage) = details.homepage.as_deref().map(str::trim)
+            && !homepage.is_empty()
+        {
+            links.push(("Official site", homepage.to_string()));
+        }
+        links.extend(social_links(&external_ids));
+
+        if links.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("No links are known for {}", details.name).into(),
+            ]));
+        }
+
+        // markdown links, so clients can render them as clickable
+        let result = links
+            .iter()
+            .map(|(site, url)| format!("- [{site}]({url})"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!("Links for {}:\n{result}", details.name).into(),
+        ]))
+    }
+}
+
+/// Builds full profile URLs from the IDs and bare handles TMDB stores, skipping unknown ones.
+fn social_links(external_ids: &ExternalIds) -> Vec<(&'static str, String)> {
+    // site name, stored id or handle, and the text around it in the profile URL
+    let sites = [
+        (
+            "IMDb",
+            &external_ids.imdb_id,
+            "https://www.imdb.com/name/",
+            "/",
+        ),
+        (
+            "Instagram",
+            &external_ids.instagram_id,
+            "https://www.instagram.com/",
+            "/",
+        ),
+        (
+            "X (Twitter)",
+            &external_ids.twitter_id,
+            "https://x.com/",
+            "",
+        ),
+        (
+            "Facebook",
+            &external_ids.facebook_id,
+            "https://www.facebook.com/",
+            "",
+        ),
+        (
+            "TikTok",
+            &external_ids.tiktok_id,
+            "https://www.tiktok.com/@",
+            "",
+        ),
+        (
+            "Wikidata",
+            &external_ids.wikidata_id,
+            "https://www.wikidata.org/wiki/",
+            "",
+        ),
+    ];
+
+    sites
+        .into_iter()
+        .filter_map(|(site, handle, prefix, suffix)| {
+            // handles are sometimes stored with a leading "@"
+            let handle = handle.as_deref()?.trim().trim_start_matches('@');
+            (!handle.is_empty()).then(|| (site, format!("{prefix}{handle}{suffix}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    #[test]
+    fn builds_profile_urls_from_handles() {
+        let external_ids: ExternalIds = serde_json::from_value(json!({
+            "imdb_id": "nm0000230",
+            "facebook_id": "stallone",
+            "instagram_id": "@officialslystallone",
+            "twitter_id": "TheSlyStallone",
+            "tiktok_id": "slyofficial",
+            "wikidata_id": "Q40026",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            social_links(&external_ids),
+            [
+                ("IMDb", "https://www.imdb.com/name/nm0000230/".to_string()),
+                (
+                    "Instagram",
+                    "https://www.instagram.com/officialslystallone/".to_string()
+                ),
+                ("X (Twitter)", "https://x.com/TheSlyStallone".to_string()),
+                ("Facebook", "https://www.facebook.com/stallone".to_string()),
+                ("TikTok", "https://www.tiktok.com/@slyofficial".to_string()),
+                (
+                    "Wikidata",
+                    "https://www.wikidata.org/wiki/Q40026".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_and_empty_handles_are_skipped() {
+        let external_ids: ExternalIds = serde_json::from_value(json!({
+            "imdb_id": "nm0000230",
+            "facebook_id": null,
+            "instagram_id": "",
+            "twitter_id": " @ ",
+            "tiktok_id": null,
+            "wikidata_id": null,
+        }))
+        .unwrap();
+
+        let sites: Vec<_> = social_links(&external_ids)
+            .into_iter()
+            .map(|(site, _)| site)
+            .collect();
+        assert_eq!(sites, ["IMDb"]);
+    }
+
+    #[tokio::test]
+    async fn lists_the_homepage_and_profiles_as_markdown_links() {
+        let mock = MockTmdb::start().await;
+        let mut person = fixtures::person(16483, "Sylvester Stallone");
+        person["homepage"] = " https://www.sylvesterstallone.com ".into();
+        mock.json("/person/16483", person).json(
+            "/person/16483/external_ids",
+            json!({ "id": 16483, "imdb_id": "nm0000230", "twitter_id": "TheSlyStallone" }),
+        );
+
+        let result = GetActorLinks { actor_id: 16483 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.contains("- [Official site](https://www.sylvesterstallone.com)"));
+        assert!(text.contains("- [IMDb](https://www.imdb.com/name/nm0000230/)"));
+        assert!(text.contains("- [X (Twitter)](https://x.com/TheSlyStallone)"));
+    }
+
+    #[tokio::test]
+    async fn an_unknown_actor_is_an_error() {
+        let mock = MockTmdb::start().await;
+
+        let result = GetActorLinks { actor_id: 1 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No actor with the TMDB ID 1"));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient},
+    tools::PageInfo,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use time::OffsetDateTime;
+
+/// Year of the earliest surviving films; no earlier release year can match.
+const FIRST_FILM_YEAR: u32 = 1874;
+
+/// How many years ahead TMDB lists announced movies.
+const MAX_YEARS_AHEAD: u32 = 10;
+
+#[mcp_tool(
+    name = "get_actor_movies_in_year",
+    title = "Get Actor's Movies in a Year",
+    description = concat!(
+        "List the movies an actor appeared in that were released in a given year, ",
+        "e.g. what Tom Hanks made in 2019. Specify the TMDB `actor_id` and the `year`.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetActorMoviesInYear {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+    /// Release year, e.g. 2019
+    #[json_schema(minimum = 1874)]
+    pub year: u32,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetActorMoviesInYear {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let last_year = OffsetDateTime::now_utc().year() as u32 + MAX_YEARS_AHEAD;
+        if !(FIRST_FILM_YEAR..=last_year).contains(&self.year) {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!(
+                    "`year` must be between {FIRST_FILM_YEAR} and {last_year}, got {}",
+                    self.year
+                ),
+            )));
+        }
+
+        // fetch the actor and the movies at the same time, checking the actor exists
+        let language = &tmdb_client.config().language;
+        let (actor, movies) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.actor_movies_in_year(self.actor_id, self.year),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(actor) = actor else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+
+        let movies = movies.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let page_info = PageInfo::of(&movies);
+        if movies.results.is_empty() {
+            return Ok(page_info.attach_to(CallToolResult::text_content(vec![
+                format!("{} has no movies released in {}", actor.name, self.year).into(),
+            ])));
+        }
+
+        // numbered list including each movie's id, so it can be passed to other tools
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = movies
+            .results
+            .iter()
+            .enumerate()
+            .map(|(index, movie)| {
+                format!(
+                    "{}. {} - TMDB ID: {}",
+                    index + 1,
+                    movie.render(missing),
+                    movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![
+            format!(
+                "Movies with {} released in {}:\n{result}",
+                actor.name, self.year
+            )
+            .into(),
+        ])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    #[tokio::test]
+    async fn discovers_movies_by_cast_and_release_year() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"));
+        mock.json(
+            "/discover/movie",
+            fixtures::single_page(vec![fixtures::movie(1010581, "Toy Story 4", "2019-06-19")]),
+        );
+
+        let result = GetActorMoviesInYear {
+            actor_id: 31,
+            year: 2019,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        let request = mock
+            .requests()
+            .into_iter()
+            .find(|request| request.path() == "/discover/movie")
+            .unwrap();
+        assert_eq!(request.query("with_cast"), Some("31"));
+        assert_eq!(request.query("primary_release_year"), Some("2019"));
+        let text = result_text(&result);
+        assert!(text.starts_with("Movies with Tom Hanks released in 2019:\n1. Toy Story 4"));
+        assert!(text.contains("TMDB ID: 1010581"));
+    }
+
+    #[tokio::test]
+    async fn a_year_without_movies_says_so() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"));
+        mock.json("/discover/movie", fixtures::single_page(vec![]));
+
+        let result = GetActorMoviesInYear {
+            actor_id: 31,
+            year: 1950,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks has no movies released in 1950"
+        );
+    }
+
+    #[tokio::test]
+    async fn a_year_before_the_first_films_is_rejected_without_a_request() {
+        let mock = MockTmdb::start().await;
+
+        let result = GetActorMoviesInYear {
+            actor_id: 31,
+            year: 1800,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).starts_with("`year` must be between 1874 and "));
+        assert!(mock.requests().is_empty());
+    }
+}
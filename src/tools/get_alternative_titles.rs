@@ -0,0 +1,177 @@
+use crate::{
+    tmdb_client::{AlternativeTitle, TmdbClient},
+    tools::format::compare_names,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::BTreeMap;
+
+#[mcp_tool(
+    name = "get_alternative_titles",
+    title = "Get Alternative Movie Titles",
+    description = concat!(
+        "List the titles a movie is known by in different countries, given its TMDB ID. ",
+        "Useful for matching films released under different names in different markets. ",
+        "Optionally provide a two-letter `country` code (e.g. JP) to only return titles for that country.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetAlternativeTitles {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+    /// Only return titles for this ISO 3166-1 country code (e.g. "US", "JP")
+    #[json_schema(min_length = 2, max_length = 2)]
+    pub country: Option<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetAlternativeTitles {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let titles = tmdb_client
+            .alternative_titles(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(titles) = titles else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+
+        // country codes are upper case on TMDB, accept either case from the caller
+        let country = self.country.as_deref().map(|c| c.trim().to_uppercase());
+        let titles: Vec<&AlternativeTitle> = titles
+            .iter()
+            .filter(|title| country.as_deref().is_none_or(|c| title.iso_3166_1 == c))
+            .collect();
+
+        if titles.is_empty() {
+            let message = match country {
+                Some(country) => format!(
+                    "Movie {} has no alternative titles for country \"{country}\"",
+                    self.movie_id
+                ),
+                None => format!("Movie {} has no alternative titles", self.movie_id),
+            };
+            return Ok(CallToolResult::text_content(vec![message.into()]));
+        }
+
+        Ok(CallToolResult::text_content(vec![
+            render_titles(&titles).into(),
+        ]))
+    }
+}
+
+/// Renders one line per country (sorted by country code) listing its titles alphabetically,
+/// e.g. `JP: Title A; Title B (working title)`.
+fn render_titles(titles: &[&AlternativeTitle]) -> String {
+    let mut by_country: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for title in titles {
+        let label = if title.title_type.is_empty() {
+            title.title.clone()
+        } else {
+            format!("{} ({})", title.title, title.title_type)
+        };
+        by_country
+            .entry(title.iso_3166_1.as_str())
+            .or_default()
+            .push(label);
+    }
+
+    by_country
+        .into_iter()
+        .map(|(country, mut labels)| {
+            labels.sort_by(|a, b| compare_names(a, b));
+            format!("{country}: {}", labels.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::MockTmdb;
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    async fn mock_titles() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/129/alternative_titles",
+            json!({
+                "id": 129,
+                "titles": [
+                    { "iso_3166_1": "US", "title": "Spirited Away", "type": "" },
+                    { "iso_3166_1": "JP", "title": "Sen to Chihiro", "type": "romaji" },
+                    { "iso_3166_1": "JP", "title": "Sen", "type": "" }
+                ]
+            }),
+        );
+        mock
+    }
+
+    #[tokio::test]
+    async fn groups_titles_by_country() {
+        let mock = mock_titles().await;
+
+        let result = GetAlternativeTitles {
+            movie_id: 129,
+            country: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "JP: Sen; Sen to Chihiro (romaji)\nUS: Spirited Away"
+        );
+    }
+
+    #[tokio::test]
+    async fn filters_by_country_in_either_case() {
+        let mock = mock_titles().await;
+
+        let tool = |country: &str| GetAlternativeTitles {
+            movie_id: 129,
+            country: Some(country.to_string()),
+        };
+        let result = tool("us").invoke(&mock.client()).await.unwrap();
+        assert_eq!(result_text(&result), "US: Spirited Away");
+
+        let result = tool("FR").invoke(&mock.client()).await.unwrap();
+        assert_eq!(
+            result_text(&result),
+            "Movie 129 has no alternative titles for country \"FR\""
+        );
+    }
+
+    #[tokio::test]
+    async fn an_unknown_movie_is_an_error() {
+        let mock = MockTmdb::start().await;
+
+        let result = GetAlternativeTitles {
+            movie_id: 404,
+            country: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No movie with the TMDB ID 404"));
+    }
+}
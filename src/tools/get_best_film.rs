@@ -0,0 +1,200 @@
+use crate::{
+    tmdb_client::{MovieCredit, TmdbClient},
+    tools::{format::format_rating, summarize_biography},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Films with fewer votes than this are ignored, so obscure titles with a handful of
+/// perfect scores don't win.
+const DEFAULT_MIN_VOTE_COUNT: u32 = 200;
+
+/// Overviews longer than this many characters are shortened.
+const MAX_OVERVIEW_CHARS: usize = 300;
+
+#[mcp_tool(
+    name = "get_best_film",
+    title = "Get an Actor's Best Film",
+    description = concat!(
+        "Find an actor's most acclaimed film: the highest-rated movie in their filmography ",
+        "that has enough votes to be meaningful. Returns the title, rating and a short overview.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetBestFilm {
+    /// The TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+    /// Minimum number of votes a film needs to be considered (defaults to 200)
+    pub min_vote_count: Option<u32>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetBestFilm {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let credits = tmdb_client
+            .person_movie_credits(self.actor_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let min_vote_count = self.min_vote_count.unwrap_or(DEFAULT_MIN_VOTE_COUNT);
+        let Some(best) = best_film(&credits, min_vote_count) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No films with at least {min_vote_count} votes were found for this actor"),
+            )));
+        };
+
+        let overview = match best.movie.overview.trim() {
+            "" => "No overview available".to_string(),
+            overview => summarize_biography(overview, MAX_OVERVIEW_CHARS),
+        };
+        let result = format!(
+            "{}\nTMDB ID: {}\nRating: {}/10 ({} votes)\nOverview: {}",
+            best,
+            best.movie.id,
+            format_rating(best.movie.vote_average, &tmdb_client.config().language),
+            best.movie.vote_count,
+            overview
+        );
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+/// Returns the highest-rated credit with at least `min_vote_count` votes.
+/// Ties are broken by vote count.
+pub fn best_film(credits: &[MovieCredit], min_vote_count: u32) -> Option<&MovieCredit> {
+    credits
+        .iter()
+        .filter(|credit| credit.movie.vote_count >= min_vote_count)
+        .max_by(|a, b| {
+            a.movie
+                .vote_average
+                .total_cmp(&b.movie.vote_average)
+                .then(a.movie.vote_count.cmp(&b.movie.vote_count))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::Value;
+
+    fn rated(mut credit: Value, vote_average: f64, vote_count: u32) -> Value {
+        credit["vote_average"] = vote_average.into();
+        credit["vote_count"] = vote_count.into();
+        credit
+    }
+
+    fn mock_credits(mock: &MockTmdb) {
+        let mut gump = rated(
+            fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump"),
+            8.5,
+            27000,
+        );
+        gump["overview"] = "A man with a low IQ has accomplished great things.".into();
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                rated(
+                    fixtures::credit(1, "Obscure Gem", "2001-01-01", ""),
+                    9.8,
+                    12,
+                ),
+                gump,
+                rated(fixtures::credit(2, "Big", "1988-06-03", "Josh"), 7.2, 4000),
+            ]),
+        );
+    }
+
+    #[tokio::test]
+    async fn picks_the_highest_rated_film_with_enough_votes() {
+        let mock = MockTmdb::start().await;
+        mock_credits(&mock);
+
+        let result = GetBestFilm {
+            actor_id: 31,
+            min_vote_count: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Forrest Gump (1994)"), "{text}");
+        assert!(text.contains("TMDB ID: 13\nRating: 8.5/10 (27000 votes)"));
+        assert!(text.ends_with("Overview: A man with a low IQ has accomplished great things."));
+    }
+
+    #[tokio::test]
+    async fn a_low_vote_threshold_lets_obscure_films_win() {
+        let mock = MockTmdb::start().await;
+        mock_credits(&mock);
+
+        let result = GetBestFilm {
+            actor_id: 31,
+            min_vote_count: Some(10),
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Obscure Gem (2001)"), "{text}");
+        assert!(text.ends_with("Overview: No overview available"));
+    }
+
+    #[tokio::test]
+    async fn long_overviews_are_shortened() {
+        let mock = MockTmdb::start().await;
+        let mut credit = fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump");
+        credit["overview"] = "word ".repeat(100).into();
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![credit]),
+        );
+
+        let result = GetBestFilm {
+            actor_id: 31,
+            min_vote_count: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        let text = result_text(&result);
+        let overview = text.split_once("Overview: ").unwrap().1;
+        assert!(overview.ends_with("word...(truncated)"), "{overview}");
+        assert!(overview.chars().count() <= MAX_OVERVIEW_CHARS + "...(truncated)".len());
+    }
+
+    #[tokio::test]
+    async fn no_film_above_the_threshold_is_an_error() {
+        let mock = MockTmdb::start().await;
+        mock_credits(&mock);
+
+        let result = GetBestFilm {
+            actor_id: 31,
+            min_vote_count: Some(100_000),
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No films with at least 100000 votes"));
+    }
+}
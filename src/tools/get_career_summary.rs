@@ -0,0 +1,226 @@
+use crate::{
+    tmdb_client::{GenreNames, MovieCredit, TmdbClient},
+    tools::{by_count_then_name, format::format_rating, unique_movies},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::HashMap;
+
+#[mcp_tool(
+    name = "get_career_summary",
+    title = "Get Actor Career Summary",
+    description = concat!(
+        "Get a compact overview of an actor's film career by TMDB actor ID: ",
+        "first and latest film year, total number of films, average rating and most common genre.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetCareerSummary {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetCareerSummary {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let (credits, genres) = tokio::join!(
+            tmdb_client.person_movie_credits(self.actor_id),
+            tmdb_client.genre_names(),
+        );
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(summary) = CareerSummary::new(&credits, &genres) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movies were found for actor ID {}", self.actor_id),
+            )));
+        };
+
+        Ok(CallToolResult::text_content(vec![
+            summary.render(&tmdb_client.config().language).into(),
+        ]))
+    }
+}
+
+/// Statistics derived from an actor's movie credits.
+struct CareerSummary {
+    /// Number of distinct movies
+    total_films: usize,
+    /// First and latest release year, if any movie has a release date
+    span: Option<(u16, u16)>,
+    /// Mean vote average over movies that have votes
+    average_rating: Option<f64>,
+    /// Genre appearing in the most movies
+    top_genre: Option<(String, u32)>,
+}
+
+impl CareerSummary {
+    /// Summarizes the credits, or returns None if there are none.
+    fn new(credits: &[MovieCredit], genre_names: &GenreNames) -> Option<Self> {
+        let movies: Vec<_> = unique_movies(credits)
+            .into_iter()
+            .map(|credit| &credit.movie)
+            .collect();
+        if movies.is_empty() {
+            return None;
+        }
+
+        let years: Vec<u16> = movies
+            .iter()
+            .filter_map(|movie| movie.release_date.get(0..4)?.parse().ok())
+            .collect();
+        let span = years.iter().min().copied().zip(years.iter().max().copied());
+
+        let rated: Vec<f64> = movies
+            .iter()
+            .filter(|movie| movie.vote_count > 0)
+            .map(|movie| movie.vote_average)
+            .collect();
+        let average_rating =
+            (!rated.is_empty()).then(|| rated.iter().sum::<f64>() / rated.len() as f64);
+
+        let mut genre_counts: HashMap<String, u32> = HashMap::new();
+        for genre_id in movies.iter().flat_map(|movie| &movie.genre_ids) {
+            *genre_counts.entry(genre_names.name(*genre_id)).or_default() += 1;
+        }
+        let top_genre = genre_counts
+            .into_iter()
+            .min_by(|(name_a, count_a), (name_b, count_b)| {
+                by_count_then_name((*count_a, name_a), (*count_b, name_b))
+            });
+
+        Some(Self {
+            total_films: movies.len(),
+            span,
+            average_rating,
+            top_genre,
+        })
+    }
+
+    /// Renders the summary, one statistic per line.
+    fn render(&self, language: &str) -> String {
+        let span = match self.span {
+            None => "Unknown (no release dates)".to_string(),
+            Some((first, latest)) if first == latest => format!("{first} (a single year)"),
+            Some((first, latest)) => format!("{first}-{latest} ({} years)", latest - first),
+        };
+        let average_rating = self
+            .average_rating
+            .map(|rating| format!("{}/10", format_rating(rating, language)))
+            .unwrap_or_else(|| "Not rated".to_string());
+        let top_genre = self
+            .top_genre
+            .as_ref()
+            .map(|(name, count)| format!("{name} ({count} films)"))
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        format!(
+            "Career span: {span}\nTotal films: {}\nAverage rating: {average_rating}\nMost common genre: {top_genre}",
+            self.total_films
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn credit(id: i64, release_date: &str, vote_average: f64, genre_ids: &[u32]) -> Value {
+        let mut credit = fixtures::credit(id, &format!("Movie {id}"), release_date, "Lead");
+        credit["vote_average"] = json!(vote_average);
+        credit["genre_ids"] = json!(genre_ids);
+        credit
+    }
+
+    async fn summarize(cast: Vec<Value>) -> String {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31/movie_credits", fixtures::movie_credits(cast))
+            .json("/genre/movie/list", fixtures::genres());
+
+        let result = GetCareerSummary { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+        result_text(&result)
+    }
+
+    #[tokio::test]
+    async fn summarizes_a_fixed_filmography() {
+        let text = summarize(vec![
+            credit(1, "1988-06-03", 6.0, &[35]),
+            credit(2, "1994-06-23", 8.0, &[18, 35]),
+            // a second role in the same movie counts once
+            credit(2, "1994-06-23", 8.0, &[18, 35]),
+            credit(3, "2000-12-22", 7.0, &[18]),
+            credit(4, "", 9.0, &[18]),
+        ])
+        .await;
+
+        assert_eq!(
+            text,
+            "Career span: 1988-2000 (12 years)\nTotal films: 4\nAverage rating: 7.5/10\nMost common genre: Drama (3 films)"
+        );
+    }
+
+    #[tokio::test]
+    async fn a_single_film_has_a_span_of_one_year() {
+        let text = summarize(vec![credit(1, "1994-06-23", 8.0, &[])]).await;
+
+        assert!(text.contains("Career span: 1994 (a single year)"));
+        assert!(text.contains("Total films: 1"));
+        assert!(text.contains("Most common genre: Unknown"));
+    }
+
+    #[tokio::test]
+    async fn a_failing_genre_list_shows_the_genre_id() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                credit(1, "1988-06-03", 6.0, &[35]),
+                credit(2, "1994-06-23", 8.0, &[18, 35]),
+            ]),
+        )
+        .on(
+            "/genre/movie/list",
+            MockResponse::bytes(502, "text/html", "<html>"),
+        );
+
+        let result = GetCareerSummary { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, None);
+        let text = result_text(&result);
+        assert!(text.contains("Total films: 2"));
+        assert!(text.ends_with("Most common genre: genre 35 (2 films)"));
+    }
+
+    #[tokio::test]
+    async fn no_credits_is_an_error() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31/movie_credits", fixtures::movie_credits(vec![]));
+
+        let result = GetCareerSummary { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::tmdb_client::{CastMember, TmdbClient};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::HashSet;
+
+/// Number of co-stars listed; the rest of the cast is summarized as a count.
+const MAX_COSTARS: usize = 25;
+
+#[mcp_tool(
+    name = "get_costars_in_film",
+    title = "Get an Actor's Co-Stars in a Film",
+    description = concat!(
+        "Get who appeared alongside an actor in one movie, by TMDB movie ID and actor ID: ",
+        "the rest of the movie's cast in billing order, with the characters they played. ",
+        "For the co-stars an actor works with most across their career, use get_frequent_costars.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetCostarsInFilm {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+    /// TMDB ID of the actor whose co-stars to list
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetCostarsInFilm {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let (movie, credits) = tokio::join!(
+            tmdb_client.movie_details(self.movie_id),
+            tmdb_client.movie_credits(self.movie_id),
+        );
+        let movie = movie.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let (Some(movie), Some(credits)) = (movie, credits) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+
+        let title = match movie.release_year() {
+            Some(year) => format!("{} ({year})", movie.title),
+            None => movie.title.clone(),
+        };
+
+        // TMDB usually lists the cast in billing order, but don't rely on it
+        let mut cast = credits.cast;
+        cast.sort_by_key(|member| member.order);
+
+        let Some(actor) = cast.iter().find(|member| member.id == self.actor_id) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!(
+                    "The actor with the TMDB ID {} is not in the cast of {title}; get_top_cast lists who is",
+                    self.actor_id
+                ),
+            )));
+        };
+        let actor_name = actor.name.clone();
+
+        let costars = costars(cast, self.actor_id);
+        if costars.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("{actor_name} is the only cast member listed for {title}").into(),
+            ]));
+        }
+
+        let mut result = costars
+            .iter()
+            .take(MAX_COSTARS)
+            .enumerate()
+            .map(|(index, member)| {
+                let character = if member.character.is_empty() {
+                    String::new()
+                } else {
+                    format!(" as {}", member.character)
+                };
+                format!(
+                    "{}. {}{character} - TMDB ID: {}",
+                    index + 1,
+                    member.name,
+                    member.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        if costars.len() > MAX_COSTARS {
+            result.push_str(&format!("\n...and {} more", costars.len() - MAX_COSTARS));
+        }
+
+        Ok(CallToolResult::text_content(vec![
+            format!("{actor_name}'s co-stars in {title}:\n{result}").into(),
+        ]))
+    }
+}
+
+/// Returns the cast without the given actor, keeping billing order. Someone credited for
+/// several roles is listed once, under their top-billed role.
+fn costars(cast: Vec<CastMember>, actor_id: i64) -> Vec<CastMember> {
+    let mut seen = HashSet::from([actor_id]);
+    cast.into_iter()
+        .filter(|member| seen.insert(member.id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    /// Serves Apollo 13 with its cast out of billing order, Tom Hanks credited twice.
+    fn mock_apollo_13(mock: &MockTmdb) {
+        mock.json(
+            "/movie/568",
+            fixtures::movie_full(568, "Apollo 13", "1995-06-30"),
+        )
+        .json(
+            "/movie/568/credits",
+            fixtures::credits(
+                568,
+                vec![
+                    fixtures::cast_member(2144, "Bill Paxton", "Fred Haise", 2),
+                    fixtures::cast_member(31, "Tom Hanks", "Jim Lovell", 0),
+                    fixtures::cast_member(4724, "Kevin Bacon", "Jack Swigert", 1),
+                    fixtures::cast_member(31, "Tom Hanks", "Himself (archive footage)", 9),
+                    fixtures::cast_member(2176, "Gary Sinise", "", 3),
+                ],
+                vec![],
+            ),
+        );
+    }
+
+    #[test]
+    fn the_actor_is_left_out_of_the_cast() {
+        let cast: Vec<CastMember> = serde_json::from_value(serde_json::json!([
+            fixtures::cast_member(31, "Tom Hanks", "Jim Lovell", 0),
+            fixtures::cast_member(4724, "Kevin Bacon", "Jack Swigert", 1),
+            fixtures::cast_member(31, "Tom Hanks", "Himself", 2),
+            fixtures::cast_member(4724, "Kevin Bacon", "Himself", 3),
+        ]))
+        .unwrap();
+
+        let costars = costars(cast, 31);
+
+        assert_eq!(costars.len(), 1);
+        assert_eq!(costars[0].name, "Kevin Bacon");
+        assert_eq!(costars[0].character, "Jack Swigert");
+    }
+
+    #[tokio::test]
+    async fn lists_everyone_else_in_billing_order() {
+        let mock = MockTmdb::start().await;
+        mock_apollo_13(&mock);
+
+        let result = GetCostarsInFilm {
+            movie_id: 568,
+            actor_id: 31,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks's co-stars in Apollo 13 (1995):\n\
+             1. Kevin Bacon as Jack Swigert - TMDB ID: 4724\n\
+             2. Bill Paxton as Fred Haise - TMDB ID: 2144\n\
+             3. Gary Sinise - TMDB ID: 2176"
+        );
+    }
+
+    #[tokio::test]
+    async fn an_actor_not_in_the_film_is_an_error() {
+        let mock = MockTmdb::start().await;
+        mock_apollo_13(&mock);
+
+        let result = GetCostarsInFilm {
+            movie_id: 568,
+            actor_id: 5344,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(
+            result_text(&result)
+                .contains("The actor with the TMDB ID 5344 is not in the cast of Apollo 13 (1995)")
+        );
+    }
+}
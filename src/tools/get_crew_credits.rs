@@ -0,0 +1,242 @@
+use crate::tmdb_client::{CrewCredit, TmdbClient};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::BTreeMap;
+
+/// Number of titles listed per job before the rest are summarized as a count.
+const MAX_PER_JOB: usize = 15;
+
+#[mcp_tool(
+    name = "get_crew_credits",
+    title = "Get Crew Credits",
+    description = concat!(
+        "Get the movies and TV shows a person worked on behind the camera (e.g. as director, ",
+        "writer or producer), grouped by job, by TMDB person ID. ",
+        "Useful for people whose known_for_department is not Acting.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetCrewCredits {
+    /// TMDB ID of the person
+    #[json_schema(minimum = 1)]
+    pub person_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetCrewCredits {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // fetch the person and their credits at the same time
+        let language = &tmdb_client.config().language;
+        let (person, credits) = tokio::join!(
+            tmdb_client.person_details(self.person_id, language),
+            tmdb_client.person_crew_credits(self.person_id),
+        );
+        let person = person.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(person) = person else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No person with the TMDB ID {} was found", self.person_id),
+            )));
+        };
+
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        if credits.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("{} has no crew credits on TMDB", person.name).into(),
+            ]));
+        }
+
+        let result = group_by_job(&credits)
+            .into_iter()
+            .map(|(job, credits)| render_job(job, &credits))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!("Crew credits of {}:\n\n{result}", person.name).into(),
+        ]))
+    }
+}
+
+/// Groups the credits by job (alphabetically), newest first within each job, without
+/// repeating a title under the same job. Movies and shows are numbered separately on TMDB,
+/// so a title is identified by its media type and ID together.
+fn group_by_job(credits: &[CrewCredit]) -> BTreeMap<&str, Vec<&CrewCredit>> {
+    let mut jobs: BTreeMap<&str, Vec<&CrewCredit>> = BTreeMap::new();
+    for credit in credits {
+        let titles = jobs.entry(credit.job.as_str()).or_default();
+        if !titles
+            .iter()
+            .any(|existing| existing.id == credit.id && existing.media_type == credit.media_type)
+        {
+            titles.push(credit);
+        }
+    }
+    for titles in jobs.values_mut() {
+        // undated (usually unreleased) titles first, then newest first
+        titles.sort_by(|a, b| {
+            (!a.date().is_empty(), b.date()).cmp(&(!b.date().is_empty(), a.date()))
+        });
+    }
+    jobs
+}
+
+/// Renders a job heading followed by its titles, e.g. `- Jaws (1975)` or `- Amazing Stories (1985, TV)`.
+fn render_job(job: &str, credits: &[&CrewCredit]) -> String {
+    let mut lines = vec![format!("{job} ({}):", credits.len())];
+    for credit in credits.iter().take(MAX_PER_JOB) {
+        let mut details: Vec<&str> = Vec::new();
+        if let Some(year) = credit.date().get(0..4) {
+            details.push(year);
+        }
+        if credit.media_type == "tv" {
+            details.push("TV");
+        }
+        let details = if details.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", details.join(", "))
+        };
+        lines.push(format!(
+            "- {}{details} - TMDB ID: {}",
+            credit.display_title(),
+            credit.id
+        ));
+    }
+    if credits.len() > MAX_PER_JOB {
+        lines.push(format!("- ...and {} more", credits.len() - MAX_PER_JOB));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn crew_credit(id: i64, media_type: &str, title: &str, date: &str, job: &str) -> Value {
+        let (title_field, date_field) = if media_type == "tv" {
+            ("name", "first_air_date")
+        } else {
+            ("title", "release_date")
+        };
+        json!({
+            "id": id,
+            "media_type": media_type,
+            title_field: title,
+            date_field: date,
+            "department": if job == "Director" { "Directing" } else { "Writing" },
+            "job": job,
+        })
+    }
+
+    fn combined_credits(crew: Vec<Value>) -> Value {
+        json!({ "id": 488, "cast": [], "crew": crew })
+    }
+
+    #[test]
+    fn parses_crew_entries_and_their_jobs() {
+        let credits: Vec<CrewCredit> = serde_json::from_value(json!([
+            crew_credit(578, "movie", "Jaws", "1975-06-20", "Director"),
+            crew_credit(1915, "tv", "Amazing Stories", "1985-09-29", "Screenplay"),
+        ]))
+        .unwrap();
+
+        assert_eq!(credits[0].display_title(), "Jaws");
+        assert_eq!(credits[0].date(), "1975-06-20");
+        assert_eq!(credits[0].department, "Directing");
+        assert_eq!(credits[0].job, "Director");
+        assert_eq!(credits[1].display_title(), "Amazing Stories");
+        assert_eq!(credits[1].date(), "1985-09-29");
+        assert_eq!(credits[1].job, "Screenplay");
+    }
+
+    #[test]
+    fn a_movie_and_a_show_sharing_an_id_are_both_kept() {
+        let credits: Vec<CrewCredit> = serde_json::from_value(json!([
+            crew_credit(100, "movie", "A Movie", "2001-01-01", "Director"),
+            crew_credit(100, "tv", "A Show", "2002-01-01", "Director"),
+            crew_credit(100, "movie", "A Movie", "2001-01-01", "Director"),
+        ]))
+        .unwrap();
+
+        let jobs = group_by_job(&credits);
+        let titles: Vec<&str> = jobs["Director"]
+            .iter()
+            .map(|credit| credit.display_title())
+            .collect();
+        assert_eq!(titles, ["A Show", "A Movie"]);
+    }
+
+    #[tokio::test]
+    async fn lists_credits_grouped_by_job() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/488", fixtures::person(488, "Steven Spielberg"));
+        mock.json(
+            "/person/488/combined_credits",
+            combined_credits(vec![
+                crew_credit(578, "movie", "Jaws", "1975-06-20", "Director"),
+                crew_credit(329, "movie", "Jurassic Park", "1993-06-11", "Director"),
+                crew_credit(1915, "tv", "Amazing Stories", "1985-09-29", "Screenplay"),
+            ]),
+        );
+
+        let result = GetCrewCredits { person_id: 488 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Crew credits of Steven Spielberg:\n\n\
+             Director (2):\n\
+             - Jurassic Park (1993) - TMDB ID: 329\n\
+             - Jaws (1975) - TMDB ID: 578\n\n\
+             Screenplay (1):\n\
+             - Amazing Stories (1985, TV) - TMDB ID: 1915"
+        );
+    }
+
+    #[tokio::test]
+    async fn a_person_without_crew_credits_says_so() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"));
+        mock.json("/person/31/combined_credits", combined_credits(vec![]));
+
+        let result = GetCrewCredits { person_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks has no crew credits on TMDB"
+        );
+    }
+
+    #[tokio::test]
+    async fn an_unknown_person_is_an_error() {
+        let mock = MockTmdb::start().await;
+
+        let result = GetCrewCredits { person_id: 999 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No person with the TMDB ID 999"));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient},
+    tools::{PageInfo, registry::DynamicTool},
+};
+use async_trait::async_trait;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult, Tool},
+};
+use serde_json::{Map, Value};
+
+/// Only offered when `TMDB_SESSION_ID` is set (see `ToolRegistry::new`).
+#[mcp_tool(
+    name = "get_favorite_movies",
+    title = "Get My Favorite Movies",
+    description = "List the movies marked as favorites on the TMDB account this server is signed in to.",
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetFavoriteMovies {}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetFavoriteMovies {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let page = tmdb_client
+            .account_favorites()
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let page_info = PageInfo::of(&page);
+        let movies = page.results;
+
+        if movies.is_empty() {
+            return Ok(page_info.attach_to(CallToolResult::text_content(vec![
+                "No favorite movies are saved on this TMDB account".into(),
+            ])));
+        }
+
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = movies
+            .iter()
+            .enumerate()
+            .map(|(index, movie)| {
+                format!(
+                    "{}. {} - TMDB ID: {}",
+                    index + 1,
+                    movie.render(missing),
+                    movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![
+            format!("Favorite movies:\n{result}").into(),
+        ])))
+    }
+}
+
+/// Registers `get_favorite_movies` with a `ToolRegistry`. Tools registered at runtime are not
+/// part of `tool_box!`, so this parses the call arguments itself.
+pub struct FavoriteMoviesTool;
+
+#[async_trait]
+impl DynamicTool for FavoriteMoviesTool {
+    fn tool(&self) -> Tool {
+        GetFavoriteMovies::tool()
+    }
+
+    async fn call(
+        &self,
+        tmdb_client: &TmdbClient,
+        arguments: Map<String, Value>,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let request: GetFavoriteMovies =
+            serde_json::from_value(Value::Object(arguments)).map_err(|err| {
+                CallToolError::invalid_arguments(
+                    GetFavoriteMovies::tool_name(),
+                    Some(err.to_string()),
+                )
+            })?;
+        request.invoke(tmdb_client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    async fn account_with(movies: Value) -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json("/account", fixtures::account(42, "fan"))
+            .json("/account/42/favorite/movies", movies);
+        mock
+    }
+
+    fn favorites() -> GetFavoriteMovies {
+        GetFavoriteMovies {}
+    }
+
+    #[tokio::test]
+    async fn lists_the_favorites_with_page_info() {
+        let mock = account_with(fixtures::page(
+            1,
+            2,
+            21,
+            vec![fixtures::movie(13, "Forrest Gump", "1994-06-23")],
+        ))
+        .await;
+        let client = mock.client_with(|config| config.session_id = Some("session".into()));
+
+        let result = favorites().invoke(&client).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Favorite movies:\n1. Forrest Gump (1994) - TMDB ID: 13"
+        );
+        assert_eq!(
+            Value::Object(result.structured_content.unwrap()),
+            json!({ "page": 1, "total_pages": 2, "total_results": 21, "has_more": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn an_empty_list_still_has_page_info() {
+        let mock = account_with(fixtures::single_page(vec![])).await;
+        let client = mock.client_with(|config| config.session_id = Some("session".into()));
+
+        let result = favorites().invoke(&client).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "No favorite movies are saved on this TMDB account"
+        );
+        assert_eq!(result.structured_content.unwrap()["total_results"], 0);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    tmdb_client::{GenreNames, MovieCredit, TmdbClient},
+    tools::{format::compare_names, unique_movies},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "get_filmography_csv",
+    title = "Get Filmography as CSV",
+    description = concat!(
+        "Get an actor's filmography, by TMDB actor ID, as CSV text that can be pasted into a spreadsheet. ",
+        "Columns: title, year, rating, vote_count, genres. Movies are listed in release order.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetFilmographyCsv {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetFilmographyCsv {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let (credits, genres) = tokio::join!(
+            tmdb_client.person_movie_credits(self.actor_id),
+            tmdb_client.genre_names(),
+        );
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        if credits.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movies were found for actor ID {}", self.actor_id),
+            )));
+        }
+
+        // one row per movie,
+        // in release order with undated movies last, then by title
+        let mut credits = unique_movies(credits);
+        credits.sort_by(|a, b| {
+            let (a, b) = (&a.movie, &b.movie);
+            (a.release_date.is_empty(), &a.release_date)
+                .cmp(&(b.release_date.is_empty(), &b.release_date))
+                .then_with(|| compare_names(&a.title, &b.title))
+                .then(a.id.cmp(&b.id))
+        });
+
+        Ok(CallToolResult::text_content(vec![
+            render_csv(&credits, &genres).into(),
+        ]))
+    }
+}
+
+/// Renders the credits as CSV with a header row.
+fn render_csv(credits: &[MovieCredit], genre_names: &GenreNames) -> String {
+    let mut csv = String::from("title,year,rating,vote_count,genres\n");
+    for credit in credits {
+        let movie = &credit.movie;
+        let genres = movie
+            .genre_ids
+            .iter()
+            .map(|id| genre_names.name(*id))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let row = [
+            movie.title.clone(),
+            movie.release_date.get(0..4).unwrap_or_default().to_string(),
+            format!("{:.1}", movie.vote_average),
+            movie.vote_count.to_string(),
+            genres,
+        ];
+        let row = row
+            .iter()
+            .map(|field| escape_csv_field(field))
+            .collect::<Vec<_>>();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quotes a field if it contains a comma, quote or line break, doubling any quotes (RFC 4180).
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    #[tokio::test]
+    async fn renders_one_row_per_movie_in_release_order() {
+        let mock = MockTmdb::start().await;
+        let mut gump = fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump");
+        gump["genre_ids"] = serde_json::json!([35, 18]);
+        mock.json("/genre/movie/list", fixtures::genres()).json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                fixtures::credit(1, "Untitled \"Project\"", "", ""),
+                gump,
+                fixtures::credit(2, "Big, the Movie", "1988-06-03", "Josh"),
+                fixtures::credit(2, "Big, the Movie", "1988-06-03", "Josh (adult)"),
+            ]),
+        );
+
+        let result = GetFilmographyCsv { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "title,year,rating,vote_count,genres\n\
+             \"Big, the Movie\",1988,7.0,1000,\n\
+             Forrest Gump,1994,7.0,1000,Comedy; Drama\n\
+             \"Untitled \"\"Project\"\"\",,7.0,1000,\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn genre_ids_are_shown_when_the_genre_list_is_unavailable() {
+        let mock = MockTmdb::start().await;
+        let mut gump = fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump");
+        gump["genre_ids"] = serde_json::json!([35]);
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![gump]),
+        )
+        .on(
+            "/genre/movie/list",
+            MockResponse::bytes(502, "text/html", "<html>"),
+        );
+
+        let result = GetFilmographyCsv { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, None);
+        assert!(result_text(&result).ends_with("Forrest Gump,1994,7.0,1000,genre 35\n"));
+    }
+}
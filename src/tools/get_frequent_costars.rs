@@ -0,0 +1,235 @@
+use crate::{
+    tmdb_client::TmdbClient,
+    tools::{BatchFailures, by_count_then_name, unique_movies},
+};
+use futures::stream::{self, StreamExt};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Number of the actor's most popular movies whose casts are inspected.
+const MAX_MOVIES: usize = 20;
+
+/// Number of cast lists fetched at the same time.
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// Number of co-stars listed in the result.
+const MAX_COSTARS: usize = 10;
+
+#[mcp_tool(
+    name = "get_frequent_costars",
+    title = "Get Frequent Co-stars",
+    description = concat!(
+        "Find the actors who most often appear alongside a given actor, by TMDB actor ID. ",
+        "Looks at the casts of the actor's most popular movies and counts how often each co-star appears.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetFrequentCostars {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetFrequentCostars {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let mut movies = tmdb_client
+            .person_movie_credits(self.actor_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        if movies.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movies were found for actor ID {}", self.actor_id),
+            )));
+        }
+
+        // only inspect the most popular movies to keep the number of requests reasonable,
+        // once each even if the actor played several roles in one
+        movies.sort_by(|a, b| b.movie.popularity.total_cmp(&a.movie.popularity));
+        let mut movies = unique_movies(movies);
+        movies.truncate(MAX_MOVIES);
+
+        // fetch the casts a few at a time
+        let movie_ids: Vec<i64> = movies.iter().map(|credit| credit.movie.id).collect();
+        let casts = stream::iter(movie_ids)
+            .map(|movie_id| async move { (movie_id, tmdb_client.movie_credits(movie_id).await) })
+            .buffer_unordered(CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        // count the movies each co-star appears in, keyed by person id, skipping casts that
+        // could not be fetched rather than failing the whole tally
+        let mut tally: HashMap<i64, (String, u32)> = HashMap::new();
+        let mut failures = BatchFailures::default();
+        let mut inspected = 0;
+        for (movie_id, cast) in casts {
+            let credits = match cast {
+                Ok(Some(credits)) => credits,
+                Ok(None) => continue,
+                Err(err) => {
+                    failures.push(format!("cast of movie {movie_id}"), err);
+                    continue;
+                }
+            };
+            inspected += 1;
+            // a co-star credited for several roles in one movie still counts once for it
+            let mut counted = HashSet::from([self.actor_id]);
+            for member in credits.cast {
+                if counted.insert(member.id) {
+                    tally.entry(member.id).or_insert((member.name, 0)).1 += 1;
+                }
+            }
+        }
+
+        let mut costars: Vec<_> = tally.into_iter().collect();
+        costars.sort_by(|(_, (name_a, count_a)), (_, (name_b, count_b))| {
+            by_count_then_name((*count_a, name_a), (*count_b, name_b))
+        });
+        costars.truncate(MAX_COSTARS);
+
+        if failures.len() == movies.len() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                failures.to_string(),
+            )));
+        }
+
+        if costars.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                "No co-stars were found".into(),
+            ]));
+        }
+
+        let result = costars
+            .iter()
+            .enumerate()
+            .map(|(index, (id, (name, count)))| {
+                let films = if *count == 1 { "film" } else { "films" };
+                format!("{}. {name} - {count} {films} (TMDB ID: {id})", index + 1)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut result = format!("Most frequent co-stars across {inspected} movies:\n{result}");
+        if !failures.is_empty() {
+            result.push_str(&format!("\n\n{failures}"));
+        }
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    /// A movie's credits with `members` (ID, name, character) billed in order.
+    fn cast(movie_id: i64, members: &[(i64, &str, &str)]) -> Value {
+        let cast = members
+            .iter()
+            .zip(0..)
+            .map(|((id, name, character), order)| {
+                fixtures::cast_member(*id, name, character, order)
+            })
+            .collect();
+        fixtures::credits(movie_id, cast, vec![])
+    }
+
+    #[tokio::test]
+    async fn counts_each_movie_and_costar_once() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                // two roles in Cloud Atlas are two credits for the same movie
+                fixtures::credit(83542, "Cloud Atlas", "2012-10-26", "Zachry"),
+                fixtures::credit(83542, "Cloud Atlas", "2012-10-26", "Dr. Henry Goose"),
+                fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump"),
+                fixtures::credit(404, "Lost Film", "2000-01-01", "Himself"),
+            ]),
+        )
+        .json(
+            "/movie/83542/credits",
+            cast(
+                83542,
+                &[
+                    (31, "Tom Hanks", "Zachry"),
+                    (31, "Tom Hanks", "Dr. Henry Goose"),
+                    (1245, "Halle Berry", "Meronym"),
+                    (1245, "Halle Berry", "Luisa Rey"),
+                    (2227, "Hugo Weaving", "Old Georgie"),
+                ],
+            ),
+        )
+        .json(
+            "/movie/13/credits",
+            cast(
+                13,
+                &[
+                    (31, "Tom Hanks", "Forrest Gump"),
+                    (1245, "Halle Berry", "Extra"),
+                ],
+            ),
+        );
+
+        let result = GetFrequentCostars { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Most frequent co-stars across 2 movies:\n\
+             1. Halle Berry - 2 films (TMDB ID: 1245)\n\
+             2. Hugo Weaving - 1 film (TMDB ID: 2227)"
+        );
+        assert_eq!(mock.hits("/movie/83542/credits"), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_casts_are_listed_and_not_counted() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump"),
+                fixtures::credit(862, "Toy Story", "1995-10-30", "Woody"),
+            ]),
+        )
+        .json(
+            "/movie/13/credits",
+            cast(
+                13,
+                &[
+                    (31, "Tom Hanks", "Forrest Gump"),
+                    (32, "Robin Wright", "Jenny"),
+                ],
+            ),
+        )
+        .on("/movie/862/credits", MockResponse::status(500, json!({})));
+
+        let result = GetFrequentCostars { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Most frequent co-stars across 1 movies:\n1. Robin Wright"));
+        assert!(text.contains("cast of movie 862"));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{
+    tmdb_client::{MovieCredits, TmdbClient},
+    tools::ResponseFormat,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::BTreeMap;
+
+/// Number of top-billed cast members listed.
+const MAX_CAST: usize = 15;
+
+/// Number of top-billed cast members listed in terse responses.
+const TERSE_CAST: usize = 5;
+
+/// Crew jobs worth listing; everyone else in the crew is left out.
+const KEY_JOBS: &[&str] = &[
+    "Director",
+    "Screenplay",
+    "Writer",
+    "Story",
+    "Novel",
+    "Producer",
+    "Original Music Composer",
+    "Director of Photography",
+    "Editor",
+];
+
+#[mcp_tool(
+    name = "get_movie_credits",
+    title = "Get Movie Cast and Crew",
+    description = concat!(
+        "Get the cast and key crew of a movie by its TMDB ID. ",
+        "Lists the director(s), the top-billed cast with their characters, ",
+        "and key crew such as writers, producers and the composer grouped by department.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetMovieCredits {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetMovieCredits {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+        format: ResponseFormat,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let credits = tmdb_client
+            .movie_credits(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(credits) = credits else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+
+        let result = match format {
+            ResponseFormat::Detailed => render_credits(&credits),
+            ResponseFormat::Terse => render_terse_credits(&credits),
+        };
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+/// Renders the director(s) and the first few top-billed cast members on two lines.
+fn render_terse_credits(credits: &MovieCredits) -> String {
+    let directors = credits
+        .directors()
+        .map(|director| director.name.as_str())
+        .collect::<Vec<_>>();
+    let cast = credits
+        .cast
+        .iter()
+        .take(TERSE_CAST)
+        .map(|member| member.name.as_str())
+        .collect::<Vec<_>>();
+
+    format!(
+        "Directed by: {}\nStarring: {}",
+        if directors.is_empty() {
+            "Unknown".to_string()
+        } else {
+            directors.join(", ")
+        },
+        if cast.is_empty() {
+            "Unknown".to_string()
+        } else {
+            cast.join(", ")
+        }
+    )
+}
+
+/// Renders the director(s) first, then the top-billed cast, then key crew grouped by department.
+fn render_credits(credits: &MovieCredits) -> String {
+    let directors = credits
+        .directors()
+        .map(|director| director.name.as_str())
+        .collect::<Vec<_>>();
+    let mut result = if directors.is_empty() {
+        "Directed by: Unknown\n".to_string()
+    } else {
+        format!("Directed by: {}\n", directors.join(", "))
+    };
+
+    result.push_str("\nCast:\n");
+    if credits.cast.is_empty() {
+        result.push_str("No cast information available\n");
+    }
+    for (index, member) in credits.cast.iter().take(MAX_CAST).enumerate() {
+        if member.character.is_empty() {
+            result.push_str(&format!("{}. {}\n", index + 1, member.name));
+        } else {
+            result.push_str(&format!(
+                "{}. {} as {}\n",
+                index + 1,
+                member.name,
+                member.character
+            ));
+        }
+    }
+    if credits.cast.len() > MAX_CAST {
+        result.push_str(&format!("...and {} more\n", credits.cast.len() - MAX_CAST));
+    }
+
+    // group key crew by department, listing each person once per job
+    let mut departments: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for member in &credits.crew {
+        if !KEY_JOBS.contains(&member.job.as_str()) {
+            continue;
+        }
+        let entry = format!("{} ({})", member.name, member.job);
+        let people = departments.entry(member.department.as_str()).or_default();
+        if !people.contains(&entry) {
+            people.push(entry);
+        }
+    }
+
+    if !departments.is_empty() {
+        result.push_str("\nCrew:\n");
+        for (department, people) in departments {
+            result.push_str(&format!("{department}: {}\n", people.join(", ")));
+        }
+    }
+
+    result.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    async fn mock_credits() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/13/credits",
+            fixtures::credits(
+                13,
+                vec![
+                    fixtures::cast_member(31, "Tom Hanks", "Forrest Gump", 0),
+                    fixtures::cast_member(32, "Robin Wright", "Jenny Curran", 1),
+                    fixtures::cast_member(33, "Gary Sinise", "", 2),
+                ],
+                vec![
+                    fixtures::crew_member(24, "Robert Zemeckis", "Director"),
+                    fixtures::crew_member(25, "Eric Roth", "Screenplay"),
+                    fixtures::crew_member(25, "Eric Roth", "Screenplay"),
+                    fixtures::crew_member(26, "Key Grip", "Grip"),
+                ],
+            ),
+        );
+        mock
+    }
+
+    #[tokio::test]
+    async fn lists_directors_cast_and_key_crew() {
+        let mock = mock_credits().await;
+
+        let result = GetMovieCredits { movie_id: 13 }
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Directed by: Robert Zemeckis\n\
+             \n\
+             Cast:\n\
+             1. Tom Hanks as Forrest Gump\n\
+             2. Robin Wright as Jenny Curran\n\
+             3. Gary Sinise\n\
+             \n\
+             Crew:\n\
+             Directing: Robert Zemeckis (Director)\n\
+             Writing: Eric Roth (Screenplay)"
+        );
+    }
+
+    #[tokio::test]
+    async fn terse_credits_fit_on_two_lines() {
+        let mock = mock_credits().await;
+
+        let result = GetMovieCredits { movie_id: 13 }
+            .invoke(&mock.client(), ResponseFormat::Terse)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Directed by: Robert Zemeckis\nStarring: Tom Hanks, Robin Wright, Gary Sinise"
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieFull, TmdbClient, TmdbConfig},
+    tools::{
+        format::{format_date, format_rating},
+        raw_not_allowed,
+        registry::DynamicTool,
+    },
+};
+use async_trait::async_trait;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult, Tool},
+};
+use serde_json::{Map, Value};
+
+/// Experimental: only offered when `TMDB_EXPERIMENTAL_TOOLS` is set (see `ToolRegistry::new`).
+#[mcp_tool(
+    name = "get_movie_details",
+    title = "Get Movie Details",
+    description = concat!(
+        "Get the details of a movie by TMDB movie ID: release date, runtime, genres, ",
+        "languages, production countries, rating, tagline and overview. Experimental.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetMovieDetails {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+    /// Return TMDB's unmodified JSON response instead of the formatted details (only if the
+    /// server allows it)
+    pub raw: Option<bool>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetMovieDetails {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        if self.raw.unwrap_or(false) {
+            return self.invoke_raw(tmdb_client).await;
+        }
+
+        let movie = tmdb_client
+            .movie_details(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(movie) = movie else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+
+        Ok(CallToolResult::text_content(vec![
+            render_details(&movie, tmdb_client.config()).into(),
+        ]))
+    }
+
+    /// Returns the movie's details as TMDB sent them, if `TMDB_ALLOW_RAW` permits it.
+    async fn invoke_raw(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        if !tmdb_client.config().allow_raw {
+            return Ok(CallToolResult::with_error(raw_not_allowed()));
+        }
+        let body = tmdb_client
+            .movie_details_raw(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(body) = body else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+        Ok(CallToolResult::text_content(vec![body.into()]))
+    }
+}
+
+/// Registers `get_movie_details` with a `ToolRegistry`. Tools registered at runtime are not
+/// part of `tool_box!`, so this parses the call arguments itself.
+pub struct MovieDetailsTool;
+
+#[async_trait]
+impl DynamicTool for MovieDetailsTool {
+    fn tool(&self) -> Tool {
+        GetMovieDetails::tool()
+    }
+
+    async fn call(
+        &self,
+        tmdb_client: &TmdbClient,
+        arguments: Map<String, Value>,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let request: GetMovieDetails =
+            serde_json::from_value(Value::Object(arguments)).map_err(|err| {
+                CallToolError::invalid_arguments(
+                    GetMovieDetails::tool_name(),
+                    Some(err.to_string()),
+                )
+            })?;
+        request.invoke(tmdb_client).await
+    }
+}
+
+/// Renders the movie as one labelled field per line, followed by the overview. Unknown fields
+/// are labelled or left out according to `TMDB_MISSING_FIELDS`.
+fn render_details(movie: &MovieFull, config: &TmdbConfig) -> String {
+    let (language, missing) = (
+        config.language.as_str(),
+        config.missing_fields_or(MissingFields::Label),
+    );
+    let runtime = movie
+        .runtime
+        .filter(|minutes| *minutes > 0)
+        .map(|minutes| format!("{minutes} min"));
+    let released = Some(movie.release_date.trim())
+        .filter(|date| !date.is_empty())
+        .map(|date| format_date(date, language));
+    let genres = movie
+        .genres
+        .iter()
+        .map(|genre| genre.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let languages = movie
+        .spoken_languages
+        .iter()
+        .map(|language| {
+            if language.english_name.is_empty() {
+                language.iso_639_1.as_str()
+            } else {
+                language.english_name.as_str()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let countries = movie
+        .production_countries
+        .iter()
+        .map(|country| country.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let rating = if movie.vote_count == 0 {
+        "Not rated".to_string()
+    } else {
+        format!(
+            "{}/10 ({} votes)",
+            format_rating(movie.vote_average, language),
+            movie.vote_count
+        )
+    };
+
+    let mut result = format!("{} - TMDB ID: {}", movie.title, movie.id);
+    if let Some(tagline) = movie
+        .tagline
+        .as_deref()
+        .filter(|tagline| !tagline.is_empty())
+    {
+        result.push_str(&format!("\n\"{tagline}\""));
+    }
+    for line in [
+        missing.field("Released", released.as_deref()),
+        missing.field("Runtime", runtime.as_deref()),
+        missing.field("Genres", Some(&genres)),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        result.push_str(&format!("\n{line}"));
+    }
+    // both on one line, e.g. "Languages: English, French; Countries: United States of America"
+    let origin = [
+        missing.field("Languages", Some(&languages)),
+        missing.field("Countries", Some(&countries)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    if !origin.is_empty() {
+        result.push_str(&format!("\n{}", origin.join("; ")));
+    }
+    result.push_str(&format!("\nRating: {rating}"));
+    if !movie.overview.is_empty() {
+        result.push_str(&format!("\n\n{}", movie.overview));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::{
+        MissingFields,
+        test_server::{MockTmdb, fixtures},
+    };
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    fn french_connection() -> Value {
+        let mut movie = fixtures::movie_full(1051, "The French Connection", "1971-10-09");
+        movie["spoken_languages"] = json!([
+            { "english_name": "English", "iso_639_1": "en", "name": "English" },
+            { "english_name": "French", "iso_639_1": "fr", "name": "Français" },
+        ]);
+        movie["production_countries"] = json!([
+            { "iso_3166_1": "US", "name": "United States of America" },
+        ]);
+        movie
+    }
+
+    #[test]
+    fn deserializes_languages_and_countries_with_their_iso_codes() {
+        let movie: MovieFull = serde_json::from_value(french_connection()).unwrap();
+
+        let languages: Vec<_> = movie
+            .spoken_languages
+            .iter()
+            .map(|language| (language.iso_639_1.as_str(), language.english_name.as_str()))
+            .collect();
+        assert_eq!(languages, [("en", "English"), ("fr", "French")]);
+        assert_eq!(movie.production_countries[0].iso_3166_1, "US");
+        assert_eq!(
+            movie.production_countries[0].name,
+            "United States of America"
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_languages_and_countries_on_one_line() {
+        let mock = MockTmdb::start().await;
+        mock.json("/movie/1051", french_connection());
+
+        let result = GetMovieDetails {
+            movie_id: 1051,
+            raw: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert!(
+            result_text(&result)
+                .contains("\nLanguages: English, French; Countries: United States of America\n")
+        );
+    }
+
+    #[test]
+    fn empty_languages_and_countries_follow_the_missing_fields_setting() {
+        let movie: MovieFull =
+            serde_json::from_value(fixtures::movie_full(1, "Untitled", "2030-01-01")).unwrap();
+        let mut config = TmdbConfig::with_defaults(String::new(), String::new());
+
+        config.missing_fields = Some(MissingFields::Label);
+        assert!(
+            render_details(&movie, &config).contains("\nLanguages: Unknown; Countries: Unknown\n")
+        );
+
+        config.missing_fields = Some(MissingFields::Omit);
+        let text = render_details(&movie, &config);
+        assert!(!text.contains("Languages") && !text.contains("Countries"));
+    }
+}
@@ -0,0 +1,220 @@
+use crate::tmdb_client::TmdbClient;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult, ContentBlock},
+};
+
+/// Number of images returned per call.
+const MAX_IMAGES: usize = 3;
+
+/// Kind of movie image, advertised to clients as a schema enum.
+#[derive(
+    ::serde::Deserialize, ::serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema,
+)]
+pub enum ImageType {
+    #[serde(rename = "poster")]
+    Poster,
+    #[serde(rename = "backdrop")]
+    Backdrop,
+}
+
+impl ImageType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ImageType::Poster => "poster",
+            ImageType::Backdrop => "backdrop",
+        }
+    }
+}
+
+#[mcp_tool(
+    name = "get_movie_images",
+    title = "Get Movie Posters or Backdrops",
+    description = concat!(
+        "Get a few of the best rated posters or backdrops of a movie by its TMDB ID. ",
+        "Specify `image_type` as \"poster\" or \"backdrop\". The images are returned inline.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetMovieImages {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+    /// Kind of image to return: "poster" or "backdrop"
+    pub image_type: ImageType,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetMovieImages {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let images = tmdb_client
+            .movie_images(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(images) = images else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+
+        let image_type = self.image_type.as_str();
+        let mut images = match self.image_type {
+            ImageType::Poster => images.posters,
+            ImageType::Backdrop => images.backdrops,
+        };
+        if images.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("Movie {} has no {image_type}s", self.movie_id).into(),
+            ]));
+        }
+
+        // best rated first
+        images.sort_by(|a, b| b.vote_average.total_cmp(&a.vote_average));
+        let total = images.len();
+        images.truncate(MAX_IMAGES);
+
+        // download each image through the same path (and configured size) as profile images
+        let mut content = vec![ContentBlock::text_content(format!(
+            "Showing {} of {total} {image_type}s for movie {}",
+            images.len(),
+            self.movie_id
+        ))];
+        for image in &images {
+            match tmdb_client.image_as_base64(&image.file_path).await {
+                Ok(image_data) => {
+                    content.push(ContentBlock::image_content(image_data, "image/jpeg".into()))
+                }
+                Err(err) => content.push(ContentBlock::text_content(format!(
+                    "(Image {} could not be loaded: {err})",
+                    image.file_path
+                ))),
+            }
+        }
+
+        Ok(CallToolResult::from_content(content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    #[test]
+    fn the_schema_lists_the_image_types() {
+        let schema = GetMovieImages::json_schema();
+        assert_eq!(
+            schema["properties"]["image_type"]["oneOf"],
+            json!([{ "enum": ["poster"] }, { "enum": ["backdrop"] }])
+        );
+
+        let invalid = serde_json::from_value::<GetMovieImages>(
+            json!({ "movie_id": 550, "image_type": "logo" }),
+        );
+        assert!(invalid.is_err());
+    }
+
+    fn image(file_path: &str, vote_average: f64) -> serde_json::Value {
+        json!({
+            "aspect_ratio": 0.667,
+            "file_path": file_path,
+            "width": 1000,
+            "height": 1500,
+            "iso_639_1": "en",
+            "vote_average": vote_average,
+            "vote_count": 10
+        })
+    }
+
+    #[tokio::test]
+    async fn returns_the_best_rated_images_inline() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/13/images",
+            json!({
+                "id": 13,
+                "posters": [
+                    image("/p1.jpg", 5.0),
+                    image("/p2.jpg", 5.5),
+                    image("/p3.jpg", 4.0),
+                    image("/p4.jpg", 5.2),
+                    image("/p5.jpg", 3.0)
+                ],
+                "backdrops": [],
+                "logos": []
+            }),
+        );
+        for path in ["/w92/p1.jpg", "/w92/p2.jpg", "/w92/p4.jpg"] {
+            mock.on(path, MockResponse::bytes(200, "image/jpeg", "jpeg"));
+        }
+
+        let result = GetMovieImages {
+            movie_id: 13,
+            image_type: ImageType::Poster,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result_text(&result), "Showing 3 of 5 posters for movie 13");
+        let images = result
+            .content
+            .iter()
+            .filter(|block| matches!(block, ContentBlock::ImageContent(_)))
+            .count();
+        assert_eq!(images, 3);
+        assert_eq!(mock.hits("/w92/p3.jpg"), 0);
+    }
+
+    #[tokio::test]
+    async fn images_that_fail_to_load_are_noted() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/13/images",
+            json!({ "id": 13, "posters": [], "backdrops": [image("/b1.jpg", 5.0)], "logos": [] }),
+        );
+
+        let result = GetMovieImages {
+            movie_id: 13,
+            image_type: ImageType::Backdrop,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Showing 1 of 1 backdrops for movie 13"));
+        assert!(text.contains("(Image /b1.jpg could not be loaded: "));
+    }
+
+    #[tokio::test]
+    async fn a_movie_without_images_of_the_type_is_noted() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/13/images",
+            json!({ "id": 13, "posters": [], "backdrops": [], "logos": [] }),
+        );
+
+        let result = GetMovieImages {
+            movie_id: 13,
+            image_type: ImageType::Poster,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result_text(&result), "Movie 13 has no posters");
+    }
+}
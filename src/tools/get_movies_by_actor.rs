@@ -1,15 +1,22 @@
-use crate::tmdb_client::TmdbClient;
+use crate::{
+    tmdb_client::{MissingFields, MovieCredit, TmdbClient},
+    tools::{ResponseFormat, format::format_rating},
+};
 use rust_mcp_sdk::{
     macros::{JsonSchema, mcp_tool},
     schema::{CallToolError, CallToolResult},
 };
 
+/// Number of movies listed in terse responses.
+const TERSE_LIMIT: usize = 10;
+
 #[mcp_tool(
     name = "get_movies_by_actor",
         title = "Get Movies by Actor ID",
         description = concat!(
-            "Retrieve a list of movies featuring a specific actor. ",
-            "Specify `actor_id` to search for movies that the actor appeared in. ",
+            "Retrieve a list of movies featuring a specific actor, including the character they played. ",
+            "Specify either `actor_id` or `actor_name` (but not both) to search for movies that the actor appeared in. ",
+            "Set `as_markdown` to get the list as a markdown table with title, year and rating columns.",
         ),
     icons = [
         (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
@@ -19,8 +26,14 @@ use rust_mcp_sdk::{
 )]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
 pub struct GetMoviesByActor {
-    /// Required filter: return movies for this actor ID
-    pub actor_id: i64,
+    /// Return movies for this actor ID. Provide either this or `actor_name`.
+    #[json_schema(minimum = 1)]
+    pub actor_id: Option<i64>,
+    /// Return movies for the actor with this name. Provide either this or `actor_id`.
+    #[json_schema(min_length = 1)]
+    pub actor_name: Option<String>,
+    /// Render the movies as a markdown table (Title, Year, Rating) instead of a numbered list
+    pub as_markdown: Option<bool>,
 }
 
 // Implements the `invoke` function, which is executed whenever the client calls this tool.
@@ -29,18 +42,72 @@ impl GetMoviesByActor {
     pub async fn invoke(
         &self,
         tmdb_client: &TmdbClient,
+        format: ResponseFormat,
     ) -> std::result::Result<CallToolResult, CallToolError> {
-        // retrieve list of movies the actor appeared in
-        let movies = tmdb_client
-            .movies_by_actor(self.actor_id)
-            .await
-            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        // resolve the actor id, looking it up by name if needed
+        let actor_id = match (self.actor_id, self.actor_name.as_deref()) {
+            (Some(actor_id), None) => actor_id,
+            (None, Some(actor_name)) => {
+                let actor_id = tmdb_client
+                    .actor_id(actor_name)
+                    .await
+                    .map_err(|err| CallToolError::from_message(err.to_string()))?;
+                let Some(actor_id) = actor_id else {
+                    return Ok(CallToolResult::with_error(CallToolError::from_message(
+                        format!("No actors matching the name \"{actor_name}\" were found"),
+                    )));
+                };
+                actor_id
+            }
+            _ => {
+                return Ok(CallToolResult::with_error(CallToolError::from_message(
+                    "Provide exactly one of `actor_id` or `actor_name`",
+                )));
+            }
+        };
 
-        // return a error response if no moview were found
-        if movies.is_empty() {
+        // retrieve list of movies the actor appeared in, checking the actor exists at the same time
+        let language = &tmdb_client.config().language;
+        let (actor, movies) = tokio::join!(
+            tmdb_client.person_details(actor_id, language),
+            tmdb_client.person_movie_credits(actor_id),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // return an error response if there is no such actor
+        let Some(actor) = actor else {
             return Ok(CallToolResult::with_error(CallToolError::from_message(
-                "No movies were found!",
+                format!("No actor with the TMDB ID {actor_id} was found"),
             )));
+        };
+
+        let mut movies = movies.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // the actor exists but has no film credits (e.g. they only appear in TV shows)
+        if movies.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("{} has no movie credits on TMDB", actor.name).into(),
+            ]));
+        }
+
+        // most popular titles first
+        movies.sort_by(|a, b| b.movie.popularity.total_cmp(&a.movie.popularity));
+
+        // terse: only the most popular titles, without the characters played
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        if format == ResponseFormat::Terse {
+            let result = movies
+                .iter()
+                .take(TERSE_LIMIT)
+                .map(|credit| credit.movie.render(missing))
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Ok(CallToolResult::text_content(vec![result.into()]));
+        }
+
+        if self.as_markdown.unwrap_or(false) {
+            let table = markdown_table(&movies, language);
+            return Ok(CallToolResult::text_content(vec![table.into()]));
         }
 
         // Convert the list of movies into a numbered string list
@@ -54,3 +121,204 @@ impl GetMoviesByActor {
         Ok(CallToolResult::text_content(vec![result.into()]))
     }
 }
+
+/// Renders the movies as a GitHub-flavored markdown table with Title, Year and Rating columns.
+fn markdown_table(movies: &[MovieCredit], language: &str) -> String {
+    let mut table = String::from("| Title | Year | Rating |\n| --- | --- | --- |");
+    for credit in movies {
+        let movie = &credit.movie;
+        let year = movie.release_date.get(0..4).unwrap_or("Unknown");
+        let rating = if movie.vote_count == 0 {
+            "Not rated".to_string()
+        } else {
+            format!("{}/10", format_rating(movie.vote_average, language))
+        };
+        table.push_str(&format!(
+            "\n| {} | {year} | {rating} |",
+            escape_markdown_cell(&movie.title)
+        ));
+    }
+    table
+}
+
+/// Escapes pipes so they don't split the cell, and flattens line breaks that would end the row.
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    /// Serves Tom Hanks (ID 31) with `cast` as his movie credits.
+    async fn mock_filmography(cast: Vec<serde_json::Value>) -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json("/person/31/movie_credits", fixtures::movie_credits(cast));
+        mock
+    }
+
+    fn by_id(actor_id: i64) -> GetMoviesByActor {
+        GetMoviesByActor {
+            actor_id: Some(actor_id),
+            actor_name: None,
+            as_markdown: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_movies_with_the_character_played() {
+        let mock = mock_filmography(vec![
+            fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump"),
+            fixtures::credit(862, "Toy Story", "1995-10-30", ""),
+        ])
+        .await;
+
+        let result = by_id(31)
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.contains("Forrest Gump (1994) (as Forrest Gump)"));
+        assert!(text.contains("Toy Story (1995)"));
+        assert!(!text.contains("Toy Story (1995) (as"));
+    }
+
+    #[tokio::test]
+    async fn terse_output_lists_the_first_titles_without_characters() {
+        let cast = (1..=12)
+            .map(|id| fixtures::credit(id, &format!("Movie {id}"), "2000-01-01", "Someone"))
+            .collect();
+        let mock = mock_filmography(cast).await;
+
+        let result = by_id(31)
+            .invoke(&mock.client(), ResponseFormat::Terse)
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        assert_eq!(text.lines().count(), TERSE_LIMIT);
+        assert!(!text.contains("(as"));
+    }
+
+    #[tokio::test]
+    async fn an_actor_without_movie_credits_is_not_an_error() {
+        let mock = mock_filmography(vec![]).await;
+
+        let result = by_id(31)
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, None);
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks has no movie credits on TMDB"
+        );
+    }
+
+    #[tokio::test]
+    async fn an_unknown_actor_id_is_an_error() {
+        let mock = MockTmdb::start().await;
+
+        let result = by_id(1)
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No actor with the TMDB ID 1 was found"));
+    }
+
+    #[tokio::test]
+    async fn markdown_tables_escape_pipes_in_titles() {
+        let mock = mock_filmography(vec![fixtures::credit(
+            1,
+            "Either|Or",
+            "2001-05-04",
+            "Someone",
+        )])
+        .await;
+
+        let result = GetMoviesByActor {
+            as_markdown: Some(true),
+            ..by_id(31)
+        }
+        .invoke(&mock.client(), ResponseFormat::Detailed)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "| Title | Year | Rating |\n| --- | --- | --- |\n| Either\\|Or | 2001 | 7.0/10 |"
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_the_actor_by_name() {
+        let mock = mock_filmography(vec![fixtures::credit(
+            13,
+            "Forrest Gump",
+            "1994-06-23",
+            "Forrest Gump",
+        )])
+        .await;
+        mock.json(
+            "/search/person?query=Tom+Hanks",
+            fixtures::single_page(vec![fixtures::person_summary(31, "Tom Hanks")]),
+        );
+
+        let result = GetMoviesByActor {
+            actor_id: None,
+            actor_name: Some("Tom Hanks".to_string()),
+            as_markdown: None,
+        }
+        .invoke(&mock.client(), ResponseFormat::Detailed)
+        .await
+        .unwrap();
+
+        assert!(result_text(&result).contains("Forrest Gump (1994)"));
+        assert_eq!(mock.hits("/person/31/movie_credits"), 1);
+    }
+
+    #[tokio::test]
+    async fn an_unknown_name_is_an_error() {
+        let mock = MockTmdb::start().await;
+        mock.json("/search/person", fixtures::single_page(vec![]));
+
+        let result = GetMoviesByActor {
+            actor_id: None,
+            actor_name: Some("Nobody".to_string()),
+            as_markdown: None,
+        }
+        .invoke(&mock.client(), ResponseFormat::Detailed)
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No actors matching the name \"Nobody\""));
+    }
+
+    #[tokio::test]
+    async fn exactly_one_of_id_and_name_is_required() {
+        let mock = MockTmdb::start().await;
+
+        for (actor_id, actor_name) in [(None, None), (Some(31), Some("Tom Hanks".to_string()))] {
+            let result = GetMoviesByActor {
+                actor_id,
+                actor_name,
+                as_markdown: None,
+            }
+            .invoke(&mock.client(), ResponseFormat::Detailed)
+            .await
+            .unwrap();
+
+            assert_eq!(result.is_error, Some(true));
+            assert!(result_text(&result).contains("Provide exactly one of"));
+        }
+        assert!(mock.requests().is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    tmdb_client::TmdbClient,
+    tools::{BatchFailures, summarize_biography},
+};
+use futures::future::join_all;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Maximum number of languages fetched in one call; keep in sync with `max_length` on `languages`.
+const MAX_LANGUAGES: usize = 10;
+
+#[mcp_tool(
+    name = "get_multilingual_bio",
+    title = "Get Actor Biography in Several Languages",
+    description = concat!(
+        "Get an actor's biography in several languages at once, by TMDB actor ID. ",
+        "Provide `languages` as ISO 639-1 codes, optionally with a region (e.g. [\"en-US\", \"fr\", \"ja\"]). ",
+        "Languages without a biography on TMDB are left out.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetMultilingualBio {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+    /// Languages to fetch the biography in, e.g. ["en-US", "fr", "ja"] (at most 10)
+    #[json_schema(min_length = 1, max_length = 10)]
+    pub languages: Vec<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetMultilingualBio {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // the schema's bounds aren't checked by every client, so enforce them here too
+        if self.languages.is_empty() || self.languages.len() > MAX_LANGUAGES {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("Provide between 1 and {MAX_LANGUAGES} languages"),
+            )));
+        }
+
+        // ignore blank and repeated languages
+        let mut languages: Vec<&str> = Vec::new();
+        for language in self.languages.iter().map(|language| language.trim()) {
+            if !language.is_empty() && !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+        if languages.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                "Provide at least one language code, e.g. \"en-US\"",
+            )));
+        }
+
+        // fetch the details once per language, all at the same time
+        let responses = join_all(
+            languages
+                .iter()
+                .map(|language| tmdb_client.person_details(self.actor_id, language)),
+        )
+        .await;
+
+        let max_biography_chars = tmdb_client.config().max_biography_chars;
+        let mut name = None;
+        let mut biographies = Vec::new();
+        let mut without_biography = Vec::new();
+        let mut failures = BatchFailures::default();
+        for (language, response) in languages.iter().zip(responses) {
+            match response {
+                Ok(Some(details)) => {
+                    if details.biography.trim().is_empty() {
+                        without_biography.push(*language);
+                    } else {
+                        biographies.push(format!(
+                            "[{language}]\n{}",
+                            summarize_biography(&details.biography, max_biography_chars)
+                        ));
+                    }
+                    name.get_or_insert(details.name);
+                }
+                Ok(None) => {
+                    return Ok(CallToolResult::with_error(CallToolError::from_message(
+                        format!("No actor with the TMDB ID {} was found", self.actor_id),
+                    )));
+                }
+                Err(err) => failures.push(*language, err),
+            }
+        }
+
+        let Some(name) = name else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                failures.to_string(),
+            )));
+        };
+
+        let mut result = if biographies.is_empty() {
+            format!("{name} has no biography in any of the requested languages")
+        } else {
+            format!("Biographies of {name}:\n\n{}", biographies.join("\n\n"))
+        };
+        if !biographies.is_empty() && !without_biography.is_empty() {
+            result.push_str(&format!(
+                "\n\nNo biography available in: {}",
+                without_biography.join(", ")
+            ));
+        }
+        if !failures.is_empty() {
+            result.push_str(&format!("\n\n{failures}"));
+        }
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    fn tool(languages: &[&str]) -> GetMultilingualBio {
+        GetMultilingualBio {
+            actor_id: 31,
+            languages: languages
+                .iter()
+                .map(|language| language.to_string())
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn only_non_empty_biographies_are_returned() {
+        let mock = MockTmdb::start().await;
+        let mut french = fixtures::person(31, "Tom Hanks");
+        french["biography"] = "Tom Hanks est un acteur.".into();
+        let mut japanese = fixtures::person(31, "Tom Hanks");
+        japanese["biography"] = " ".into();
+        mock.json(
+            "/person/31?language=en-US",
+            fixtures::person(31, "Tom Hanks"),
+        )
+        .json("/person/31?language=fr-FR", french)
+        .json("/person/31?language=ja-JP", japanese);
+
+        let result = tool(&["en-US", "fr-FR", "ja-JP", "fr-FR"])
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Biographies of Tom Hanks:\n\n[en-US]\nTom Hanks is an actor.\n\n[fr-FR]\nTom Hanks est un acteur.\n\nNo biography available in: ja-JP"
+        );
+        assert_eq!(mock.hits("/person/31"), 3);
+    }
+
+    #[tokio::test]
+    async fn the_number_of_languages_is_bounded() {
+        let mock = MockTmdb::start().await;
+        let eleven = ["en"; MAX_LANGUAGES + 1];
+
+        for languages in [&[][..], &eleven[..], &[" ", ""][..]] {
+            let result = tool(languages).invoke(&mock.client()).await.unwrap();
+            assert_eq!(result.is_error, Some(true));
+        }
+        assert!(mock.requests().is_empty());
+    }
+
+    #[test]
+    fn the_schema_advertises_the_bounds() {
+        let schema = GetMultilingualBio::json_schema();
+        let languages = &schema["properties"]["languages"];
+        assert_eq!(languages["minItems"], 1);
+        assert_eq!(languages["maxItems"], MAX_LANGUAGES);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient},
+    tools::BatchFailures,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Number of other films listed from the primary company.
+const OTHER_FILMS: usize = 5;
+
+#[mcp_tool(
+    name = "get_production_companies",
+    title = "Get a Movie's Production Companies",
+    description = concat!(
+        "Get the production companies of a movie by TMDB movie ID, with their country and logo. ",
+        "Set `include_other_films` to also list a few of the most popular other films from the ",
+        "primary (first listed) company.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetProductionCompanies {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+    /// Also list other popular films from the primary company (default false)
+    pub include_other_films: Option<bool>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetProductionCompanies {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let movie = tmdb_client
+            .movie_details(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(movie) = movie else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+        let Some(primary) = movie.production_companies.first() else {
+            return Ok(CallToolResult::text_content(vec![
+                format!("TMDB lists no production companies for {}", movie.title).into(),
+            ]));
+        };
+
+        let companies = movie
+            .production_companies
+            .iter()
+            .map(|company| {
+                let mut line = format!("- {} (company ID: {})", company.name, company.id);
+                if !company.origin_country.is_empty() {
+                    line.push_str(&format!(", {}", company.origin_country));
+                }
+                if let Some(logo_path) =
+                    company.logo_path.as_deref().filter(|path| !path.is_empty())
+                {
+                    line.push_str(&format!(
+                        "\n  Logo: {}",
+                        tmdb_client.resolve_image_url(logo_path)
+                    ));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut result = format!("Production companies of {}:\n{companies}", movie.title);
+
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        if self.include_other_films.unwrap_or(false) {
+            // the companies are still worth returning if their other films can't be fetched
+            match tmdb_client.movies_by_company(primary.id).await {
+                Ok(films) => {
+                    let others = films
+                        .results
+                        .iter()
+                        .filter(|film| film.id != movie.id)
+                        .take(OTHER_FILMS)
+                        .map(|film| format!("- {} - TMDB ID: {}", film.render(missing), film.id))
+                        .collect::<Vec<_>>();
+                    if others.is_empty() {
+                        result.push_str(&format!(
+                            "\n\nTMDB lists no other films from {}",
+                            primary.name
+                        ));
+                    } else {
+                        result.push_str(&format!(
+                            "\n\nOther popular films from {}:\n{}",
+                            primary.name,
+                            others.join("\n")
+                        ));
+                    }
+                }
+                Err(err) => {
+                    let mut failures = BatchFailures::default();
+                    failures.push(format!("other films from {}", primary.name), err);
+                    result.push_str(&format!("\n\n{failures}"));
+                }
+            }
+        }
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    /// Serves Rocky, produced by United Artists (with a logo) and Chartoff-Winkler (without).
+    fn mock_rocky(mock: &MockTmdb) {
+        let mut rocky = fixtures::movie_full(1366, "Rocky", "1976-11-21");
+        rocky["production_companies"] = json!([
+            {
+                "id": 60,
+                "name": "United Artists",
+                "logo_path": "/ua.png",
+                "origin_country": "US"
+            },
+            {
+                "id": 8536,
+                "name": "Chartoff-Winkler Productions",
+                "logo_path": null,
+                "origin_country": ""
+            }
+        ]);
+        mock.json("/movie/1366", rocky);
+    }
+
+    fn production_companies(include_other_films: bool) -> GetProductionCompanies {
+        GetProductionCompanies {
+            movie_id: 1366,
+            include_other_films: Some(include_other_films),
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_each_company_with_its_country_and_logo() {
+        let mock = MockTmdb::start().await;
+        mock_rocky(&mock);
+        let client = mock.client();
+
+        let result = production_companies(false).invoke(&client).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            format!(
+                "Production companies of Rocky:\n\
+                 - United Artists (company ID: 60), US\n  Logo: {}\n\
+                 - Chartoff-Winkler Productions (company ID: 8536)",
+                client.resolve_image_url("/ua.png")
+            )
+        );
+        assert_eq!(mock.hits("/discover/movie"), 0);
+    }
+
+    #[tokio::test]
+    async fn lists_other_films_from_the_primary_company() {
+        let mock = MockTmdb::start().await;
+        mock_rocky(&mock);
+        mock.json(
+            "/discover/movie?with_companies=60",
+            fixtures::single_page(vec![
+                fixtures::movie(1366, "Rocky", "1976-11-21"),
+                fixtures::movie(646, "Dr. No", "1962-10-07"),
+            ]),
+        );
+
+        let result = production_companies(true)
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert!(result_text(&result).ends_with(
+            "\n\nOther popular films from United Artists:\n- Dr. No (1962) - TMDB ID: 646"
+        ));
+    }
+
+    #[tokio::test]
+    async fn failing_other_films_are_reported_with_the_companies() {
+        let mock = MockTmdb::start().await;
+        mock_rocky(&mock);
+        mock.on(
+            "/discover/movie",
+            MockResponse::bytes(502, "text/html", "<html>"),
+        );
+
+        let result = production_companies(true)
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, None);
+        let text = result_text(&result);
+        assert!(text.starts_with("Production companies of Rocky:\n- United Artists"));
+        assert!(
+            text.contains("\n\nCould not fetch 1 item(s):\n- other films from United Artists: ")
+        );
+    }
+}
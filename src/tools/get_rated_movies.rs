@@ -0,0 +1,139 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient},
+    tools::{PageInfo, format::format_rating, registry::DynamicTool},
+};
+use async_trait::async_trait;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult, Tool},
+};
+use serde_json::{Map, Value};
+
+/// Only offered when `TMDB_SESSION_ID` is set (see `ToolRegistry::new`).
+#[mcp_tool(
+    name = "get_rated_movies",
+    title = "Get My Rated Movies",
+    description = concat!(
+        "List the movies rated on the TMDB account this server is signed in to, ",
+        "with the account's own rating next to TMDB's average, highest rated first.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetRatedMovies {}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetRatedMovies {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let page = tmdb_client
+            .account_rated_movies()
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let page_info = PageInfo::of(&page);
+        let mut movies = page.results;
+
+        if movies.is_empty() {
+            return Ok(page_info.attach_to(CallToolResult::text_content(vec![
+                "No movies have been rated on this TMDB account".into(),
+            ])));
+        }
+
+        movies.sort_by(|a, b| b.rating.total_cmp(&a.rating));
+        let language = &tmdb_client.config().language;
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = movies
+            .iter()
+            .enumerate()
+            .map(|(index, rated)| {
+                format!(
+                    "{}. {} - rated {}/10 (TMDB average {}/10) - TMDB ID: {}",
+                    index + 1,
+                    rated.movie.render(missing),
+                    format_rating(rated.rating, language),
+                    format_rating(rated.movie.vote_average, language),
+                    rated.movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![
+            format!("Rated movies:\n{result}").into(),
+        ])))
+    }
+}
+
+/// Registers `get_rated_movies` with a `ToolRegistry`. Tools registered at runtime are not
+/// part of `tool_box!`, so this parses the call arguments itself.
+pub struct RatedMoviesTool;
+
+#[async_trait]
+impl DynamicTool for RatedMoviesTool {
+    fn tool(&self) -> Tool {
+        GetRatedMovies::tool()
+    }
+
+    async fn call(
+        &self,
+        tmdb_client: &TmdbClient,
+        arguments: Map<String, Value>,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let request: GetRatedMovies =
+            serde_json::from_value(Value::Object(arguments)).map_err(|err| {
+                CallToolError::invalid_arguments(GetRatedMovies::tool_name(), Some(err.to_string()))
+            })?;
+        request.invoke(tmdb_client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    async fn account_with(movies: Value) -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json("/account", fixtures::account(42, "fan"))
+            .json("/account/42/rated/movies", movies);
+        mock
+    }
+
+    fn rated(id: i64, title: &str, rating: f64) -> Value {
+        let mut movie = fixtures::movie(id, title, "1994-06-23");
+        movie["rating"] = json!(rating);
+        movie
+    }
+
+    #[tokio::test]
+    async fn lists_the_highest_rated_first_with_page_info() {
+        let mock = account_with(fixtures::single_page(vec![
+            rated(680, "Pulp Fiction", 6.5),
+            rated(13, "Forrest Gump", 9.0),
+        ]))
+        .await;
+        let client = mock.client_with(|config| config.session_id = Some("session".into()));
+
+        let result = GetRatedMovies {}.invoke(&client).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Rated movies:\n\
+             1. Forrest Gump (1994) - rated 9.0/10 (TMDB average 7.0/10) - TMDB ID: 13\n\
+             2. Pulp Fiction (1994) - rated 6.5/10 (TMDB average 7.0/10) - TMDB ID: 680"
+        );
+        assert_eq!(
+            Value::Object(result.structured_content.unwrap()),
+            json!({ "page": 1, "total_pages": 1, "total_results": 2, "has_more": false })
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::tmdb_client::TmdbClient;
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Number of cast members returned when `limit` is omitted.
+const DEFAULT_LIMIT: u32 = 10;
+
+#[mcp_tool(
+    name = "get_top_cast",
+    title = "Get Top-Billed Cast",
+    description = concat!(
+        "Get the top-billed actors of a movie, by TMDB movie ID, with their TMDB person IDs, ",
+        "the characters they played and their billing order. ",
+        "Also returned as structured data, so each actor can be passed on to other tools. ",
+        "Optionally set `limit` (default 10).",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetTopCast {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+    /// Number of cast members to return (default 10)
+    #[json_schema(minimum = 1)]
+    pub limit: Option<u32>,
+}
+
+/// Structured content returned by `get_top_cast`. Also the source of the tool's output schema.
+#[derive(::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct TopCastOutput {
+    /// TMDB movie ID
+    pub movie_id: i64,
+    /// Cast members in billing order
+    pub cast: Vec<TopCastMember>,
+}
+
+/// One billed actor in `TopCastOutput`.
+#[derive(::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct TopCastMember {
+    /// TMDB person ID
+    pub id: i64,
+    pub name: String,
+    /// Character played (empty if unknown)
+    pub character: String,
+    /// Billing position (0 = top billed)
+    pub order: u32,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetTopCast {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let credits = tmdb_client
+            .movie_credits(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(credits) = credits else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+
+        if credits.cast.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("Movie {} has no cast listed", self.movie_id).into(),
+            ]));
+        }
+
+        // TMDB usually lists the cast in billing order, but don't rely on it
+        let mut cast = credits.cast;
+        cast.sort_by_key(|member| member.order);
+        cast.truncate(self.limit.unwrap_or(DEFAULT_LIMIT) as usize);
+
+        let output = TopCastOutput {
+            movie_id: self.movie_id,
+            cast: cast
+                .into_iter()
+                .map(|member| TopCastMember {
+                    id: member.id,
+                    name: member.name,
+                    character: member.character,
+                    order: member.order,
+                })
+                .collect(),
+        };
+
+        let text = output
+            .cast
+            .iter()
+            .enumerate()
+            .map(|(index, member)| {
+                let character = if member.character.is_empty() {
+                    String::new()
+                } else {
+                    format!(" as {}", member.character)
+                };
+                format!(
+                    "{}. {}{character} - TMDB ID: {}",
+                    index + 1,
+                    member.name,
+                    member.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let structured = serde_json::to_value(&output)
+            .ok()
+            .and_then(|value| value.as_object().cloned())
+            .unwrap_or_default();
+
+        Ok(CallToolResult::text_content(vec![
+            format!("Top-billed cast of movie {}:\n{text}", self.movie_id).into(),
+        ])
+        .with_structured_content(structured))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn the_cast_is_sorted_by_billing_order_and_truncated() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/13/credits",
+            fixtures::credits(
+                13,
+                vec![
+                    fixtures::cast_member(33, "Gary Sinise", "Lt. Dan Taylor", 2),
+                    fixtures::cast_member(31, "Tom Hanks", "Forrest Gump", 0),
+                    fixtures::cast_member(35, "Sally Field", "Mrs. Gump", 3),
+                    fixtures::cast_member(32, "Robin Wright", "Jenny Curran", 1),
+                ],
+                vec![],
+            ),
+        );
+
+        let result = GetTopCast {
+            movie_id: 13,
+            limit: Some(3),
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Top-billed cast of movie 13:\n\
+             1. Tom Hanks as Forrest Gump - TMDB ID: 31\n\
+             2. Robin Wright as Jenny Curran - TMDB ID: 32\n\
+             3. Gary Sinise as Lt. Dan Taylor - TMDB ID: 33"
+        );
+        let structured = result.structured_content.unwrap();
+        assert_eq!(structured["movie_id"], 13);
+        assert_eq!(
+            structured["cast"],
+            json!([
+                { "id": 31, "name": "Tom Hanks", "character": "Forrest Gump", "order": 0 },
+                { "id": 32, "name": "Robin Wright", "character": "Jenny Curran", "order": 1 },
+                { "id": 33, "name": "Gary Sinise", "character": "Lt. Dan Taylor", "order": 2 },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn the_limit_defaults_to_ten() {
+        let mock = MockTmdb::start().await;
+        let cast = (0..15)
+            .map(|order| {
+                fixtures::cast_member(100 + order, &format!("Actor {order}"), "", order as u32)
+            })
+            .collect();
+        mock.json("/movie/13/credits", fixtures::credits(13, cast, vec![]));
+
+        let result = GetTopCast {
+            movie_id: 13,
+            limit: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert!(result_text(&result).ends_with("10. Actor 9 - TMDB ID: 109"));
+        let structured = result.structured_content.unwrap();
+        assert_eq!(structured["cast"].as_array().unwrap().len(), 10);
+    }
+
+    #[tokio::test]
+    async fn an_unknown_movie_is_an_error() {
+        let mock = MockTmdb::start().await;
+
+        let result = GetTopCast {
+            movie_id: 999,
+            limit: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            result_text(&result),
+            "No movie with the TMDB ID 999 was found"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    tmdb_client::{MissingFields, TimeWindow, TmdbClient},
+    tools::PageInfo,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "get_trending_movies",
+    title = "Get Trending Movies",
+    description = concat!(
+        "Retrieve the movies currently trending on TMDB. ",
+        "Specify `time_window` as \"day\" for today's trending movies or \"week\" for this week's.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetTrendingMovies {
+    /// Trending time window: "day" or "week"
+    pub time_window: TimeWindow,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetTrendingMovies {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let page = tmdb_client
+            .trending_movies(self.time_window)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let page_info = PageInfo::of(&page);
+        let movies = page.results;
+
+        if movies.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                "No trending movies were found!",
+            )));
+        }
+
+        // numbered list including each movie's id, so it can be passed to other tools
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = movies
+            .iter()
+            .enumerate()
+            .map(|(index, movie)| {
+                format!(
+                    "{}. {} - TMDB ID: {}",
+                    index + 1,
+                    movie.render(missing),
+                    movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![result.into()])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    #[test]
+    fn the_schema_lists_the_time_windows() {
+        let schema = GetTrendingMovies::json_schema();
+        assert_eq!(
+            schema["properties"]["time_window"]["oneOf"],
+            json!([{ "enum": ["day"] }, { "enum": ["week"] }])
+        );
+
+        let invalid =
+            serde_json::from_value::<GetTrendingMovies>(json!({ "time_window": "month" }));
+        assert!(invalid.is_err());
+    }
+
+    #[tokio::test]
+    async fn the_time_window_selects_the_endpoint() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/trending/movie/week",
+            fixtures::single_page(vec![fixtures::movie(438631, "Dune", "2021-09-15")]),
+        );
+
+        let result = GetTrendingMovies {
+            time_window: TimeWindow::Week,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result_text(&result), "1. Dune (2021) - TMDB ID: 438631");
+        assert_eq!(mock.hits("/trending/movie/day"), 0);
+    }
+
+    #[tokio::test]
+    async fn page_info_is_attached() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/trending/movie/day",
+            fixtures::page(
+                1,
+                50,
+                1000,
+                vec![fixtures::movie(438631, "Dune", "2021-09-15")],
+            ),
+        );
+
+        let result = GetTrendingMovies {
+            time_window: TimeWindow::Day,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(
+            serde_json::Value::Object(result.structured_content.unwrap()),
+            json!({ "page": 1, "total_pages": 50, "total_results": 1000, "has_more": true })
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{
+    tmdb_client::{Episode, TmdbClient},
+    tools::format::{format_date, format_rating},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "get_tv_season",
+    title = "Get TV Season Episodes",
+    description = concat!(
+        "List the episodes of a TV series season with their numbers, air dates and ratings. ",
+        "Specify the TMDB `series_id` and the `season` number (season 0 holds specials).",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetTvSeason {
+    /// TMDB ID of the TV series
+    #[json_schema(minimum = 1)]
+    pub series_id: i64,
+    /// Season number (0 for specials)
+    #[json_schema(minimum = 0)]
+    pub season: u32,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetTvSeason {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let season = tmdb_client
+            .tv_season(self.series_id, self.season)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(season) = season else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                self.season_not_found_message(tmdb_client).await?,
+            )));
+        };
+
+        if season.episodes.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("{} has no episodes listed yet", season.name).into(),
+            ]));
+        }
+
+        let episodes = season
+            .episodes
+            .iter()
+            .map(|episode| format_episode(episode, &tmdb_client.config().language))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!(
+                "{} ({} episodes):\n{episodes}",
+                season.name,
+                season.episodes.len()
+            )
+            .into(),
+        ]))
+    }
+
+    /// Explains why the season could not be found: either the series doesn't exist,
+    /// or it doesn't have that many seasons.
+    async fn season_not_found_message(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<String, CallToolError> {
+        let series = tmdb_client
+            .tv_series(self.series_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        Ok(match series {
+            None => format!("No TV series with the TMDB ID {} was found", self.series_id),
+            Some(series) => format!(
+                "\"{}\" has no season {}; it has seasons 1 to {}",
+                series.name, self.season, series.number_of_seasons
+            ),
+        })
+    }
+}
+
+/// Formats an episode as `E01 - Name - Aired: March 3, 2008 - Rating: 8.1/10`.
+fn format_episode(episode: &Episode, language: &str) -> String {
+    let air_date = match episode.air_date.as_deref() {
+        Some(air_date) if !air_date.is_empty() => format_date(air_date, language),
+        _ => "TBD".to_string(),
+    };
+    let rating = if episode.vote_count == 0 {
+        "Not rated".to_string()
+    } else {
+        format!("{}/10", format_rating(episode.vote_average, language))
+    };
+    format!(
+        "E{:02} - {} - Aired: {air_date} - Rating: {rating}",
+        episode.episode_number, episode.name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::{TvSeason, test_server::MockTmdb};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn season_one() -> Value {
+        json!({
+            "_id": "52542282760ee313280017f9",
+            "name": "Season 1",
+            "season_number": 1,
+            "air_date": "2008-01-20",
+            "overview": "",
+            "episodes": [
+                {
+                    "id": 62085,
+                    "episode_number": 1,
+                    "name": "Pilot",
+                    "air_date": "2008-01-20",
+                    "runtime": 58,
+                    "vote_average": 8.1,
+                    "vote_count": 120,
+                },
+                {
+                    "id": 62086,
+                    "episode_number": 2,
+                    "name": "Cat's in the Bag...",
+                    "air_date": null,
+                    "runtime": null,
+                    "vote_average": 0.0,
+                    "vote_count": 0,
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn parses_the_episodes_array() {
+        let season: TvSeason = serde_json::from_value(season_one()).unwrap();
+
+        assert_eq!(season.episodes.len(), 2);
+        assert_eq!(season.episodes[0].name, "Pilot");
+        assert_eq!(season.episodes[0].runtime, Some(58));
+        assert_eq!(season.episodes[1].air_date, None);
+    }
+
+    #[tokio::test]
+    async fn lists_episodes_with_air_dates_and_ratings() {
+        let mock = MockTmdb::start().await;
+        mock.json("/tv/1396/season/1", season_one());
+
+        let result = GetTvSeason {
+            series_id: 1396,
+            season: 1,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Season 1 (2 episodes):"));
+        assert!(text.contains("E01 - Pilot - Aired: January 20, 2008 - Rating: 8.1/10"));
+        assert!(text.contains("E02 - Cat's in the Bag... - Aired: TBD - Rating: Not rated"));
+    }
+
+    #[tokio::test]
+    async fn an_out_of_range_season_names_the_seasons_there_are() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/tv/1396",
+            json!({
+                "id": 1396,
+                "name": "Breaking Bad",
+                "original_name": "Breaking Bad",
+                "original_language": "en",
+                "origin_country": ["US"],
+                "first_air_date": "2008-01-20",
+                "last_air_date": "2013-09-29",
+                "number_of_seasons": 5,
+                "number_of_episodes": 62,
+                "status": "Ended",
+                "type": "Scripted",
+                "in_production": false,
+                "vote_average": 8.9,
+                "vote_count": 14000,
+            }),
+        );
+
+        let result = GetTvSeason {
+            series_id: 1396,
+            season: 9,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            result_text(&result),
+            "\"Breaking Bad\" has no season 9; it has seasons 1 to 5"
+        );
+    }
+
+    #[tokio::test]
+    async fn an_unknown_series_is_an_error() {
+        let mock = MockTmdb::start().await;
+
+        let result = GetTvSeason {
+            series_id: 1,
+            season: 1,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No TV series with the TMDB ID 1"));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    tmdb_client::{MovieCredit, TmdbClient},
+    tools::format::format_date,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use time::OffsetDateTime;
+
+#[mcp_tool(
+    name = "get_upcoming_for_actor",
+    title = "Get Upcoming Movies for Actor",
+    description = concat!(
+        "List an actor's upcoming or unreleased movies, soonest release first. ",
+        "Movies without a release date yet are listed last as TBD. ",
+        "Specify `actor_id` to see what the actor is working on next.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct GetUpcomingForActor {
+    /// The TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl GetUpcomingForActor {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // retrieve list of movies the actor appeared in
+        let credits = tmdb_client
+            .person_movie_credits(self.actor_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let today = OffsetDateTime::now_utc().date().to_string();
+        let upcoming = upcoming_credits(credits, &today);
+
+        if upcoming.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                "No upcoming movies were found for this actor",
+            )));
+        }
+
+        let result = upcoming
+            .iter()
+            .enumerate()
+            .map(|(index, credit)| {
+                let release = match credit.movie.release_date.as_str() {
+                    "" => "TBD".to_string(),
+                    date => format_date(date, &tmdb_client.config().language),
+                };
+                format!("{}. {} - Release: {release}", index + 1, credit)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+/// Keeps credits released after `today` (a YYYY-MM-DD date) or with no release date yet,
+/// sorted by soonest release with undated titles last.
+fn upcoming_credits(credits: Vec<MovieCredit>, today: &str) -> Vec<MovieCredit> {
+    let mut upcoming: Vec<MovieCredit> = credits
+        .into_iter()
+        .filter(|credit| {
+            let date = credit.movie.release_date.trim();
+            date.is_empty() || date > today
+        })
+        .collect();
+
+    // ISO dates sort chronologically as strings; empty (TBD) dates go last
+    upcoming.sort_by(|a, b| {
+        let (a, b) = (&a.movie.release_date, &b.movie.release_date);
+        a.is_empty().cmp(&b.is_empty()).then_with(|| a.cmp(b))
+    });
+
+    upcoming
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    #[tokio::test]
+    async fn lists_unreleased_movies_soonest_first_with_tbd_last() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                fixtures::credit(1, "Untitled Project", "", "Himself"),
+                fixtures::credit(2, "Far Future", "2099-05-01", ""),
+                fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump"),
+                fixtures::credit(3, "Near Future", "2098-12-24", ""),
+            ]),
+        );
+
+        let result = GetUpcomingForActor { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 3, "{text}");
+        assert!(lines[0].starts_with("1. Near Future"));
+        assert!(lines[0].ends_with("Release: December 24, 2098"));
+        assert!(lines[1].starts_with("2. Far Future"));
+        assert!(lines[2].starts_with("3. Untitled Project"));
+        assert!(lines[2].ends_with("Release: TBD"));
+    }
+
+    #[tokio::test]
+    async fn an_actor_with_only_released_movies_is_an_error() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![fixtures::credit(
+                13,
+                "Forrest Gump",
+                "1994-06-23",
+                "Forrest Gump",
+            )]),
+        );
+
+        let result = GetUpcomingForActor { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("No upcoming movies"));
+    }
+}
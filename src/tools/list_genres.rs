@@ -0,0 +1,74 @@
+use crate::{tmdb_client::TmdbClient, tools::format::compare_names};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "list_genres",
+    title = "List Movie Genres",
+    description = concat!(
+        "List every movie genre TMDB knows, with its ID, in alphabetical order. ",
+        "Use it to find valid `genre` values for tools such as trending_in_genre.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct ListGenres {}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl ListGenres {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // cached by the client, so listing genres rarely costs a request
+        let mut genres = tmdb_client
+            .movie_genres()
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        genres.sort_by(|a, b| compare_names(&a.name, &b.name));
+
+        let result = genres
+            .iter()
+            .map(|genre| format!("- {} (ID: {})", genre.name, genre.id))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!("Movie genres:\n{result}").into(),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    #[tokio::test]
+    async fn lists_genres_alphabetically_and_caches_them() {
+        let mock = MockTmdb::start().await;
+        mock.json("/genre/movie/list", fixtures::genres());
+        let client = mock.client();
+
+        let result = ListGenres {}.invoke(&client).await.unwrap();
+        ListGenres {}.invoke(&client).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Movie genres:\n\
+             - Action (ID: 28)\n\
+             - Comedy (ID: 35)\n\
+             - Drama (ID: 18)\n\
+             - Science Fiction (ID: 878)"
+        );
+        assert_eq!(mock.hits("/genre/movie/list"), 1);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient},
+    tools::PageInfo,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "movies_with_both_actors",
+    title = "Movies With Both Actors",
+    description = concat!(
+        "Find the movies two actors both appear in, most popular first. ",
+        "Identify each actor by TMDB ID (`actor_id_a`, `actor_id_b`) or by name ",
+        "(`actor_name_a`, `actor_name_b`), one of the two per actor.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct MoviesWithBothActors {
+    /// TMDB ID of the first actor. Provide either this or `actor_name_a`.
+    #[json_schema(minimum = 1)]
+    pub actor_id_a: Option<i64>,
+    /// Name of the first actor. Provide either this or `actor_id_a`.
+    #[json_schema(min_length = 1)]
+    pub actor_name_a: Option<String>,
+    /// TMDB ID of the second actor. Provide either this or `actor_name_b`.
+    #[json_schema(minimum = 1)]
+    pub actor_id_b: Option<i64>,
+    /// Name of the second actor. Provide either this or `actor_id_b`.
+    #[json_schema(min_length = 1)]
+    pub actor_name_b: Option<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl MoviesWithBothActors {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // resolve both actor ids, looking them up by name if needed
+        let actors = [
+            ("a", self.actor_id_a, self.actor_name_a.as_deref()),
+            ("b", self.actor_id_b, self.actor_name_b.as_deref()),
+        ];
+        let mut actor_ids = [0; 2];
+        for (actor_id, (suffix, id, name)) in actor_ids.iter_mut().zip(actors) {
+            match resolve_actor_id(tmdb_client, suffix, id, name).await? {
+                Ok(id) => *actor_id = id,
+                Err(message) => {
+                    return Ok(CallToolResult::with_error(CallToolError::from_message(
+                        message,
+                    )));
+                }
+            }
+        }
+        let [actor_id_a, actor_id_b] = actor_ids;
+        if actor_id_a == actor_id_b {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!(
+                    "Both actors are the same (TMDB ID {actor_id_a}); provide two different actors"
+                ),
+            )));
+        }
+
+        // discover's `with_cast` matches movies casting both, which is more accurate than
+        // intersecting two filmographies; the details confirm both actors exist and give their names
+        let language = &tmdb_client.config().language;
+        let (details_a, details_b, movies) = tokio::join!(
+            tmdb_client.person_details(actor_id_a, language),
+            tmdb_client.person_details(actor_id_b, language),
+            tmdb_client.movies_with_cast(&actor_ids),
+        );
+        let details_a = details_a.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let details_b = details_b.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let movies = movies.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let (Some(actor_a), Some(actor_b)) = (details_a, details_b) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("Could not find both actors (IDs {actor_id_a} and {actor_id_b})"),
+            )));
+        };
+
+        let page_info = PageInfo::of(&movies);
+        if movies.results.is_empty() {
+            return Ok(page_info.attach_to(CallToolResult::text_content(vec![
+                format!(
+                    "{} and {} have not appeared in a movie together",
+                    actor_a.name, actor_b.name
+                )
+                .into(),
+            ])));
+        }
+
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = movies
+            .results
+            .iter()
+            .enumerate()
+            .map(|(index, movie)| {
+                format!(
+                    "{}. {} - TMDB ID: {}",
+                    index + 1,
+                    movie.render(missing),
+                    movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![
+            format!(
+                "Movies with both {} and {}:\n{result}",
+                actor_a.name, actor_b.name
+            )
+            .into(),
+        ])))
+    }
+}
+
+/// Returns the actor's TMDB ID, searching for it by name if no ID was given.
+/// The inner `Err` is a message for the client: both or neither were given, or no actor matched.
+async fn resolve_actor_id(
+    tmdb_client: &TmdbClient,
+    suffix: &str,
+    actor_id: Option<i64>,
+    actor_name: Option<&str>,
+) -> std::result::Result<std::result::Result<i64, String>, CallToolError> {
+    match (actor_id, actor_name) {
+        (Some(actor_id), None) => Ok(Ok(actor_id)),
+        (None, Some(actor_name)) => {
+            let actor_id = tmdb_client
+                .actor_id(actor_name)
+                .await
+                .map_err(|err| CallToolError::from_message(err.to_string()))?;
+            Ok(actor_id
+                .ok_or_else(|| format!("No actors matching the name \"{actor_name}\" were found")))
+        }
+        _ => Ok(Err(format!(
+            "Provide exactly one of `actor_id_{suffix}` or `actor_name_{suffix}`"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use std::collections::HashSet;
+
+    fn by_ids(actor_id_a: i64, actor_id_b: i64) -> MoviesWithBothActors {
+        MoviesWithBothActors {
+            actor_id_a: Some(actor_id_a),
+            actor_name_a: None,
+            actor_id_b: Some(actor_id_b),
+            actor_name_b: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn finds_the_same_movies_as_intersecting_filmographies() {
+        let mock = MockTmdb::start().await;
+        let shared = [
+            fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest Gump"),
+            fixtures::credit(857, "Saving Private Ryan", "1998-07-24", "Captain Miller"),
+        ];
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json("/person/3", fixtures::person(3, "Harrison Ford"))
+            .json(
+                "/person/31/movie_credits",
+                fixtures::movie_credits(vec![
+                    shared[0].clone(),
+                    shared[1].clone(),
+                    fixtures::credit(862, "Toy Story", "1995-10-30", "Woody"),
+                ]),
+            )
+            .json(
+                "/person/3/movie_credits",
+                fixtures::movie_credits(vec![
+                    shared[0].clone(),
+                    shared[1].clone(),
+                    fixtures::credit(11, "Star Wars", "1977-05-25", "Han Solo"),
+                ]),
+            )
+            .json(
+                "/discover/movie?with_cast=31%2C3",
+                fixtures::single_page(vec![
+                    fixtures::movie(13, "Forrest Gump", "1994-06-23"),
+                    fixtures::movie(857, "Saving Private Ryan", "1998-07-24"),
+                ]),
+            );
+        let tmdb = mock.client();
+
+        let result = by_ids(31, 3).invoke(&tmdb).await.unwrap();
+
+        let listed: HashSet<i64> = result_text(&result)
+            .lines()
+            .filter_map(|line| line.rsplit_once("TMDB ID: ")?.1.parse().ok())
+            .collect();
+        let filmography = |credits: Vec<crate::tmdb_client::MovieCredit>| {
+            credits
+                .into_iter()
+                .map(|credit| credit.movie.id)
+                .collect::<HashSet<_>>()
+        };
+        let hanks = filmography(tmdb.person_movie_credits(31).await.unwrap());
+        let ford = filmography(tmdb.person_movie_credits(3).await.unwrap());
+        assert_eq!(listed, &hanks & &ford);
+        assert_eq!(mock.hits("/discover/movie"), 1);
+    }
+
+    #[tokio::test]
+    async fn the_same_actor_twice_is_rejected() {
+        let mock = MockTmdb::start().await;
+
+        let result = by_ids(31, 31).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("Both actors are the same (TMDB ID 31)"));
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn actors_without_shared_movies_get_a_note() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json("/person/3", fixtures::person(3, "Harrison Ford"))
+            .json("/discover/movie", fixtures::single_page(vec![]));
+
+        let result = by_ids(31, 3).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks and Harrison Ford have not appeared in a movie together"
+        );
+    }
+}
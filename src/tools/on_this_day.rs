@@ -0,0 +1,336 @@
+use crate::{
+    tmdb_client::{MAX_PAGE, PersonDetails, TmdbClient},
+    tools::{BatchFailures, format::format_date},
+};
+use futures::stream::{self, StreamExt};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use time::OffsetDateTime;
+
+/// Number of person details fetched at the same time.
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// Number of people listed in the result.
+const MAX_RESULTS: usize = 10;
+
+/// Finds popular people born on a given day.
+///
+/// TMDB has no way to search or discover people by birthday, so this looks at the
+/// currently most popular people (`/person/popular`, up to `TMDB_MAX_PAGES` pages of 20, and
+/// never past the 500th page, the last TMDB serves), fetches each one's details for their
+/// birthday and keeps those whose month and day match.
+#[mcp_tool(
+    name = "on_this_day",
+    title = "Actors Born on This Day",
+    description = concat!(
+        "List notable people born on a given day of the year, today by default. ",
+        "Optionally provide `date` as MM-DD (e.g. 07-06) or YYYY-MM-DD. ",
+        "Only the people currently most popular on TMDB are considered.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct OnThisDay {
+    /// Day to look for as MM-DD or YYYY-MM-DD (defaults to today, UTC)
+    pub date: Option<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl OnThisDay {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let month_day = match self.date.as_deref() {
+            None => {
+                let today = OffsetDateTime::now_utc().date();
+                format!("{:02}-{:02}", today.month() as u8, today.day())
+            }
+            Some(date) => match parse_month_day(date) {
+                Some(month_day) => month_day,
+                None => {
+                    return Ok(CallToolResult::with_error(CallToolError::from_message(
+                        format!("\"{date}\" is not a valid date, expected MM-DD or YYYY-MM-DD"),
+                    )));
+                }
+            },
+        };
+
+        // collect the most popular people; a page or person that can't be fetched is
+        // reported with the results instead of failing the whole search
+        let mut failures = BatchFailures::default();
+        let mut people = Vec::new();
+        // TMDB serves no pages past MAX_PAGE, whatever TMDB_MAX_PAGES says
+        let max_pages = tmdb_client.config().max_pages.min(MAX_PAGE);
+        let mut capped = false;
+        let mut at_page_limit = false;
+        for page in 1..=max_pages {
+            match tmdb_client.popular_people(page).await {
+                Ok(page) => {
+                    capped = page.page == max_pages && page.has_more();
+                    at_page_limit = page.at_page_limit();
+                    let done = !page.has_more();
+                    people.extend(page.results.into_iter().map(|person| person.id));
+                    if done {
+                        break;
+                    }
+                }
+                Err(err) => failures.push(format!("popular people page {page}"), err),
+            }
+        }
+        if people.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                failures.to_string(),
+            )));
+        }
+
+        // fetch their birthdays a few at a time, keeping popularity order
+        let language = &tmdb_client.config().language;
+        let details = stream::iter(people)
+            .map(|person_id| async move {
+                (
+                    person_id,
+                    tmdb_client.person_details(person_id, language).await,
+                )
+            })
+            .buffered(CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut born: Vec<PersonDetails> = Vec::new();
+        let mut inspected = 0;
+        for (person_id, person) in details {
+            let person = match person {
+                Ok(Some(person)) => person,
+                Ok(None) => continue,
+                Err(err) => {
+                    failures.push(format!("person {person_id}"), err);
+                    continue;
+                }
+            };
+            inspected += 1;
+            if person
+                .birthday
+                .as_deref()
+                .is_some_and(|birthday| birthday.ends_with(&format!("-{month_day}")))
+            {
+                born.push(person);
+            }
+        }
+        born.truncate(MAX_RESULTS);
+
+        if inspected == 0 {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                failures.to_string(),
+            )));
+        }
+
+        let mut notes = if failures.is_empty() {
+            String::new()
+        } else {
+            format!("\n\n{failures}")
+        };
+        if at_page_limit {
+            notes.push_str(&format!(
+                "\n\n(Results are incomplete: TMDB serves only the first {MAX_PAGE} pages of popular people, so less popular people could not be checked)"
+            ));
+        } else if capped {
+            notes.push_str(&format!(
+                "\n\n(Results are incomplete: only the first {max_pages} pages of popular people were checked; raise TMDB_MAX_PAGES to check more)"
+            ));
+        }
+
+        if born.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!(
+                    "None of the {inspected} most popular people on TMDB were born on {month_day}{notes}"
+                )
+                .into(),
+            ]));
+        }
+
+        let result = born
+            .iter()
+            .enumerate()
+            .map(|(index, person)| {
+                format!(
+                    "{}. {} (born {}) - TMDB ID: {}",
+                    index + 1,
+                    person.name,
+                    format_date(person.birthday.as_deref().unwrap_or_default(), language),
+                    person.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!(
+                "Born on {month_day}, among the {inspected} most popular people on TMDB:\n{result}{notes}"
+            )
+            .into(),
+        ]))
+    }
+}
+
+/// Normalizes `MM-DD` or `YYYY-MM-DD` to `MM-DD`, returning None if it is not a valid day.
+fn parse_month_day(date: &str) -> Option<String> {
+    let date = date.trim();
+    let month_day = match date.len() {
+        5 => date,
+        10 if date.as_bytes()[4] == b'-' && date[..4].chars().all(|c| c.is_ascii_digit()) => {
+            &date[5..]
+        }
+        _ => return None,
+    };
+
+    let (month, day) = month_day.split_once('-')?;
+    if month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    let (month, day): (u8, u8) = (month.parse().ok()?, day.parse().ok()?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    Some(format!("{month:02}-{day:02}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    #[test]
+    fn dates_are_normalized_to_month_and_day() {
+        assert_eq!(parse_month_day("07-09").as_deref(), Some("07-09"));
+        assert_eq!(parse_month_day(" 1956-07-09 ").as_deref(), Some("07-09"));
+        assert_eq!(parse_month_day("13-01"), None);
+        assert_eq!(parse_month_day("7-9"), None);
+        assert_eq!(parse_month_day("July 9"), None);
+    }
+
+    #[tokio::test]
+    async fn lists_popular_people_born_on_the_day() {
+        let mock = MockTmdb::start().await;
+        let mut hathaway = fixtures::person(1813, "Anne Hathaway");
+        hathaway["birthday"] = "1982-11-12".into();
+        mock.json(
+            "/person/popular",
+            fixtures::single_page(vec![
+                fixtures::person_summary(1813, "Anne Hathaway"),
+                fixtures::person_summary(31, "Tom Hanks"),
+            ]),
+        )
+        .json("/person/1813", hathaway)
+        .json("/person/31", fixtures::person(31, "Tom Hanks"));
+
+        let result = OnThisDay {
+            date: Some("07-09".to_string()),
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Born on 07-09, among the 2 most popular people on TMDB:\n\
+             1. Tom Hanks (born July 9, 1956) - TMDB ID: 31"
+        );
+    }
+
+    #[tokio::test]
+    async fn paging_stops_at_max_pages_and_notes_it() {
+        let mock = MockTmdb::start().await;
+        for page in 1..=20 {
+            let person_id = 100 + i64::from(page);
+            mock.json(
+                &format!("/person/popular?page={page}"),
+                fixtures::page(
+                    page,
+                    20,
+                    20,
+                    vec![fixtures::person_summary(person_id, "Someone")],
+                ),
+            )
+            .json(
+                &format!("/person/{person_id}"),
+                fixtures::person(person_id, "Someone"),
+            );
+        }
+
+        let result = OnThisDay {
+            date: Some("01-01".to_string()),
+        }
+        .invoke(&mock.client_with(|config| config.max_pages = 5))
+        .await
+        .unwrap();
+
+        let pages: Vec<_> = mock
+            .requests()
+            .iter()
+            .filter(|request| request.path() == "/person/popular")
+            .map(|request| request.query("page").unwrap().to_string())
+            .collect();
+        assert_eq!(pages, ["1", "2", "3", "4", "5"]);
+        assert_eq!(
+            result_text(&result),
+            "None of the 5 most popular people on TMDB were born on 01-01\n\n\
+             (Results are incomplete: only the first 5 pages of popular people were checked; raise TMDB_MAX_PAGES to check more)"
+        );
+    }
+
+    #[tokio::test]
+    async fn reaching_tmdbs_last_page_is_noted() {
+        let mock = MockTmdb::start().await;
+        // TMDB has 800 pages but serves only up to MAX_PAGE; answer as its last page
+        mock.json(
+            "/person/popular",
+            fixtures::page(
+                MAX_PAGE,
+                800,
+                16000,
+                vec![fixtures::person_summary(31, "Tom Hanks")],
+            ),
+        )
+        .json("/person/31", fixtures::person(31, "Tom Hanks"));
+
+        let result = OnThisDay {
+            date: Some("07-09".to_string()),
+        }
+        .invoke(&mock.client_with(|config| config.max_pages = 501))
+        .await
+        .unwrap();
+
+        assert!(mock.requests().iter().all(|request| {
+            request.path() != "/person/popular" || request.query("page") != Some("501")
+        }));
+        assert!(result_text(&result).ends_with(
+            "\n\n(Results are incomplete: TMDB serves only the first 500 pages of popular people, so less popular people could not be checked)"
+        ));
+    }
+
+    #[tokio::test]
+    async fn an_invalid_date_is_rejected_without_requests() {
+        let mock = MockTmdb::start().await;
+
+        let result = OnThisDay {
+            date: Some("02-30-ish".to_string()),
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).contains("is not a valid date"));
+        assert!(mock.requests().is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    tmdb_client::{MovieCredit, TmdbClient},
+    tools::{format::format_rating, unique_movies},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use std::collections::BTreeMap;
+
+/// Fewest votes a movie needs to count towards its decade's average, so a handful of votes
+/// on an obscure title doesn't swing the trend.
+const MIN_VOTE_COUNT: u32 = 10;
+
+/// Bars used by the sparkline, from lowest to highest average.
+const SPARKLINE_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[mcp_tool(
+    name = "rating_trend",
+    title = "Actor Rating Trend",
+    description = concat!(
+        "Show whether an actor's films got better or worse over time, by TMDB actor ID: ",
+        "the average TMDB rating of their movies per decade, oldest first. ",
+        "Movies with fewer than 10 votes are left out. Set `sparkline` to add a one-line ASCII chart of the trend.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct RatingTrend {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+    /// Whether to add a sparkline of the per-decade averages (default false)
+    pub sparkline: Option<bool>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl RatingTrend {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let credits = tmdb_client
+            .person_movie_credits(self.actor_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let trend = decade_averages(&credits);
+        if trend.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!(
+                    "No dated movies with at least {MIN_VOTE_COUNT} votes were found for actor ID {}",
+                    self.actor_id
+                ),
+            )));
+        }
+
+        let language = &tmdb_client.config().language;
+        let mut result = trend
+            .iter()
+            .map(|(decade, average)| match average {
+                Some((average, films)) => format!(
+                    "{decade}s: {}/10 ({films} {})",
+                    format_rating(*average, language),
+                    if *films == 1 { "film" } else { "films" }
+                ),
+                None => format!("{decade}s: no rated films"),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if self.sparkline.unwrap_or(false) {
+            result.push_str(&format!("\n\nTrend: {}", sparkline(&trend)));
+        }
+
+        Ok(CallToolResult::text_content(vec![
+            format!("Average rating by decade:\n{result}").into(),
+        ]))
+    }
+}
+
+/// Averages the vote average of each distinct, sufficiently voted movie by release decade.
+/// Every decade from the first to the last one with such a movie is included, with None
+/// (instead of an average and film count) for decades in between that have none.
+fn decade_averages(credits: &[MovieCredit]) -> BTreeMap<u16, Option<(f64, usize)>> {
+    let mut ratings: BTreeMap<u16, Vec<f64>> = BTreeMap::new();
+    for movie in unique_movies(credits)
+        .into_iter()
+        .map(|credit| &credit.movie)
+    {
+        if movie.vote_count < MIN_VOTE_COUNT {
+            continue;
+        }
+        let Some(year) = movie
+            .release_date
+            .get(0..4)
+            .and_then(|year| year.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        ratings
+            .entry(year / 10 * 10)
+            .or_default()
+            .push(movie.vote_average);
+    }
+
+    let (Some(first), Some(last)) = (
+        ratings.keys().next().copied(),
+        ratings.keys().next_back().copied(),
+    ) else {
+        return BTreeMap::new();
+    };
+    (first..=last)
+        .step_by(10)
+        .map(|decade| {
+            let average = ratings.get(&decade).map(|ratings| {
+                (
+                    ratings.iter().sum::<f64>() / ratings.len() as f64,
+                    ratings.len(),
+                )
+            });
+            (decade, average)
+        })
+        .collect()
+}
+
+/// One bar per decade, scaled between the lowest and highest average; decades without rated
+/// films are shown as a space.
+fn sparkline(trend: &BTreeMap<u16, Option<(f64, usize)>>) -> String {
+    let averages: Vec<f64> = trend
+        .values()
+        .flatten()
+        .map(|(average, _)| *average)
+        .collect();
+    let low = averages.iter().copied().fold(f64::INFINITY, f64::min);
+    let high = averages.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let top = SPARKLINE_BARS.len() - 1;
+
+    trend
+        .values()
+        .map(|average| match average {
+            None => ' ',
+            Some(_) if high <= low => SPARKLINE_BARS[top / 2],
+            Some((average, _)) => {
+                let index = ((average - low) / (high - low) * top as f64).round() as usize;
+                SPARKLINE_BARS[index.min(top)]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn rated(id: i64, release_date: &str, vote_average: f64, vote_count: u32) -> Value {
+        let mut credit = fixtures::credit(id, &format!("Movie {id}"), release_date, "Role");
+        credit["vote_average"] = json!(vote_average);
+        credit["vote_count"] = json!(vote_count);
+        credit
+    }
+
+    /// Two 1980s films, nothing rated in the 1990s, and two 2000s films, plus a second role
+    /// in one movie, a barely voted film and an undated one.
+    fn filmography() -> Vec<Value> {
+        let mut second_role = rated(1, "1984-05-01", 6.0, 100);
+        second_role["character"] = json!("Another Role");
+        vec![
+            rated(1, "1984-05-01", 6.0, 100),
+            second_role,
+            rated(2, "1989-11-17", 7.0, 100),
+            rated(3, "1995-03-03", 9.9, 3),
+            rated(4, "2001-01-01", 8.0, 100),
+            rated(5, "2009-12-31", 8.5, 100),
+            rated(6, "", 1.0, 100),
+        ]
+    }
+
+    #[test]
+    fn averages_each_decade_once_per_movie() {
+        let credits: Vec<MovieCredit> = serde_json::from_value(json!(filmography())).unwrap();
+
+        let trend = decade_averages(&credits);
+
+        assert_eq!(
+            trend,
+            BTreeMap::from([
+                (1980, Some((6.5, 2))),
+                (1990, None),
+                (2000, Some((8.25, 2))),
+            ])
+        );
+        assert_eq!(sparkline(&trend), "▁ █");
+    }
+
+    #[tokio::test]
+    async fn lists_the_trend_by_decade() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(filmography()),
+        );
+
+        let result = RatingTrend {
+            actor_id: 31,
+            sparkline: Some(true),
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Average rating by decade:\n\
+             1980s: 6.5/10 (2 films)\n\
+             1990s: no rated films\n\
+             2000s: 8.2/10 (2 films)\n\n\
+             Trend: ▁ █"
+        );
+    }
+
+    #[tokio::test]
+    async fn no_qualifying_films_is_an_error() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![rated(3, "1995-03-03", 9.9, 3)]),
+        );
+
+        let result = RatingTrend {
+            actor_id: 31,
+            sparkline: None,
+        }
+        .invoke(&mock.client())
+        .await
+        .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            result_text(&result),
+            "No dated movies with at least 10 votes were found for actor ID 31"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient},
+    tools::{PageInfo, format::format_rating, get_best_film::best_film},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Films with fewer votes than this can't be the actor's top film (as in `get_best_film`).
+const MIN_VOTE_COUNT: u32 = 200;
+
+/// Number of recommendations listed.
+const MAX_RECOMMENDATIONS: usize = 10;
+
+#[mcp_tool(
+    name = "recommend_for_actor",
+    title = "Recommend Movies for an Actor's Fans",
+    description = concat!(
+        "Recommend movies to fans of an actor, by TMDB actor ID: finds the actor's highest-rated ",
+        "film, then returns the movies TMDB recommends to people who liked it.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct RecommendForActor {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl RecommendForActor {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // step 1: the actor's top film
+        let credits = tmdb_client
+            .person_movie_credits(self.actor_id)
+            .await
+            .map_err(|err| {
+                CallToolError::from_message(format!("Could not fetch the actor's films: {err}"))
+            })?;
+
+        let Some(best) = best_film(&credits, MIN_VOTE_COUNT) else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!(
+                    "No films with at least {MIN_VOTE_COUNT} votes were found for actor {}, so there is nothing to base recommendations on",
+                    self.actor_id
+                ),
+            )));
+        };
+
+        // step 2: recommendations for that film
+        let recommendations = tmdb_client
+            .movie_recommendations(best.movie.id)
+            .await
+            .map_err(|err| {
+                CallToolError::from_message(format!(
+                    "Could not fetch recommendations for {}: {err}",
+                    best.movie.title
+                ))
+            })?
+            .map(|mut page| {
+                // never recommend films the actor's fans have likely seen already
+                page.retain(|movie| credits.iter().all(|credit| credit.movie.id != movie.id));
+                page
+            });
+        let page_info = recommendations.as_ref().map(PageInfo::of);
+        let recommendations: Vec<_> = recommendations
+            .map(|page| page.results)
+            .unwrap_or_default()
+            .into_iter()
+            .take(MAX_RECOMMENDATIONS)
+            .collect();
+
+        let language = &tmdb_client.config().language;
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let top_film = format!(
+            "Based on their top film, {} ({}/10):",
+            best.movie.render(missing),
+            format_rating(best.movie.vote_average, language)
+        );
+        // a film TMDB no longer knows has no page of recommendations to describe
+        let with_page_info = |result: CallToolResult| match &page_info {
+            Some(page_info) => page_info.attach_to(result),
+            None => result,
+        };
+        if recommendations.is_empty() {
+            return Ok(with_page_info(CallToolResult::text_content(vec![
+                format!("{top_film}\nTMDB has no recommendations for this film").into(),
+            ])));
+        }
+
+        let result = recommendations
+            .iter()
+            .enumerate()
+            .map(|(index, movie)| {
+                format!(
+                    "{}. {} - TMDB ID: {}",
+                    index + 1,
+                    movie.render(missing),
+                    movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(with_page_info(CallToolResult::text_content(vec![
+            format!("{top_film}\n{result}").into(),
+        ])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn rated_credit(id: i64, title: &str, vote_average: f64, vote_count: u32) -> Value {
+        let mut credit = fixtures::credit(id, title, "1994-06-23", "");
+        credit["vote_average"] = json!(vote_average);
+        credit["vote_count"] = json!(vote_count);
+        credit
+    }
+
+    /// Tom Hanks's films: Forrest Gump is the best rated with enough votes.
+    async fn tom_hanks() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                rated_credit(13, "Forrest Gump", 8.5, 25000),
+                rated_credit(862, "Toy Story", 8.0, 17000),
+                rated_credit(99999, "Obscure Short", 9.9, 12),
+            ]),
+        );
+        mock
+    }
+
+    fn tool() -> RecommendForActor {
+        RecommendForActor { actor_id: 31 }
+    }
+
+    #[tokio::test]
+    async fn recommends_movies_based_on_the_top_film() {
+        let mock = tom_hanks().await;
+        mock.json(
+            "/movie/13/recommendations",
+            fixtures::single_page(vec![
+                fixtures::movie(807, "Se7en", "1995-09-22"),
+                fixtures::movie(862, "Toy Story", "1995-10-30"),
+                fixtures::movie(278, "The Shawshank Redemption", "1994-09-23"),
+            ]),
+        );
+
+        let result = tool().invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Based on their top film, Forrest Gump (1994) (8.5/10):\n\
+             1. Se7en (1995) - TMDB ID: 807\n\
+             2. The Shawshank Redemption (1994) - TMDB ID: 278"
+        );
+        assert_eq!(mock.hits("/movie/99999/recommendations"), 0);
+        // Toy Story is one of the actor's own films, so it is not counted either
+        assert_eq!(
+            Value::Object(result.structured_content.unwrap()),
+            json!({ "page": 1, "total_pages": 1, "total_results": 2, "has_more": false })
+        );
+    }
+
+    #[tokio::test]
+    async fn a_film_without_recommendations_says_so() {
+        let mock = tom_hanks().await;
+        mock.json("/movie/13/recommendations", fixtures::single_page(vec![]));
+
+        let result = tool().invoke(&mock.client()).await.unwrap();
+
+        assert!(result_text(&result).ends_with("\nTMDB has no recommendations for this film"));
+    }
+
+    #[tokio::test]
+    async fn each_step_reports_its_own_failure() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![rated_credit(99999, "Obscure Short", 9.9, 12)]),
+        );
+        let result = tool().invoke(&mock.client()).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(result_text(&result).starts_with("No films with at least 200 votes were found"));
+
+        let mock = tom_hanks().await;
+        mock.on(
+            "/movie/13/recommendations",
+            MockResponse::status(
+                401,
+                json!({
+                    "success": false,
+                    "status_code": 7,
+                    "status_message": "Invalid API key",
+                }),
+            ),
+        );
+        let err = tool().invoke(&mock.client()).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Could not fetch recommendations for Forrest Gump: "),
+            "{err}"
+        );
+    }
+}
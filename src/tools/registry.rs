@@ -0,0 +1,190 @@
+//! Tools registered at startup on top of the compiled-in `TmdbTools` set.
+//!
+//! `tool_box!` fixes its tools at compile time. Tools that should only be offered in some
+//! deployments (e.g. experimental ones enabled through configuration, or account tools that
+//! need a TMDB session) implement
+//! `DynamicTool` instead and are added to the `ToolRegistry` the handler serves from.
+
+use crate::{
+    tmdb_client::{TmdbClient, TmdbConfig},
+    tools::{
+        get_favorite_movies::FavoriteMoviesTool, get_movie_details::MovieDetailsTool,
+        get_rated_movies::RatedMoviesTool, list_tools, with_output_schema,
+    },
+};
+use async_trait::async_trait;
+use rust_mcp_sdk::schema::{CallToolError, CallToolResult, Tool};
+use serde_json::{Map, Value};
+
+/// A tool that can be registered at runtime.
+#[async_trait]
+pub trait DynamicTool: Send + Sync {
+    /// Definition advertised in `tools/list`.
+    fn tool(&self) -> Tool;
+
+    /// Runs the tool with the arguments of a `tools/call` request.
+    async fn call(
+        &self,
+        tmdb_client: &TmdbClient,
+        arguments: Map<String, Value>,
+    ) -> std::result::Result<CallToolResult, CallToolError>;
+}
+
+/// The tools served by this server: every `TmdbTools` tool, followed by the registered ones.
+#[derive(Default)]
+pub struct ToolRegistry {
+    extra_tools: Vec<Box<dyn DynamicTool>>,
+}
+
+impl ToolRegistry {
+    /// Creates a registry with the built-in tools, plus the experimental ones when
+    /// `TMDB_EXPERIMENTAL_TOOLS` is set and the account ones when `TMDB_SESSION_ID` is set.
+    pub fn new(config: &TmdbConfig) -> Self {
+        let mut registry = Self::default();
+        if config.experimental_tools {
+            registry.register(MovieDetailsTool);
+        }
+        if config.session_id.is_some() {
+            registry.register(FavoriteMoviesTool);
+            registry.register(RatedMoviesTool);
+        }
+        registry
+    }
+
+    /// Adds a tool after the built-in ones.
+    ///
+    /// # Panics
+    /// Panics if a tool with the same name is already served, since clients could not
+    /// tell the two apart.
+    pub fn register(&mut self, tool: impl DynamicTool + 'static) {
+        let name = tool.tool().name;
+        assert!(
+            !self.tools().iter().any(|existing| existing.name == name),
+            "a tool named \"{name}\" is already registered"
+        );
+        self.extra_tools.push(Box::new(tool));
+    }
+
+    /// Returns every tool served, built-in ones first.
+    pub fn tools(&self) -> Vec<Tool> {
+        let mut tools = list_tools();
+        tools.extend(
+            self.extra_tools
+                .iter()
+                .map(|tool| with_output_schema(tool.tool())),
+        );
+        tools
+    }
+
+    /// Returns the registered (non built-in) tool with this name, if any.
+    pub fn find(&self, name: &str) -> Option<&dyn DynamicTool> {
+        self.extra_tools
+            .iter()
+            .find(|tool| tool.tool().name == name)
+            .map(Box::as_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    /// A tool that does nothing, named `name`.
+    struct NoopTool(&'static str);
+
+    #[async_trait]
+    impl DynamicTool for NoopTool {
+        fn tool(&self) -> Tool {
+            serde_json::from_value(json!({
+                "name": self.0,
+                "inputSchema": { "type": "object" },
+            }))
+            .unwrap()
+        }
+
+        async fn call(
+            &self,
+            _tmdb_client: &TmdbClient,
+            _arguments: Map<String, Value>,
+        ) -> std::result::Result<CallToolResult, CallToolError> {
+            Ok(CallToolResult::text_content(vec!["noop".into()]))
+        }
+    }
+
+    #[tokio::test]
+    async fn registered_tools_are_listed_after_the_built_in_ones() {
+        let mut registry = ToolRegistry::default();
+        registry.register(NoopTool("noop"));
+
+        let tools = registry.tools();
+        assert_eq!(tools.len(), list_tools().len() + 1);
+        assert_eq!(tools.last().unwrap().name, "noop");
+
+        let tmdb = TmdbClient::with_client(reqwest::Client::new(), "http://localhost".to_string());
+        let result = registry
+            .find("noop")
+            .unwrap()
+            .call(&tmdb, Map::new())
+            .await
+            .unwrap();
+        assert_eq!(result_text(&result), "noop");
+        assert!(registry.find("get_actor_info").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "a tool named \"get_actor_info\" is already registered")]
+    fn a_name_can_only_be_registered_once() {
+        ToolRegistry::default().register(NoopTool("get_actor_info"));
+    }
+
+    #[test]
+    fn experimental_tools_are_only_offered_when_enabled() {
+        let mut config = TmdbConfig::with_defaults(String::new(), String::new());
+        assert!(
+            ToolRegistry::new(&config)
+                .find("get_movie_details")
+                .is_none()
+        );
+
+        config.experimental_tools = true;
+        assert!(
+            ToolRegistry::new(&config)
+                .find("get_movie_details")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn account_tools_are_hidden_without_a_session() {
+        let names = |registry: ToolRegistry| -> Vec<String> {
+            registry.tools().into_iter().map(|tool| tool.name).collect()
+        };
+        let mut config = TmdbConfig::with_defaults(String::new(), String::new());
+        assert_eq!(config.session_id, None);
+
+        let tools = names(ToolRegistry::new(&config));
+        assert!(!tools.iter().any(|name| name == "get_favorite_movies"));
+        assert!(!tools.iter().any(|name| name == "get_rated_movies"));
+
+        config.session_id = Some("session".to_string());
+        let tools = names(ToolRegistry::new(&config));
+        assert!(tools.iter().any(|name| name == "get_favorite_movies"));
+        assert!(tools.iter().any(|name| name == "get_rated_movies"));
+    }
+
+    #[test]
+    fn registered_list_tools_advertise_page_info() {
+        let mut config = TmdbConfig::with_defaults(String::new(), String::new());
+        config.session_id = Some("session".to_string());
+
+        let tools = ToolRegistry::new(&config).tools();
+
+        for name in ["get_favorite_movies", "get_rated_movies"] {
+            let tool = tools.iter().find(|tool| tool.name == name).unwrap();
+            let schema = tool.output_schema.as_ref().unwrap();
+            assert!(schema.required.contains(&"has_more".to_string()), "{name}");
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{tmdb_client::TmdbClient, tools::PageInfo};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Department a person is known for, as named by TMDB's `known_for_department`.
+#[derive(
+    ::serde::Deserialize, ::serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema,
+)]
+pub enum Department {
+    #[serde(rename = "Acting")]
+    Acting,
+    #[serde(rename = "Directing")]
+    Directing,
+    #[serde(rename = "Writing")]
+    Writing,
+    #[serde(rename = "Production")]
+    Production,
+    #[serde(rename = "Editing")]
+    Editing,
+    #[serde(rename = "Camera")]
+    Camera,
+    #[serde(rename = "Sound")]
+    Sound,
+    #[serde(rename = "Art")]
+    Art,
+    #[serde(rename = "Costume & Make-Up")]
+    CostumeAndMakeUp,
+    #[serde(rename = "Visual Effects")]
+    VisualEffects,
+    #[serde(rename = "Lighting")]
+    Lighting,
+    #[serde(rename = "Crew")]
+    Crew,
+}
+
+impl Department {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Department::Acting => "Acting",
+            Department::Directing => "Directing",
+            Department::Writing => "Writing",
+            Department::Production => "Production",
+            Department::Editing => "Editing",
+            Department::Camera => "Camera",
+            Department::Sound => "Sound",
+            Department::Art => "Art",
+            Department::CostumeAndMakeUp => "Costume & Make-Up",
+            Department::VisualEffects => "Visual Effects",
+            Department::Lighting => "Lighting",
+            Department::Crew => "Crew",
+        }
+    }
+}
+
+#[mcp_tool(
+    name = "search_by_department",
+    title = "Search People by Department",
+    description = concat!(
+        "Search for people by name, keeping only those known for a given department, ",
+        "e.g. the director rather than the actor of the same name. ",
+        "Specify the `query` and the `department`, such as \"Directing\" or \"Writing\".",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct SearchByDepartment {
+    /// Name (or part of a name) to search for
+    #[json_schema(min_length = 1)]
+    pub query: String,
+    /// Only return people known for this department
+    pub department: Department,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl SearchByDepartment {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let mut people = tmdb_client
+            .search_people(self.query.trim())
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // TMDB's person search can't filter by department, so filter the results here
+        let department = self.department.as_str();
+        let total = people.results.len();
+        people.retain(|person| person.known_for_department.as_deref() == Some(department));
+        let page_info = PageInfo::of(&people);
+        let matches = people.results;
+
+        if matches.is_empty() {
+            let message = if total == 0 {
+                format!("No people matching \"{}\" were found", self.query.trim())
+            } else {
+                format!(
+                    "None of the {total} people matching \"{}\" are known for {department}",
+                    self.query.trim()
+                )
+            };
+            return Ok(page_info.attach_to(CallToolResult::text_content(vec![message.into()])));
+        }
+
+        let result = matches
+            .iter()
+            .enumerate()
+            .map(|(index, person)| {
+                let known_for = person
+                    .known_for
+                    .iter()
+                    .map(|title| title.display_title())
+                    .filter(|title| !title.is_empty())
+                    .collect::<Vec<_>>();
+                let mut line = format!("{}. {} - TMDB ID: {}", index + 1, person.name, person.id);
+                if !known_for.is_empty() {
+                    line.push_str(&format!(" (known for {})", known_for.join(", ")));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![
+            format!(
+                "People matching \"{}\" known for {department}:\n{result}",
+                self.query.trim()
+            )
+            .into(),
+        ])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn person(id: i64, name: &str, department: &str) -> Value {
+        let mut person = fixtures::person_summary(id, name);
+        person["known_for_department"] = json!(department);
+        person
+    }
+
+    fn search(department: Department) -> SearchByDepartment {
+        SearchByDepartment {
+            query: "Coppola".to_string(),
+            department,
+        }
+    }
+
+    async fn mixed_departments() -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/search/person?query=Coppola",
+            fixtures::single_page(vec![
+                person(2349, "Nicolas Cage", "Acting"),
+                person(1776, "Francis Ford Coppola", "Directing"),
+                person(1769, "Sofia Coppola", "Directing"),
+                person(5403, "Talia Shire", "Acting"),
+                person(1778, "Roman Coppola", "Writing"),
+            ]),
+        );
+        mock
+    }
+
+    #[tokio::test]
+    async fn keeps_only_people_known_for_the_department() {
+        let mock = mixed_departments().await;
+
+        let result = search(Department::Directing)
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "People matching \"Coppola\" known for Directing:\n\
+             1. Francis Ford Coppola - TMDB ID: 1776\n\
+             2. Sofia Coppola - TMDB ID: 1769"
+        );
+    }
+
+    #[tokio::test]
+    async fn page_info_counts_the_people_kept() {
+        let mock = mixed_departments().await;
+
+        let result = search(Department::Directing)
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Value::Object(result.structured_content.unwrap()),
+            json!({ "page": 1, "total_pages": 1, "total_results": 2, "has_more": false })
+        );
+    }
+
+    #[tokio::test]
+    async fn says_when_nobody_is_known_for_the_department() {
+        let mock = mixed_departments().await;
+
+        let result = search(Department::Editing)
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "None of the 5 people matching \"Coppola\" are known for Editing"
+        );
+    }
+
+    #[test]
+    fn departments_use_tmdb_names() {
+        let department: Department = serde_json::from_value(json!("Costume & Make-Up")).unwrap();
+        assert_eq!(department, Department::CostumeAndMakeUp);
+        assert_eq!(department.as_str(), "Costume & Make-Up");
+        assert!(serde_json::from_value::<Department>(json!("Directors")).is_err());
+    }
+}
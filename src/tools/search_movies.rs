@@ -0,0 +1,195 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieDetail, TmdbClient},
+    tools::PageInfo,
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Number of candidates listed.
+const MAX_CANDIDATES: usize = 10;
+
+#[mcp_tool(
+    name = "search_movies",
+    title = "Search Movies by Title",
+    description = concat!(
+        "Find a movie's TMDB ID by title. Many films share a title (remakes, re-releases), so ",
+        "every candidate is listed with its release year and ID, most popular first. ",
+        "Give the release `year` to pick one, e.g. \"Dune\" with year 2021.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct SearchMovies {
+    /// Movie title to search for, e.g. "Dune"
+    #[json_schema(min_length = 1)]
+    pub query: String,
+    /// Release year to tell same-title movies apart, e.g. 1984 or 2021
+    #[json_schema(minimum = 1874)]
+    pub year: Option<u32>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl SearchMovies {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let query = self.query.trim();
+        let movies = tmdb_client
+            .search_movies(query, self.year)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let page_info = PageInfo::of(&movies);
+        let mut movies = movies.results;
+
+        if movies.is_empty() {
+            let Some(year) = self.year else {
+                return Ok(page_info.attach_to(CallToolResult::text_content(vec![
+                    format!("No movies titled \"{query}\" were found").into(),
+                ])));
+            };
+
+            // the year may be off by one (e.g. a festival premiere); offer the other releases,
+            // paged as TMDB paged them
+            let others = tmdb_client
+                .search_movies(query, None)
+                .await
+                .map_err(|err| CallToolError::from_message(err.to_string()))?;
+            let page_info = PageInfo::of(&others);
+            let others = others.results;
+            let mut text = format!("No movie titled \"{query}\" was released in {year}");
+            if !others.is_empty() {
+                text.push_str(&format!(
+                    ". Other movies with that title:\n{}",
+                    render_candidates(others)
+                ));
+            }
+            return Ok(page_info.attach_to(CallToolResult::text_content(vec![text.into()])));
+        }
+
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let text = match (self.year, movies.len()) {
+            (Some(year), 1) => {
+                let movie = movies.remove(0);
+                format!(
+                    "The \"{query}\" released in {year}: {} - TMDB ID: {}",
+                    movie.render(missing),
+                    movie.id
+                )
+            }
+            (Some(year), _) => format!(
+                "Movies matching \"{query}\" released in {year}:\n{}",
+                render_candidates(movies)
+            ),
+            (None, _) => format!(
+                "Movies matching \"{query}\" (give a `year` to pick one):\n{}",
+                render_candidates(movies)
+            ),
+        };
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![text.into()])))
+    }
+}
+
+/// Lists the most popular candidates as a numbered list, each with its year and ID.
+fn render_candidates(mut movies: Vec<MovieDetail>) -> String {
+    movies.sort_by(|a, b| b.popularity.total_cmp(&a.popularity));
+    movies
+        .iter()
+        .take(MAX_CANDIDATES)
+        .enumerate()
+        .map(|(index, movie)| {
+            // the year is what tells same-title movies apart, so say when it is unknown
+            let year = if movie.release_date.len() < 4 {
+                " (year unknown)"
+            } else {
+                ""
+            };
+            format!("{}. {movie}{year} - TMDB ID: {}", index + 1, movie.id)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    /// Serves the two films titled "Dune": David Lynch's from 1984 and the more popular 2021 one.
+    fn mock_dunes(mock: &MockTmdb) {
+        let lynch = fixtures::movie(841, "Dune", "1984-12-14");
+        let mut villeneuve = fixtures::movie(438631, "Dune", "2021-09-15");
+        villeneuve["popularity"] = 90.0.into();
+        mock.json(
+            "/search/movie",
+            fixtures::single_page(vec![lynch.clone(), villeneuve]),
+        )
+        .json(
+            "/search/movie?primary_release_year=1984",
+            fixtures::single_page(vec![lynch]),
+        )
+        .json(
+            "/search/movie?primary_release_year=1985",
+            fixtures::single_page(vec![]),
+        );
+    }
+
+    fn search(year: Option<u32>) -> SearchMovies {
+        SearchMovies {
+            query: " Dune ".to_string(),
+            year,
+        }
+    }
+
+    #[tokio::test]
+    async fn same_title_movies_are_listed_most_popular_first() {
+        let mock = MockTmdb::start().await;
+        mock_dunes(&mock);
+
+        let result = search(None).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Movies matching \"Dune\" (give a `year` to pick one):\n\
+             1. Dune (2021) - TMDB ID: 438631\n\
+             2. Dune (1984) - TMDB ID: 841"
+        );
+    }
+
+    #[tokio::test]
+    async fn the_year_picks_one_of_the_same_title_movies() {
+        let mock = MockTmdb::start().await;
+        mock_dunes(&mock);
+
+        let result = search(Some(1984)).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "The \"Dune\" released in 1984: Dune (1984) - TMDB ID: 841"
+        );
+        assert_eq!(result.structured_content.unwrap()["total_results"], 1);
+    }
+
+    #[tokio::test]
+    async fn a_wrong_year_lists_the_other_releases_with_their_page_info() {
+        let mock = MockTmdb::start().await;
+        mock_dunes(&mock);
+
+        let result = search(Some(1985)).invoke(&mock.client()).await.unwrap();
+
+        assert!(result_text(&result).starts_with(
+            "No movie titled \"Dune\" was released in 1985. Other movies with that title:\n"
+        ));
+        let structured = result.structured_content.unwrap();
+        assert_eq!(structured["total_results"], 2);
+        assert_eq!(structured["page"], 1);
+    }
+}
@@ -0,0 +1,77 @@
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult, InitializeResult},
+};
+
+#[mcp_tool(
+    name = "server_info",
+    title = "Server Info",
+    description = concat!(
+        "Get the name and version of this server, the MCP protocol version and capabilities it ",
+        "advertises, and the commit and Rust version it was built from. ",
+        "Useful to confirm which build you are talking to.",
+    ),
+    icons = [
+        (src = "https://avatars.githubusercontent.com/u/4128628?s=128",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct ServerInfo {}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl ServerInfo {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub fn invoke(
+        &self,
+        server_details: &InitializeResult,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // the capabilities that are advertised, i.e. not left out of the initialize result
+        let capabilities = serde_json::to_value(&server_details.capabilities)
+            .ok()
+            .and_then(|value| value.as_object().cloned())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+
+        let info = &server_details.server_info;
+        let lines = [
+            format!("Server: {}", info.name),
+            format!("Version: {}", info.version),
+            format!("Protocol version: {}", server_details.protocol_version),
+            format!("Capabilities: {}", capabilities.join(", ")),
+            format!("Git commit: {}", env!("BUILD_GIT_HASH")),
+            format!("Built with: {}", env!("BUILD_RUSTC_VERSION")),
+        ];
+
+        Ok(CallToolResult::text_content(vec![lines.join("\n").into()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::result_text;
+
+    #[test]
+    fn reports_the_package_version_and_advertised_capabilities() {
+        let result = ServerInfo {}.invoke(&crate::server_details()).unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Server: Techshare MCP Server\n"));
+        assert!(
+            text.contains(&format!("\nVersion: {}\n", env!("CARGO_PKG_VERSION"))),
+            "{text}"
+        );
+        assert!(text.contains("\nProtocol version: 2025-11-25\n"), "{text}");
+        assert!(
+            text.contains("\nCapabilities: completions, logging, resources, tools\n"),
+            "{text}"
+        );
+        assert!(text.contains("\nGit commit: "));
+        assert!(text.contains("\nBuilt with: "));
+    }
+}
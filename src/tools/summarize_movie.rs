@@ -0,0 +1,322 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient, TmdbConfig},
+    tools::{BatchFailures, format::format_rating},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Number of top-billed cast members included.
+const MAX_CAST: usize = 8;
+
+/// Number of keywords included.
+const MAX_KEYWORDS: usize = 15;
+
+#[mcp_tool(
+    name = "summarize_movie",
+    title = "Summarize Movie",
+    description = concat!(
+        "Get everything about a movie in one call, by TMDB movie ID: release date, runtime, genres, ",
+        "rating, tagline, overview, director, top cast and keywords. ",
+        "Also returned as a single structured object, for use as context.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct SummarizeMovie {
+    /// TMDB ID of the movie
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+}
+
+/// Structured content returned by `summarize_movie`. Also the source of the tool's output schema.
+#[derive(::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct MovieSummaryOutput {
+    /// TMDB movie ID
+    pub id: i64,
+    pub title: String,
+    /// Release date in YYYY-MM-DD format (empty if unknown)
+    pub release_date: String,
+    /// Runtime in minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<u32>,
+    /// Short marketing tagline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagline: Option<String>,
+    pub overview: String,
+    /// Genre names
+    pub genres: Vec<String>,
+    /// Average rating out of 10
+    pub rating: f64,
+    /// Number of votes behind the rating
+    pub vote_count: u32,
+    /// Names of the directors
+    pub directors: Vec<String>,
+    /// Top-billed cast as "Name as Character"
+    pub top_cast: Vec<String>,
+    /// Keywords tagged on the movie
+    pub keywords: Vec<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl SummarizeMovie {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // fetch every part of the summary at the same time
+        let (details, credits, keywords) = tokio::join!(
+            tmdb_client.movie_details(self.movie_id),
+            tmdb_client.movie_credits(self.movie_id),
+            tmdb_client.movie_keywords(self.movie_id),
+        );
+        let details = details.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let Some(movie) = details else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+
+        // the details are required; credits and keywords are left out if they can't be fetched
+        let mut failures = BatchFailures::default();
+        let (directors, top_cast) = match credits {
+            Ok(credits) => credits
+                .map(|credits| {
+                    let directors = credits
+                        .directors()
+                        .map(|director| director.name.clone())
+                        .collect();
+                    let top_cast = credits
+                        .cast
+                        .iter()
+                        .take(MAX_CAST)
+                        .map(|member| {
+                            if member.character.is_empty() {
+                                member.name.clone()
+                            } else {
+                                format!("{} as {}", member.name, member.character)
+                            }
+                        })
+                        .collect();
+                    (directors, top_cast)
+                })
+                .unwrap_or_default(),
+            Err(err) => {
+                failures.push("credits", err);
+                Default::default()
+            }
+        };
+        let keywords = match keywords {
+            Ok(keywords) => keywords
+                .unwrap_or_default()
+                .into_iter()
+                .take(MAX_KEYWORDS)
+                .map(|keyword| keyword.name)
+                .collect(),
+            Err(err) => {
+                failures.push("keywords", err);
+                Vec::new()
+            }
+        };
+
+        let output = MovieSummaryOutput {
+            id: movie.id,
+            title: movie.title,
+            release_date: movie.release_date,
+            runtime: movie.runtime.filter(|minutes| *minutes > 0),
+            tagline: movie.tagline.filter(|tagline| !tagline.is_empty()),
+            overview: movie.overview,
+            genres: movie.genres.into_iter().map(|genre| genre.name).collect(),
+            rating: movie.vote_average,
+            vote_count: movie.vote_count,
+            directors,
+            top_cast,
+            keywords,
+        };
+
+        let mut text = render_summary(&output, tmdb_client.config());
+        if !failures.is_empty() {
+            text.push_str(&format!("\n\n{failures}"));
+        }
+        let structured = serde_json::to_value(&output)
+            .ok()
+            .and_then(|value| value.as_object().cloned())
+            .unwrap_or_default();
+
+        Ok(CallToolResult::text_content(vec![text.into()]).with_structured_content(structured))
+    }
+}
+
+/// Renders the summary as compact labelled lines, labelling or leaving out empty sections
+/// according to `TMDB_MISSING_FIELDS`.
+fn render_summary(summary: &MovieSummaryOutput, config: &TmdbConfig) -> String {
+    let (language, missing) = (
+        config.language.as_str(),
+        config.missing_fields_or(MissingFields::Omit),
+    );
+    let year = summary.release_date.get(0..4).unwrap_or("Unknown year");
+    let mut lines = vec![format!(
+        "{} ({year}) - TMDB ID: {}",
+        summary.title, summary.id
+    )];
+    if let Some(tagline) = &summary.tagline {
+        lines.push(format!("\"{tagline}\""));
+    }
+    let runtime = summary.runtime.map(|runtime| format!("{runtime} min"));
+    let rating = (summary.vote_count > 0).then(|| {
+        format!(
+            "{}/10 ({} votes)",
+            format_rating(summary.rating, language),
+            summary.vote_count
+        )
+    });
+    lines.extend(missing.field("Runtime", runtime.as_deref()));
+    lines.extend(missing.field("Rating", rating.as_deref()));
+    for (label, values) in [
+        ("Genres", &summary.genres),
+        ("Directed by", &summary.directors),
+        ("Starring", &summary.top_cast),
+        ("Keywords", &summary.keywords),
+    ] {
+        lines.extend(missing.field(label, Some(&values.join(", "))));
+    }
+    if !summary.overview.is_empty() {
+        lines.push(format!("\n{}", summary.overview));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn aggregates_every_section_into_one_object() {
+        let mock = MockTmdb::start().await;
+        let mut movie = fixtures::movie_full(1366, "Rocky", "1976-11-21");
+        movie["tagline"] = "His whole life was a million-to-one shot.".into();
+        movie["genres"] = json!([{ "id": 18, "name": "Drama" }]);
+        mock.json("/movie/1366", movie)
+            .json(
+                "/movie/1366/credits",
+                fixtures::credits(
+                    1366,
+                    vec![
+                        fixtures::cast_member(16483, "Sylvester Stallone", "Rocky Balboa", 0),
+                        fixtures::cast_member(3094, "Talia Shire", "Adrian", 1),
+                    ],
+                    vec![fixtures::crew_member(35, "John G. Avildsen", "Director")],
+                ),
+            )
+            .json(
+                "/movie/1366/keywords",
+                json!({ "id": 1366, "keywords": [{ "id": 6075, "name": "sports" }] }),
+            );
+
+        let result = SummarizeMovie { movie_id: 1366 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        let structured = result.structured_content.as_ref().unwrap();
+        assert_eq!(structured["title"], "Rocky");
+        assert_eq!(
+            structured["tagline"],
+            "His whole life was a million-to-one shot."
+        );
+        assert_eq!(structured["runtime"], 120);
+        assert_eq!(structured["genres"], json!(["Drama"]));
+        assert_eq!(structured["directors"], json!(["John G. Avildsen"]));
+        assert_eq!(
+            structured["top_cast"],
+            json!([
+                "Sylvester Stallone as Rocky Balboa",
+                "Talia Shire as Adrian"
+            ])
+        );
+        assert_eq!(structured["keywords"], json!(["sports"]));
+
+        let text = result_text(&result);
+        assert!(text.starts_with("Rocky (1976) - TMDB ID: 1366"));
+        assert!(text.contains("Directed by: John G. Avildsen"));
+    }
+
+    /// Summarizes a movie TMDB knows no director, cast or keywords for.
+    async fn summarize_sparse_movie(missing_fields: Option<MissingFields>) -> String {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/1366",
+            fixtures::movie_full(1366, "Rocky", "1976-11-21"),
+        )
+        .json(
+            "/movie/1366/credits",
+            fixtures::credits(1366, vec![], vec![]),
+        )
+        .json(
+            "/movie/1366/keywords",
+            json!({ "id": 1366, "keywords": [] }),
+        );
+        let client = mock.client_with(|config| config.missing_fields = missing_fields);
+
+        let result = SummarizeMovie { movie_id: 1366 }
+            .invoke(&client)
+            .await
+            .unwrap();
+        result_text(&result)
+    }
+
+    #[tokio::test]
+    async fn empty_sections_are_left_out_by_default() {
+        let text = summarize_sparse_movie(None).await;
+
+        assert!(!text.contains("Directed by"));
+        assert!(!text.contains("Unknown"));
+        assert_eq!(
+            text,
+            summarize_sparse_movie(Some(MissingFields::Omit)).await
+        );
+    }
+
+    #[tokio::test]
+    async fn label_mode_marks_empty_sections_as_unknown() {
+        let text = summarize_sparse_movie(Some(MissingFields::Label)).await;
+
+        assert!(text.contains("Directed by: Unknown"));
+    }
+
+    #[tokio::test]
+    async fn failed_sections_are_reported_after_the_rest() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/1366",
+            fixtures::movie_full(1366, "Rocky", "1976-11-21"),
+        )
+        .json(
+            "/movie/1366/credits",
+            fixtures::credits(1366, vec![], vec![]),
+        )
+        .on(
+            "/movie/1366/keywords",
+            MockResponse::bytes(502, "text/html", "<html>"),
+        );
+
+        let result = SummarizeMovie { movie_id: 1366 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, None);
+        assert!(result_text(&result).contains("Could not fetch 1 item(s):\n- keywords: "));
+        assert_eq!(result.structured_content.unwrap()["keywords"], json!([]));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieFull, TmdbClient},
+    tools::{BatchFailures, format::format_usd, unique_movies},
+};
+use futures::stream::{self, StreamExt};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Number of the actor's most popular films whose details (and revenue) are fetched.
+const MAX_FILMS: usize = 30;
+
+/// Number of movie details fetched at the same time.
+const CONCURRENT_REQUESTS: usize = 4;
+
+/// Number of top earners listed.
+const MAX_RESULTS: usize = 10;
+
+#[mcp_tool(
+    name = "top_grossing",
+    title = "An Actor's Highest-Grossing Films",
+    description = concat!(
+        "Get an actor's highest-grossing films by worldwide box office revenue, by TMDB actor ID. ",
+        "Only the actor's 30 most popular films are checked, and films whose revenue TMDB doesn't ",
+        "know are left out.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct TopGrossing {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl TopGrossing {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let language = &tmdb_client.config().language;
+        let (actor, credits) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.person_movie_credits(self.actor_id),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor) = actor else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // credits carry no revenue, so fetch the details of the most popular films, each once
+        let mut movies = unique_movies(credits)
+            .into_iter()
+            .map(|credit| credit.movie)
+            .collect::<Vec<_>>();
+        if movies.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("No movies are listed for {} on TMDB", actor.name).into(),
+            ]));
+        }
+        movies.sort_by(|a, b| b.popularity.total_cmp(&a.popularity));
+        movies.truncate(MAX_FILMS);
+
+        let details = stream::iter(movies)
+            .map(|movie| async move {
+                let details = tmdb_client.movie_details(movie.id).await;
+                (movie, details)
+            })
+            .buffer_unordered(CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut earners = Vec::new();
+        let mut failures = BatchFailures::default();
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        for (movie, details) in details {
+            match details {
+                Ok(Some(details)) => earners.push(details),
+                Ok(None) => {}
+                Err(err) => failures.push(movie.render(missing), err),
+            }
+        }
+        if earners.is_empty() && !failures.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                failures.to_string(),
+            )));
+        }
+        let earners = top_earners(earners);
+
+        let mut result = if earners.is_empty() {
+            format!(
+                "TMDB knows the box office revenue of none of {}'s top films",
+                actor.name
+            )
+        } else {
+            let list = earners
+                .iter()
+                .enumerate()
+                .map(|(index, movie)| {
+                    let year = movie
+                        .release_year()
+                        .map(|year| format!(" ({year})"))
+                        .unwrap_or_default();
+                    format!(
+                        "{}. {}{year} - {} - TMDB ID: {}",
+                        index + 1,
+                        movie.title,
+                        format_usd(movie.revenue),
+                        movie.id
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!(
+                "{}'s highest-grossing films (worldwide box office):\n{list}",
+                actor.name
+            )
+        };
+        if !failures.is_empty() {
+            result.push_str(&format!("\n\n{failures}"));
+        }
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+/// Keeps the movies with a known revenue (TMDB uses 0 for unknown), highest first.
+fn top_earners(mut movies: Vec<MovieFull>) -> Vec<MovieFull> {
+    movies.retain(|movie| movie.revenue > 0);
+    movies.sort_by(|a, b| b.revenue.cmp(&a.revenue).then(a.id.cmp(&b.id)));
+    movies.truncate(MAX_RESULTS);
+    movies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    #[tokio::test]
+    async fn ranks_films_by_revenue_and_skips_unknown_revenue() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json(
+                "/person/31/movie_credits",
+                fixtures::movie_credits(vec![
+                    fixtures::credit(13, "Forrest Gump", "1994-06-23", "Forrest"),
+                    fixtures::credit(862, "Toy Story", "1995-10-30", "Woody"),
+                    fixtures::credit(301528, "Toy Story 4", "2019-06-19", "Woody"),
+                    fixtures::credit(2, "Unreleased", "", ""),
+                ]),
+            );
+        for (id, title, release_date, revenue) in [
+            (13, "Forrest Gump", "1994-06-23", 677_387_716_i64),
+            (862, "Toy Story", "1995-10-30", 373_554_033),
+            (301528, "Toy Story 4", "2019-06-19", 1_200_000_000),
+            (2, "Unreleased", "", 0),
+        ] {
+            let mut movie = fixtures::movie_full(id, title, release_date);
+            movie["revenue"] = revenue.into();
+            mock.json(&format!("/movie/{id}"), movie);
+        }
+
+        let result = TopGrossing { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks's highest-grossing films (worldwide box office):\n\
+             1. Toy Story 4 (2019) - $1,200,000,000 - TMDB ID: 301528\n\
+             2. Forrest Gump (1994) - $677,387,716 - TMDB ID: 13\n\
+             3. Toy Story (1995) - $373,554,033 - TMDB ID: 862"
+        );
+    }
+
+    #[tokio::test]
+    async fn no_known_revenue_says_so() {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json(
+                "/person/31/movie_credits",
+                fixtures::movie_credits(vec![fixtures::credit(2, "Unreleased", "", "")]),
+            )
+            .json("/movie/2", fixtures::movie_full(2, "Unreleased", ""));
+
+        let result = TopGrossing { actor_id: 31 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "TMDB knows the box office revenue of none of Tom Hanks's top films"
+        );
+    }
+}
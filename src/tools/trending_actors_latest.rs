@@ -0,0 +1,202 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieCredit, TimeWindow, TmdbClient},
+    tools::BatchFailures,
+};
+use futures::stream::{self, StreamExt};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+use time::OffsetDateTime;
+
+/// Number of trending actors listed by default.
+const DEFAULT_COUNT: usize = 5;
+
+/// Number of filmographies fetched at the same time.
+const CONCURRENT_REQUESTS: usize = 4;
+
+#[mcp_tool(
+    name = "trending_actors_latest",
+    title = "Trending Actors and Their Latest Films",
+    description = concat!(
+        "Get a \"who's hot and what they just made\" digest: the actors trending on TMDB this week, ",
+        "each with their most recently released film. ",
+        "Optionally set `count` (1-10, default 5) for how many actors to include.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/stallone-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct TrendingActorsLatest {
+    /// Number of trending actors to include (1-10, default 5)
+    #[json_schema(minimum = 1, maximum = 10)]
+    pub count: Option<u32>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl TrendingActorsLatest {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let count = self.count.map_or(DEFAULT_COUNT, |count| count as usize);
+
+        // trending people include directors and writers; keep the actors
+        let actors = tmdb_client
+            .trending_people(TimeWindow::Week)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?
+            .results
+            .into_iter()
+            .filter(|person| person.known_for_department.as_deref() == Some("Acting"))
+            .take(count)
+            .collect::<Vec<_>>();
+        if actors.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                "No actors are trending on TMDB this week".into(),
+            ]));
+        }
+
+        // fetch their filmographies a few at a time, keeping trending order
+        let credits = stream::iter(actors)
+            .map(|actor| async move {
+                let credits = tmdb_client.person_movie_credits(actor.id).await;
+                (actor, credits)
+            })
+            .buffered(CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        let today = OffsetDateTime::now_utc().date().to_string();
+        let mut lines = Vec::new();
+        let mut failures = BatchFailures::default();
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        for (actor, credits) in credits {
+            let credits = match credits {
+                Ok(credits) => credits,
+                Err(err) => {
+                    failures.push(actor.name, err);
+                    continue;
+                }
+            };
+            let latest = match latest_release(&credits, &today) {
+                Some(credit) => format!("latest film {}", credit.render(missing)),
+                None => "no released films on TMDB".to_string(),
+            };
+            lines.push(format!(
+                "{}. {} (TMDB ID: {}) - {latest}",
+                lines.len() + 1,
+                actor.name,
+                actor.id
+            ));
+        }
+        if lines.is_empty() {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                failures.to_string(),
+            )));
+        }
+
+        let mut result = format!(
+            "Trending actors this week and their latest films:\n{}",
+            lines.join("\n")
+        );
+        if !failures.is_empty() {
+            result.push_str(&format!("\n\n{failures}"));
+        }
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+/// Returns the credit with the latest release date on or before `today` (YYYY-MM-DD), so
+/// announced films that are not out yet are skipped.
+fn latest_release<'a>(credits: &'a [MovieCredit], today: &str) -> Option<&'a MovieCredit> {
+    credits
+        .iter()
+        .filter(|credit| {
+            let date = credit.movie.release_date.as_str();
+            !date.is_empty() && date <= today
+        })
+        .max_by(|a, b| a.movie.release_date.cmp(&b.movie.release_date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    /// Serves a week of trending people: three actors around a director.
+    fn mock_trending(mock: &MockTmdb) {
+        let mut director = fixtures::person_summary(578, "Ridley Scott");
+        director["known_for_department"] = "Directing".into();
+        mock.json(
+            "/trending/person/week",
+            fixtures::single_page(vec![
+                fixtures::person_summary(31, "Tom Hanks"),
+                director,
+                fixtures::person_summary(5344, "Meg Ryan"),
+                fixtures::person_summary(1813, "Anne Hathaway"),
+            ]),
+        )
+        .json(
+            "/person/31/movie_credits",
+            fixtures::movie_credits(vec![
+                fixtures::credit(13, "Forrest Gump", "1994-07-06", "Forrest"),
+                fixtures::credit(857, "Saving Private Ryan", "1998-07-24", "Captain Miller"),
+                fixtures::credit(99999, "Announced", "2999-01-01", ""),
+                fixtures::credit(99998, "Undated", "", ""),
+            ]),
+        )
+        .json(
+            "/person/5344/movie_credits",
+            fixtures::movie_credits(vec![]),
+        )
+        .on(
+            "/person/1813/movie_credits",
+            MockResponse::bytes(502, "text/html", "<html>"),
+        );
+    }
+
+    #[tokio::test]
+    async fn pairs_each_trending_actor_with_their_latest_release() {
+        let mock = MockTmdb::start().await;
+        mock_trending(&mock);
+
+        let result = TrendingActorsLatest { count: None }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        let text = result_text(&result);
+        assert!(text.starts_with(
+            "Trending actors this week and their latest films:\n\
+             1. Tom Hanks (TMDB ID: 31) - latest film Saving Private Ryan (1998) (as Captain Miller)\n\
+             2. Meg Ryan (TMDB ID: 5344) - no released films on TMDB\n\n\
+             Could not fetch 1 item(s):\n- Anne Hathaway: "
+        ));
+        assert!(!text.contains("Ridley Scott"));
+        assert_eq!(mock.hits("/person/578/movie_credits"), 0);
+    }
+
+    #[tokio::test]
+    async fn count_bounds_the_filmographies_fetched() {
+        let mock = MockTmdb::start().await;
+        mock_trending(&mock);
+
+        let result = TrendingActorsLatest { count: Some(1) }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert!(
+            result_text(&result)
+                .ends_with("latest film Saving Private Ryan (1998) (as Captain Miller)")
+        );
+        assert_eq!(mock.hits("/person/5344/movie_credits"), 0);
+        assert_eq!(mock.hits("/person/1813/movie_credits"), 0);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    tmdb_client::{MissingFields, TmdbClient, parse_region},
+    tools::{PageInfo, find_genre, unknown_genre},
+};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "trending_in_genre",
+    title = "Trending Movies in a Genre",
+    description = concat!(
+        "List the movies in a genre that are currently most popular on TMDB, ",
+        "e.g. what's hot in \"Science Fiction\" right now. ",
+        "Specify the `genre` by name, such as \"Action\", \"Comedy\" or \"Horror\". ",
+        "Optionally give a two-letter `region` code (e.g. GB) to use that region's release dates; ",
+        "it defaults to the server's configured region.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct TrendingInGenre {
+    /// Genre name, e.g. "Science Fiction" (case-insensitive)
+    #[json_schema(min_length = 1)]
+    pub genre: String,
+    /// ISO 3166-1 region code such as "US" or "GB" (defaults to the server's region)
+    pub region: Option<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl TrendingInGenre {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // an explicit region must be valid; otherwise fall back to the configured one
+        let region = match self.region.as_deref() {
+            Some(region) => match parse_region(region) {
+                Some(region) => Some(region),
+                None => {
+                    return Ok(CallToolResult::with_error(CallToolError::from_message(
+                        format!("\"{region}\" is not a two-letter region code like US or GB"),
+                    )));
+                }
+            },
+            None => tmdb_client.config().region.clone(),
+        };
+
+        let genres = tmdb_client
+            .movie_genres()
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        // list the valid genres when the name is not one of them
+        let Some(genre) = find_genre(&genres, &self.genre) else {
+            return Ok(CallToolResult::with_error(unknown_genre(
+                &genres,
+                &self.genre,
+            )));
+        };
+
+        let movies = tmdb_client
+            .popular_movies_in_genre(genre.id, region.as_deref())
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let page_info = PageInfo::of(&movies);
+
+        if movies.results.is_empty() {
+            return Ok(page_info.attach_to(CallToolResult::text_content(vec![
+                format!("No {} movies were found", genre.name).into(),
+            ])));
+        }
+
+        // numbered list including each movie's id, so it can be passed to other tools
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = movies
+            .results
+            .iter()
+            .enumerate()
+            .map(|(index, movie)| {
+                format!(
+                    "{}. {} - TMDB ID: {}",
+                    index + 1,
+                    movie.render(missing),
+                    movie.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(page_info.attach_to(CallToolResult::text_content(vec![
+            format!("Popular {} movies right now:\n{result}", genre.name).into(),
+        ])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+
+    fn tool(genre: &str, region: Option<&str>) -> TrendingInGenre {
+        TrendingInGenre {
+            genre: genre.to_string(),
+            region: region.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_the_genre_name_to_its_id() {
+        let mock = MockTmdb::start().await;
+        mock.json("/genre/movie/list", fixtures::genres()).json(
+            "/discover/movie",
+            fixtures::single_page(vec![fixtures::movie(438631, "Dune", "2021-09-15")]),
+        );
+
+        let result = tool("sci-fi", Some("gb"))
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        let discover = mock
+            .requests()
+            .into_iter()
+            .find(|request| request.path() == "/discover/movie")
+            .unwrap();
+        assert_eq!(discover.query("with_genres"), Some("878"));
+        assert_eq!(discover.query("sort_by"), Some("popularity.desc"));
+        assert_eq!(discover.query("region"), Some("GB"));
+        assert!(
+            result_text(&result)
+                .starts_with("Popular Science Fiction movies right now:\n1. Dune (2021)")
+        );
+    }
+
+    #[tokio::test]
+    async fn an_unknown_genre_lists_the_valid_ones() {
+        let mock = MockTmdb::start().await;
+        mock.json("/genre/movie/list", fixtures::genres());
+
+        let result = tool("Cowboy", None).invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            result_text(&result),
+            "Unknown genre \"Cowboy\". Valid genres: Action, Comedy, Drama, Science Fiction"
+        );
+        assert_eq!(mock.hits("/discover/movie"), 0);
+    }
+
+    #[tokio::test]
+    async fn an_omitted_region_falls_back_to_the_configured_one() {
+        let mock = MockTmdb::start().await;
+        mock.json("/genre/movie/list", fixtures::genres())
+            .json("/discover/movie", fixtures::single_page(vec![]));
+        let client = mock.client_with(|config| config.region = Some("CA".to_string()));
+
+        tool("Comedy", None).invoke(&client).await.unwrap();
+
+        let discover = mock
+            .requests()
+            .into_iter()
+            .find(|request| request.path() == "/discover/movie")
+            .unwrap();
+        assert_eq!(discover.query("with_genres"), Some("35"));
+        assert_eq!(discover.query("region"), Some("CA"));
+    }
+
+    #[tokio::test]
+    async fn a_malformed_region_is_rejected() {
+        let mock = MockTmdb::start().await;
+
+        let result = tool("Comedy", Some("USA"))
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            result_text(&result),
+            "\"USA\" is not a two-letter region code like US or GB"
+        );
+        assert!(mock.requests().is_empty());
+    }
+}
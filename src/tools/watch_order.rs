@@ -0,0 +1,177 @@
+use crate::tmdb_client::{MissingFields, TmdbClient};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+#[mcp_tool(
+    name = "watch_order",
+    title = "Franchise Watch Order",
+    description = concat!(
+        "Get the chronological (release order) viewing order of the franchise a movie belongs to, ",
+        "by TMDB movie ID, e.g. every Harry Potter film from any one of them. ",
+        "Movies without a release date yet are listed last.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct WatchOrder {
+    /// TMDB ID of any movie in the franchise
+    #[json_schema(minimum = 1)]
+    pub movie_id: i64,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl WatchOrder {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let movie = tmdb_client
+            .movie_details(self.movie_id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(movie) = movie else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No movie with the TMDB ID {} was found", self.movie_id),
+            )));
+        };
+        let Some(summary) = movie.belongs_to_collection else {
+            return Ok(CallToolResult::text_content(vec![
+                format!(
+                    "{} is not part of a collection on TMDB, so there is no watch order",
+                    movie.title
+                )
+                .into(),
+            ]));
+        };
+
+        let collection = tmdb_client
+            .collection(summary.id)
+            .await
+            .map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(mut collection) = collection else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("The collection \"{}\" could not be found", summary.name),
+            )));
+        };
+
+        // ISO dates sort chronologically as strings; undated (unreleased) parts go last
+        collection.parts.sort_by(|a, b| {
+            (a.release_date.is_empty(), &a.release_date)
+                .cmp(&(b.release_date.is_empty(), &b.release_date))
+        });
+
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        let result = collection
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                let marker = if part.id == self.movie_id {
+                    " (the movie asked about)"
+                } else {
+                    ""
+                };
+                format!(
+                    "{}. {} - TMDB ID: {}{marker}",
+                    index + 1,
+                    part.render(missing),
+                    part.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::text_content(vec![
+            format!("Watch order for {}:\n{result}", collection.name).into(),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::json;
+
+    fn the_empire_strikes_back() -> serde_json::Value {
+        let mut movie = fixtures::movie_full(1891, "The Empire Strikes Back", "1980-05-20");
+        movie["belongs_to_collection"] = json!({
+            "id": 10,
+            "name": "Star Wars Collection",
+            "poster_path": null,
+            "backdrop_path": null
+        });
+        movie
+    }
+
+    #[tokio::test]
+    async fn parts_are_sorted_into_release_order() {
+        let mock = MockTmdb::start().await;
+        mock.json("/movie/1891", the_empire_strikes_back()).json(
+            "/collection/10",
+            json!({
+                "id": 10,
+                "name": "Star Wars Collection",
+                "original_language": "en",
+                "original_name": "Star Wars Collection",
+                "overview": "",
+                "poster_path": null,
+                "backdrop_path": null,
+                "parts": [
+                    fixtures::movie(181812, "The Rise of Skywalker", "2019-12-18"),
+                    fixtures::movie(999999, "Untitled Star Wars Film", ""),
+                    fixtures::movie(1891, "The Empire Strikes Back", "1980-05-20"),
+                    fixtures::movie(11, "Star Wars", "1977-05-25"),
+                    fixtures::movie(1892, "Return of the Jedi", "1983-05-25"),
+                ]
+            }),
+        );
+
+        let result = WatchOrder { movie_id: 1891 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Watch order for Star Wars Collection:\n\
+             1. Star Wars (1977) - TMDB ID: 11\n\
+             2. The Empire Strikes Back (1980) - TMDB ID: 1891 (the movie asked about)\n\
+             3. Return of the Jedi (1983) - TMDB ID: 1892\n\
+             4. The Rise of Skywalker (2019) - TMDB ID: 181812\n\
+             5. Untitled Star Wars Film - TMDB ID: 999999"
+        );
+    }
+
+    #[tokio::test]
+    async fn a_movie_outside_any_collection_says_so() {
+        let mock = MockTmdb::start().await;
+        mock.json(
+            "/movie/13",
+            fixtures::movie_full(13, "Forrest Gump", "1994-06-23"),
+        );
+
+        let result = WatchOrder { movie_id: 13 }
+            .invoke(&mock.client())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Forrest Gump is not part of a collection on TMDB, so there is no watch order"
+        );
+        assert!(
+            mock.requests()
+                .iter()
+                .all(|request| !request.path().starts_with("/collection"))
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{
+    tmdb_client::{MissingFields, MovieDetail, TmdbClient, parse_region},
+    tools::{BatchFailures, unique_movies},
+};
+use futures::stream::{self, StreamExt};
+use rust_mcp_sdk::{
+    macros::{JsonSchema, mcp_tool},
+    schema::{CallToolError, CallToolResult},
+};
+
+/// Number of the actor's most popular films whose providers are looked up.
+const MAX_FILMS: usize = 8;
+
+/// Number of provider lookups sent at the same time.
+const CONCURRENT_REQUESTS: usize = 4;
+
+#[mcp_tool(
+    name = "where_to_watch_actor",
+    title = "Where to Watch an Actor's Films",
+    description = concat!(
+        "Find which of an actor's most popular films can be streamed right now (by subscription, ",
+        "free or with ads) and on which services, by TMDB actor ID. ",
+        "Optionally give a two-letter `region` code (e.g. GB); it defaults to the server's configured region. ",
+        "Only the actor's top 8 films are checked.",
+    ),
+    icons = [
+        (src = "https://raw.githubusercontent.com/theREDspace/mcp-server-example/main/icons/movies-128.png",
+        mime_type = "image/png",
+        sizes = ["128x128"])
+    ],
+)]
+#[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug, JsonSchema)]
+pub struct WhereToWatchActor {
+    /// TMDB ID of the actor
+    #[json_schema(minimum = 1)]
+    pub actor_id: i64,
+    /// ISO 3166-1 region code such as "US" or "GB" (defaults to the server's region)
+    pub region: Option<String>,
+}
+
+// Implements the `invoke` function, which is executed whenever the client calls this tool.
+impl WhereToWatchActor {
+    // Executes the logic for this tool when it is invoked by the client.
+    pub async fn invoke(
+        &self,
+        tmdb_client: &TmdbClient,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        // availability differs per country, so a region is required
+        let region = match self.region.as_deref() {
+            Some(region) => parse_region(region).ok_or_else(|| {
+                format!("\"{region}\" is not a two-letter region code like US or GB")
+            }),
+            None => tmdb_client.config().region.clone().ok_or_else(|| {
+                "No region was given and the server has none configured; pass a `region` such as US"
+                    .to_string()
+            }),
+        };
+        let region = match region {
+            Ok(region) => region,
+            Err(message) => {
+                return Ok(CallToolResult::with_error(CallToolError::from_message(
+                    message,
+                )));
+            }
+        };
+
+        let language = &tmdb_client.config().language;
+        let (actor, credits) = tokio::join!(
+            tmdb_client.person_details(self.actor_id, language),
+            tmdb_client.person_movie_credits(self.actor_id),
+        );
+        let actor = actor.map_err(|err| CallToolError::from_message(err.to_string()))?;
+        let Some(actor) = actor else {
+            return Ok(CallToolResult::with_error(CallToolError::from_message(
+                format!("No actor with the TMDB ID {} was found", self.actor_id),
+            )));
+        };
+        let credits = credits.map_err(|err| CallToolError::from_message(err.to_string()))?;
+
+        let mut movies: Vec<MovieDetail> = unique_movies(credits)
+            .into_iter()
+            .map(|credit| credit.movie)
+            .collect();
+        if movies.is_empty() {
+            return Ok(CallToolResult::text_content(vec![
+                format!("No movies are listed for {} on TMDB", actor.name).into(),
+            ]));
+        }
+        movies.sort_by(|a, b| b.popularity.total_cmp(&a.popularity));
+        movies.truncate(MAX_FILMS);
+
+        let region_code = region.as_str();
+        let lookups = stream::iter(movies)
+            .map(|movie| async move {
+                let providers = tmdb_client
+                    .movie_watch_providers(movie.id, region_code)
+                    .await;
+                (movie, providers)
+            })
+            .buffered(CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut streaming = Vec::new();
+        let mut not_streaming = Vec::new();
+        let mut failures = BatchFailures::default();
+        let missing = tmdb_client.config().missing_fields_or(MissingFields::Omit);
+        for (movie, providers) in lookups {
+            let label = movie.render(missing);
+            match providers {
+                Ok(Some(providers)) => {
+                    let names = providers
+                        .streaming()
+                        .iter()
+                        .map(|provider| provider.provider_name.as_str())
+                        .collect::<Vec<_>>();
+                    if names.is_empty() {
+                        not_streaming.push(label);
+                    } else {
+                        streaming.push(format!("- {label}: {}", names.join(", ")));
+                    }
+                }
+                Ok(None) => not_streaming.push(label),
+                Err(err) => failures.push(label, err),
+            }
+        }
+
+        let mut result = if streaming.is_empty() {
+            format!(
+                "None of {}'s top films can be streamed in {region} right now",
+                actor.name
+            )
+        } else {
+            format!(
+                "{}'s top films streaming in {region} right now:\n{}",
+                actor.name,
+                streaming.join("\n")
+            )
+        };
+        if !streaming.is_empty() && !not_streaming.is_empty() {
+            result.push_str(&format!(
+                "\n\nNot streaming in {region}: {}",
+                not_streaming.join(", ")
+            ));
+        }
+        if !failures.is_empty() {
+            result.push_str(&format!("\n\n{failures}"));
+        }
+        result.push_str("\n\nStreaming availability provided by JustWatch.");
+
+        Ok(CallToolResult::text_content(vec![result.into()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tmdb_client::test_server::{MockResponse, MockTmdb, fixtures};
+    use crate::tools::result_text;
+    use serde_json::{Value, json};
+
+    fn popular_credit(id: i64, title: &str, popularity: f64) -> Value {
+        let mut credit = fixtures::credit(id, title, "1994-06-23", "");
+        credit["popularity"] = json!(popularity);
+        credit
+    }
+
+    fn provider(id: i64, name: &str) -> Value {
+        json!({ "provider_id": id, "provider_name": name, "logo_path": null, "display_priority": 1 })
+    }
+
+    async fn tom_hanks(credits: Vec<Value>) -> MockTmdb {
+        let mock = MockTmdb::start().await;
+        mock.json("/person/31", fixtures::person(31, "Tom Hanks"))
+            .json("/person/31/movie_credits", fixtures::movie_credits(credits));
+        mock
+    }
+
+    fn in_region(region: &str) -> WhereToWatchActor {
+        WhereToWatchActor {
+            actor_id: 31,
+            region: Some(region.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn groups_streamable_films_by_provider() {
+        let mock = tom_hanks(vec![
+            popular_credit(862, "Toy Story", 40.0),
+            popular_credit(13, "Forrest Gump", 50.0),
+            popular_credit(8358, "Cast Away", 30.0),
+            popular_credit(594, "The Terminal", 20.0),
+        ])
+        .await;
+        mock.json(
+            "/movie/13/watch/providers",
+            json!({ "id": 13, "results": { "US": {
+                "link": "https://www.themoviedb.org/movie/13/watch?locale=US",
+                "flatrate": [provider(8, "Netflix")],
+                "free": [provider(73, "Tubi TV")],
+                "ads": [provider(8, "Netflix")],
+                "rent": [provider(2, "Apple TV")]
+            } } }),
+        )
+        .json(
+            "/movie/862/watch/providers",
+            json!({ "id": 862, "results": { "US": { "flatrate": [provider(337, "Disney Plus")] } } }),
+        )
+        .json(
+            "/movie/8358/watch/providers",
+            json!({ "id": 8358, "results": {
+                "US": { "buy": [provider(2, "Apple TV")] },
+                "GB": { "flatrate": [provider(8, "Netflix")] }
+            } }),
+        )
+        .on(
+            "/movie/594/watch/providers",
+            MockResponse::status(404, json!({ "status_code": 34 })),
+        );
+
+        let result = in_region("US").invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "Tom Hanks's top films streaming in US right now:\n\
+             - Forrest Gump (1994): Netflix, Tubi TV\n\
+             - Toy Story (1994): Disney Plus\n\n\
+             Not streaming in US: Cast Away (1994), The Terminal (1994)\n\n\
+             Streaming availability provided by JustWatch."
+        );
+    }
+
+    #[tokio::test]
+    async fn only_the_most_popular_films_are_looked_up() {
+        let credits = (1..=10)
+            .map(|id| popular_credit(id, &format!("Film {id}"), id as f64))
+            .collect();
+        let mock = tom_hanks(credits).await;
+        for id in 1..=10 {
+            mock.json(
+                &format!("/movie/{id}/watch/providers"),
+                json!({ "id": id, "results": {} }),
+            );
+        }
+
+        let result = in_region("US").invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(
+            result_text(&result),
+            "None of Tom Hanks's top films can be streamed in US right now\n\n\
+             Streaming availability provided by JustWatch."
+        );
+        assert_eq!(mock.hits("/movie/1/watch/providers"), 0);
+        assert_eq!(mock.hits("/movie/2/watch/providers"), 0);
+        assert_eq!(mock.hits("/movie/3/watch/providers"), 1);
+    }
+
+    #[tokio::test]
+    async fn a_malformed_region_is_rejected_before_any_request() {
+        let mock = tom_hanks(vec![]).await;
+
+        let result = in_region("USA").invoke(&mock.client()).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            result_text(&result),
+            "\"USA\" is not a two-letter region code like US or GB"
+        );
+        assert!(mock.requests().is_empty());
+    }
+}